@@ -0,0 +1,247 @@
+use crate::shared::analysis::arena::{arena_stats, fill_augment_names, ArenaReport};
+use crate::shared::analysis::build::{extract_builds, ParticipantBuild};
+use crate::shared::analysis::head_to_head::HeadToHead;
+use crate::shared::analysis::heatmap::{build_heatmap, HeatmapReport, DEFAULT_GRID_SIZE};
+use crate::shared::analysis::indicator::{compute_indicators, Indicator};
+use crate::shared::analysis::performance::{
+    aggregate_performance, fill_champion_names, PerformanceReport,
+};
+use crate::shared::analysis::premade::{PremadeCandidate, PremadeReport};
+use crate::shared::analysis::timeline::{analyze_timeline, TimelineReport};
+use crate::shared::init::game_data::{get_item_info_cache, is_cache_initialized};
+use crate::shared::store::filter::MatchFilter;
+use crate::shared::store::match_store::{get_player_games, get_stored_game};
+use crate::shared::web_api::game_detail::{get_game_detail, get_game_details};
+use crate::shared::web_api::head_to_head::get_head_to_head;
+use crate::shared::web_api::matchup::{get_matchup_report, MatchupQuery, MatchupReport};
+use crate::shared::web_api::premade::detect_game_premades;
+use crate::shared::web_api::record_sgp::sync_match_history;
+use crate::utils::config::indicator::get_indicator_config;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tauri::Runtime;
+use tracing::{info, warn};
+
+/// 统计玩家最近的表现（按英雄 / 分路 / 队列汇总，以及胜率走势）
+///
+/// # 参数
+/// - `puuid`: 玩家 puuid
+/// - `count`: 统计最近多少局（筛选后），为空时统计本地保存的全部对局
+/// - `filter`: 筛选条件
+/// - `sync`: 是否先从服务器拉取最近的对局，默认为 false（只使用本地数据）
+#[tauri::command]
+pub async fn get_player_performance<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    puuid: &str,
+    count: Option<usize>,
+    filter: Option<MatchFilter>,
+    sync: Option<bool>,
+) -> Result<PerformanceReport, String> {
+    info!(
+        "get_player_performance: puuid: {}, count: {:?}, filter: {:?}, sync: {:?}",
+        puuid, count, filter, sync
+    );
+    if sync.unwrap_or(false) {
+        let sync_count = count.unwrap_or(20).min(100) as i32;
+        if let Err(e) = sync_match_history(puuid, 0, sync_count).await {
+            warn!("同步对局失败，使用本地数据: {}", e);
+        }
+    }
+
+    let games = get_player_games(puuid).await.map_err(|e| e.to_string())?;
+    let mut report = aggregate_performance(&games, puuid, &filter.unwrap_or_default(), count);
+    fill_champion_names(&mut report).await;
+    Ok(report)
+}
+
+/// 分析对局时间线（经济/经验差曲线、10/15 分钟对位差距、资源控制）
+///
+/// # 参数
+/// - `match_id`: 对局 ID，如 "HN1_1234567890"
+#[tauri::command]
+pub async fn get_match_timeline<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    match_id: &str,
+) -> Result<TimelineReport, String> {
+    info!("get_match_timeline: match_id: {}", match_id);
+    let detail = get_game_detail(match_id).await?;
+    // 本地没有对局概要时无法确定分路，对位差距为空
+    let summary = get_stored_game(match_id).await.ok().flatten();
+    Ok(analyze_timeline(&detail, summary.as_deref()))
+}
+
+/// 统计玩家击杀/死亡/助攻位置热力图（按地图和前期/中期/后期分组）
+///
+/// # 参数
+/// - `puuid`: 玩家 puuid
+/// - `count`: 统计最近多少局（筛选后），默认 20 局
+/// - `filter`: 筛选条件
+/// - `grid_size`: 网格大小，默认 32
+/// - `fetch`: 本地没有对局详情时是否从服务器拉取，默认为 false
+#[tauri::command]
+pub async fn get_player_heatmap<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    puuid: &str,
+    count: Option<usize>,
+    filter: Option<MatchFilter>,
+    grid_size: Option<usize>,
+    fetch: Option<bool>,
+) -> Result<HeatmapReport, String> {
+    info!(
+        "get_player_heatmap: puuid: {}, count: {:?}, filter: {:?}, grid_size: {:?}, fetch: {:?}",
+        puuid, count, filter, grid_size, fetch
+    );
+    let filter = filter.unwrap_or_default();
+    let games: Vec<_> = get_player_games(puuid)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .filter(|game| filter.matches(game, puuid))
+        .take(count.unwrap_or(20))
+        .collect();
+    let match_ids: Vec<String> = games
+        .iter()
+        .map(|game| game.metadata.match_id.clone())
+        .collect();
+    let details = get_game_details(&match_ids, fetch.unwrap_or(false)).await;
+
+    let pairs: Vec<_> = details
+        .iter()
+        .filter_map(|detail| {
+            games
+                .iter()
+                .find(|game| game.metadata.match_id == detail.metadata.match_id)
+                .map(|game| (game.as_ref(), detail))
+        })
+        .collect();
+    Ok(build_heatmap(
+        &pairs,
+        puuid,
+        grid_size.unwrap_or(DEFAULT_GRID_SIZE),
+    ))
+}
+
+/// 还原对局中每位玩家的出门装、出装顺序、成装和技能加点顺序
+///
+/// # 参数
+/// - `match_id`: 对局 ID
+#[tauri::command]
+pub async fn get_match_builds<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    match_id: &str,
+) -> Result<Vec<ParticipantBuild>, String> {
+    info!("get_match_builds: match_id: {}", match_id);
+    let detail = get_game_detail(match_id).await?;
+    let items = if is_cache_initialized().await {
+        get_item_info_cache().await
+    } else {
+        Arc::new(HashMap::new())
+    };
+    Ok(extract_builds(&detail, &items))
+}
+
+/// 检测玩家中的开黑车队
+///
+/// # 参数
+/// - `players`: 当前对局的玩家及其队伍，会拉取每名玩家最近的对局
+#[tauri::command]
+pub async fn detect_premades<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    players: Vec<PremadeCandidate>,
+) -> Result<PremadeReport, String> {
+    info!("detect_premades: players: {}", players.len());
+    Ok(detect_game_premades(&players).await)
+}
+
+/// 计算玩家的状态指标（连胜/连败、近期状态、重开和投降频率、疑似消极比赛、疑似小号）
+///
+/// # 参数
+/// - `puuid`: 玩家 puuid
+/// - `count`: 使用最近多少局，默认 20 局
+/// - `sync`: 是否先从服务器拉取最近的对局，默认为 false（只使用本地数据）
+#[tauri::command]
+pub async fn get_player_indicators<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    puuid: &str,
+    count: Option<usize>,
+    sync: Option<bool>,
+) -> Result<Vec<Indicator>, String> {
+    info!(
+        "get_player_indicators: puuid: {}, count: {:?}, sync: {:?}",
+        puuid, count, sync
+    );
+    let count = count.unwrap_or(20);
+    if sync.unwrap_or(false) {
+        if let Err(e) = sync_match_history(puuid, 0, count.min(100) as i32).await {
+            warn!("同步对局失败，使用本地数据: {}", e);
+        }
+    }
+    let games: Vec<_> = get_player_games(puuid)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .take(count)
+        .collect();
+    let config = get_indicator_config().await;
+    Ok(compute_indicators(&games, puuid, &config))
+}
+
+/// 查询两名玩家的交手记录（同队和对位的胜率以及每局数据）
+///
+/// # 参数
+/// - `puuid`: 玩家 puuid，胜率以该玩家为准
+/// - `other_puuid`: 另一名玩家的 puuid
+/// - `backfill`: 先从服务器拉取另一名玩家最近的多少局，默认为 0（只使用本地数据）
+#[tauri::command]
+pub async fn get_head_to_head_record<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    puuid: &str,
+    other_puuid: &str,
+    backfill: Option<usize>,
+) -> Result<HeadToHead, String> {
+    info!(
+        "get_head_to_head_record: puuid: {}, other_puuid: {}, backfill: {:?}",
+        puuid, other_puuid, backfill
+    );
+    get_head_to_head(puuid, other_puuid, backfill.unwrap_or(0)).await
+}
+
+/// 统计玩家的对位战绩：面对每个对位英雄的胜率、KDA、15 分钟经济差和补刀差
+///
+/// # 参数
+/// - `query`: 统计条件，设置 `enemyChampionId` 时额外返回面对该英雄时自己各个英雄的战绩
+#[tauri::command]
+pub async fn get_matchup_stats<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    query: MatchupQuery,
+) -> Result<MatchupReport, String> {
+    info!("get_matchup_stats: {:?}", query);
+    get_matchup_report(&query).await
+}
+
+/// 统计玩家的斗魂竞技场数据：平均名次、前四率以及各个强化符文的平均名次
+///
+/// # 参数
+/// - `puuid`: 玩家 puuid
+/// - `count`: 只统计最近多少局斗魂竞技场，为空时统计本地的全部对局
+#[tauri::command]
+pub async fn get_arena_stats<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    puuid: &str,
+    count: Option<usize>,
+) -> Result<ArenaReport, String> {
+    info!("get_arena_stats: puuid: {}, count: {:?}", puuid, count);
+    let games = get_player_games(puuid).await.map_err(|e| e.to_string())?;
+    let mut report = arena_stats(&games, puuid, count);
+    fill_augment_names(&mut report).await;
+    Ok(report)
+}
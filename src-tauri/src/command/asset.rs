@@ -105,3 +105,24 @@ pub async fn get_perk_icon<R: Runtime>(
         .await
         .map_err(|e| e.to_string())
 }
+
+/// 获取斗魂竞技场强化符文图标（Base64 编码）
+///
+/// # 参数
+/// - `augment_id`: 强化符文 ID
+///
+/// # 返回
+/// - Base64 编码的图片数据 URL
+#[tauri::command]
+pub async fn get_augment_icon<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    augment_id: i64,
+) -> Result<String, String> {
+    let client = get_lcu_client().await.map_err(|e| e.to_string())?;
+    client
+        .asset
+        .get_augment_icon_base64(augment_id)
+        .await
+        .map_err(|e| e.to_string())
+}
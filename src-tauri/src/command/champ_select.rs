@@ -0,0 +1,85 @@
+use crate::core::app_init::champ_select_scout::{get_scout_results, ScoutUpdate};
+use crate::shared::web_api::champ_select::{self, ChampSelectPlayer};
+use crate::shared::web_api::matchup::{self, ChampSelectMatchups};
+use crate::utils::config::auto_pick::{
+    self as auto_pick_config, save_auto_pick_config, AutoPickConfig, AUTO_PICK_CONFIG_FILE,
+};
+use tauri::{Manager, Runtime};
+use tracing::info;
+
+/// 获取英雄选择阶段的玩家，可选检测开黑
+///
+/// # 参数
+/// - `detect_premade`: 是否检测开黑，默认为 false；检测时会拉取每名玩家最近的对局
+#[tauri::command]
+pub async fn get_champ_select_players<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    detect_premade: Option<bool>,
+) -> Result<Vec<ChampSelectPlayer>, String> {
+    info!(
+        "get_champ_select_players: detect_premade: {:?}",
+        detect_premade
+    );
+    champ_select::get_champ_select_players(detect_premade.unwrap_or(false)).await
+}
+
+/// 获取本次英雄选择中已经完成的侦察结果
+///
+/// 侦察在进入英雄选择时自动开始，之后的结果通过 `champ-select-scout` 事件推送。
+#[tauri::command]
+pub async fn get_champ_select_scouts<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+) -> Result<Vec<ScoutUpdate>, String> {
+    info!("get_champ_select_scouts");
+    Ok(get_scout_results().await)
+}
+
+/// 获取自己当前英雄（或预选英雄）与对方已选英雄的对位战绩，用于锁定前参考
+///
+/// 只使用本地保存的对局，对方英雄变化后需要重新调用。
+#[tauri::command]
+pub async fn get_champ_select_matchups<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+) -> Result<ChampSelectMatchups, String> {
+    info!("get_champ_select_matchups");
+    matchup::get_champ_select_matchups().await
+}
+
+/// 获取自动选择和禁用英雄的设置
+#[tauri::command]
+pub async fn get_auto_pick_config<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+) -> Result<AutoPickConfig, String> {
+    info!("get_auto_pick_config");
+    Ok(auto_pick_config::get_auto_pick_config()
+        .await
+        .as_ref()
+        .clone())
+}
+
+/// 保存自动选择和禁用英雄的设置，下一次轮到自己操作时生效
+///
+/// # 参数
+/// - `config`: `picks` / `bans` 按位置（top / jungle / middle / bottom / utility）配置英雄 ID，
+///   优先级从高到低，没有配置的位置和没有分路的模式使用 `default`
+#[tauri::command]
+pub async fn set_auto_pick_config<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    config: AutoPickConfig,
+) -> Result<AutoPickConfig, String> {
+    info!("set_auto_pick_config: {:?}", config);
+    let path = app
+        .path()
+        .app_config_dir()
+        .map_err(|e| e.to_string())?
+        .join(AUTO_PICK_CONFIG_FILE);
+    save_auto_pick_config(&path, config.clone())
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(config)
+}
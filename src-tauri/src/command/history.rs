@@ -1,23 +1,169 @@
-use crate::shared::web_api::record_sgp::{get_record_list, RecordItem};
+use crate::core::app_init::post_game::get_last_post_game_report;
+use crate::shared::analysis::lp::{lp_changes, rank_over_time, LpChange, RankPoint};
+use crate::shared::analysis::post_game::PostGameReport;
+use crate::shared::init::lcu::get_lcu_client;
+use crate::shared::store::export::{ExportFormat, ExportSummary};
+use crate::shared::store::query::RecordQuery;
+use crate::shared::store::rank_store::{get_rank_snapshots, RankSnapshot};
+use crate::shared::store::report_store::get_stored_report;
+use crate::shared::web_api::record_sgp::{
+    export_record_list, query_record_list, sync_match_history_on, RecordPage, SyncResult,
+};
+use std::path::PathBuf;
 use tauri::Runtime;
 use tracing::info;
 
-/// 获取召唤师头像图标（Base64 编码）
+/// 从服务器拉取战绩并保存到本地
 ///
 /// # 参数
-/// - `icon_id`: 头像图标 ID
+/// - `beg_index`: 从第几局开始（0 为最近一局）
+/// - `count`: 拉取局数
+/// - `server`: 玩家所在的服务器（如 `TENCENT_HN10`），为空时使用当前服务器；只能查询与当前服务器互通的服务器
 ///
 /// # 返回
-/// - Base64 编码的图片数据 URL，可直接用于 `<img src="...">`
+/// - 实际拉取到的局数，少于 `count` 说明服务器上已经没有更早的对局
+/// - 实际使用的来源，SGP 失败时回退到 LCU
+///
 /// 这一步慢纯粹就是后端接口慢，跟前端没关系
 #[tauri::command]
-pub async fn get_rank_list<R: Runtime>(
+pub async fn sync_records<R: Runtime>(
     _app: tauri::AppHandle<R>,
     _window: tauri::Window<R>,
     puuid: &str,
     beg_index: i32,
-    end_index: i32,
-) -> Result<Vec<RecordItem>, String> {
-    info!("get_rank_list: puuid: {}, beg_index: {}, end_index: {}", puuid, beg_index, end_index);
-    get_record_list(puuid, beg_index, end_index).await
+    count: i32,
+    server: Option<String>,
+) -> Result<SyncResult, String> {
+    info!(
+        "sync_records: puuid: {}, beg_index: {}, count: {}, server: {:?}",
+        puuid, beg_index, count, server
+    );
+    sync_match_history_on(server.as_deref(), puuid, beg_index, count).await
+}
+
+/// 查询本地战绩（筛选、排序、游标分页），同时返回筛选框的候选项统计
+#[tauri::command]
+pub async fn query_records<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    query: RecordQuery,
+) -> Result<RecordPage, String> {
+    info!("query_records: {:?}", query);
+    query_record_list(&query).await
+}
+
+/// 按查询条件导出本地战绩，每局每名玩家一行
+///
+/// # 参数
+/// - `query`: 与 `query_records` 相同的查询条件，游标和每页数量会被忽略
+/// - `format`: 导出格式（csv / jsonl / parquet）
+/// - `path`: 导出文件路径，已存在时会被覆盖
+/// - `all_participants`: 是否导出每局的全部玩家，默认只导出 `query.puuid` 本人
+#[tauri::command]
+pub async fn export_records<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    query: RecordQuery,
+    format: ExportFormat,
+    path: String,
+    all_participants: Option<bool>,
+) -> Result<ExportSummary, String> {
+    info!(
+        "export_records: {:?}, format: {:?}, path: {}, all_participants: {:?}",
+        query, format, path, all_participants
+    );
+    export_record_list(
+        &query,
+        format,
+        PathBuf::from(path),
+        all_participants.unwrap_or(false),
+    )
+    .await
+}
+
+/// 默认的排位队列
+const DEFAULT_RANKED_QUEUE: &str = "RANKED_SOLO_5x5";
+
+/// 读取账号的段位快照，`puuid` 为空时使用当前召唤师
+async fn rank_snapshots_of(puuid: Option<String>) -> Result<Vec<RankSnapshot>, String> {
+    let puuid = match puuid {
+        Some(puuid) => puuid,
+        None => {
+            let client = get_lcu_client().await.map_err(|e| e.to_string())?;
+            client
+                .summoner
+                .get_current_summoner()
+                .await
+                .map_err(|e| e.to_string())?
+                .puuid
+        }
+    };
+    get_rank_snapshots(&puuid).await.map_err(|e| e.to_string())
+}
+
+/// 获取每局的胜点变化
+///
+/// # 参数
+/// - `puuid`: 账号 puuid，为空时使用当前召唤师（只有在本机登录过的账号才有记录）
+/// - `queue_type`: 排位队列，默认为 RANKED_SOLO_5x5
+#[tauri::command]
+pub async fn get_lp_changes<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    puuid: Option<String>,
+    queue_type: Option<String>,
+) -> Result<Vec<LpChange>, String> {
+    info!(
+        "get_lp_changes: puuid: {:?}, queue_type: {:?}",
+        puuid, queue_type
+    );
+    let snapshots = rank_snapshots_of(puuid).await?;
+    Ok(lp_changes(
+        &snapshots,
+        queue_type.as_deref().unwrap_or(DEFAULT_RANKED_QUEUE),
+    ))
+}
+
+/// 获取段位随时间的走势
+///
+/// # 参数
+/// - `puuid`: 账号 puuid，为空时使用当前召唤师
+/// - `queue_type`: 排位队列，默认为 RANKED_SOLO_5x5
+#[tauri::command]
+pub async fn get_rank_history<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    puuid: Option<String>,
+    queue_type: Option<String>,
+) -> Result<Vec<RankPoint>, String> {
+    info!(
+        "get_rank_history: puuid: {:?}, queue_type: {:?}",
+        puuid, queue_type
+    );
+    let snapshots = rank_snapshots_of(puuid).await?;
+    Ok(rank_over_time(
+        &snapshots,
+        queue_type.as_deref().unwrap_or(DEFAULT_RANKED_QUEUE),
+    ))
+}
+
+/// 获取赛后报告
+///
+/// 报告在每局结束时自动生成，并通过 `post-game-report` 事件推送。
+///
+/// # 参数
+/// - `match_id`: 对局 ID，为空时返回本次启动后最近一局的报告
+#[tauri::command]
+pub async fn get_post_game_report<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    match_id: Option<String>,
+) -> Result<Option<PostGameReport>, String> {
+    info!("get_post_game_report: match_id: {:?}", match_id);
+    match match_id {
+        Some(match_id) => get_stored_report(&match_id)
+            .await
+            .map_err(|e| e.to_string()),
+        None => Ok(get_last_post_game_report().await),
+    }
 }
@@ -1,4 +1,5 @@
 use crate::shared::web_api::info::{get_info as get_info_api, Info}; // TODO: 重命名
+use crate::shared::web_api::search::{self, PlayerCandidate, SearchServer};
 use tauri::Runtime;
 use tracing::info;
 
@@ -11,3 +12,29 @@ pub async fn get_info<R: Runtime>(
     info!("get_info: puuid: {}", puuid);
     get_info_api(puuid).await
 }
+
+/// 按 Riot ID 搜索玩家，返回账号在每个服务器上的召唤师
+///
+/// # 参数
+/// - `riot_id`: `GameName#Tag`
+/// - `server`: 只搜索该服务器（如 `TENCENT_HN1`），为空时搜索当前服务器和所有可以互通的服务器
+#[tauri::command]
+pub async fn search_players<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    riot_id: String,
+    server: Option<String>,
+) -> Result<Vec<PlayerCandidate>, String> {
+    info!("search_players: riot_id: {}, server: {:?}", riot_id, server);
+    search::search_players(&riot_id, server.as_deref()).await
+}
+
+/// 获取可以搜索的服务器，当前服务器在前
+#[tauri::command]
+pub async fn get_search_servers<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+) -> Result<Vec<SearchServer>, String> {
+    info!("get_search_servers");
+    search::get_search_servers().await
+}
@@ -0,0 +1,20 @@
+use crate::core::app_init::live_game::load_live_game;
+use crate::shared::web_api::live_game::LiveGame;
+use tauri::Runtime;
+use tracing::info;
+
+/// 获取当前对局中十名玩家的信息（段位、当前英雄成就、近期表现、分路）
+///
+/// 进入游戏时会自动解析并通过 `live-game` 事件推送，之后从缓存返回。
+///
+/// # 参数
+/// - `refresh`: 是否忽略缓存重新解析，默认为 false
+#[tauri::command]
+pub async fn get_live_game<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    refresh: Option<bool>,
+) -> Result<LiveGame, String> {
+    info!("get_live_game: refresh: {:?}", refresh);
+    load_live_game(refresh.unwrap_or(false)).await
+}
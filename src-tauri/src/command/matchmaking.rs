@@ -0,0 +1,87 @@
+use crate::core::app_init::auto_accept::{self, ReadyCheckStatus, READY_CHECK_EVENT};
+use crate::shared::store::ready_check_store::{get_ready_checks, ReadyCheckRecord};
+use crate::utils::config::auto_accept::{
+    self as auto_accept_config, save_auto_accept_config, AutoAcceptConfig, AUTO_ACCEPT_CONFIG_FILE,
+};
+use tauri::{Emitter, Manager, Runtime};
+use tracing::info;
+
+/// 默认返回的就绪检查记录条数
+const DEFAULT_HISTORY_LIMIT: usize = 50;
+
+/// 获取自动接受对局的设置
+#[tauri::command]
+pub async fn get_auto_accept_config<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+) -> Result<AutoAcceptConfig, String> {
+    info!("get_auto_accept_config");
+    Ok(auto_accept_config::get_auto_accept_config()
+        .await
+        .as_ref()
+        .clone())
+}
+
+/// 保存自动接受对局的设置，下一次就绪检查开始生效
+///
+/// # 参数
+/// - `config`: 延迟超过 9 秒时按 9 秒处理；`away` 为 true 时自动拒绝
+#[tauri::command]
+pub async fn set_auto_accept_config<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    config: AutoAcceptConfig,
+) -> Result<AutoAcceptConfig, String> {
+    info!("set_auto_accept_config: {:?}", config);
+    let path = app
+        .path()
+        .app_config_dir()
+        .map_err(|e| e.to_string())?
+        .join(AUTO_ACCEPT_CONFIG_FILE);
+    save_auto_accept_config(&path, config.clone())
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(config)
+}
+
+/// 获取正在进行的就绪检查，没有时返回空
+#[tauri::command]
+pub async fn get_ready_check_status<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+) -> Result<Option<ReadyCheckStatus>, String> {
+    info!("get_ready_check_status");
+    Ok(auto_accept::get_ready_check_status().await)
+}
+
+/// 取消本次就绪检查的自动接受，返回是否取消成功（已经接受或没有计划时为 false）
+#[tauri::command]
+pub async fn cancel_auto_accept<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+) -> Result<bool, String> {
+    info!("cancel_auto_accept");
+    match auto_accept::cancel_auto_accept().await {
+        Some(status) => {
+            let _ = app.emit(READY_CHECK_EVENT, status);
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// 获取最近的就绪检查记录，按时间从新到旧
+///
+/// # 参数
+/// - `limit`: 返回条数，默认 50
+#[tauri::command]
+pub async fn get_ready_check_history<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    limit: Option<usize>,
+) -> Result<Vec<ReadyCheckRecord>, String> {
+    info!("get_ready_check_history: limit: {:?}", limit);
+    get_ready_checks(limit.unwrap_or(DEFAULT_HISTORY_LIMIT))
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -1,4 +1,9 @@
 pub mod init;
+pub mod analysis;
 pub mod asset;
+pub mod champ_select;
 pub mod history;
-pub mod info;
\ No newline at end of file
+pub mod info;
+pub mod live_game;
+pub mod matchmaking;
+pub mod note;
\ No newline at end of file
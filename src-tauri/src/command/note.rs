@@ -0,0 +1,104 @@
+use crate::core::app_init::avoid_alert::{get_avoid_alerts as get_current_alerts, AvoidAlert};
+use crate::shared::store::note_store::{self, PlayerNote, PlayerNoteInput};
+use crate::shared::web_api::lobby::{self, LobbyPlayer};
+use tauri::Runtime;
+use tracing::info;
+
+/// 列出保存的玩家备注，按修改时间从新到旧
+///
+/// # 参数
+/// - `tag`: 只返回带有该标签的备注，为空时返回全部
+/// - `avoid_only`: 只返回回避名单中的玩家，默认为 false
+#[tauri::command]
+pub async fn list_player_notes<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    tag: Option<String>,
+    avoid_only: Option<bool>,
+) -> Result<Vec<PlayerNote>, String> {
+    info!(
+        "list_player_notes: tag: {:?}, avoid_only: {:?}",
+        tag, avoid_only
+    );
+    note_store::list_player_notes(tag.as_deref(), avoid_only.unwrap_or(false))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// 获取玩家的备注，没有备注时返回空
+#[tauri::command]
+pub async fn get_player_note<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    puuid: String,
+) -> Result<Option<PlayerNote>, String> {
+    info!("get_player_note: puuid: {}", puuid);
+    note_store::get_player_note(&puuid)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// 新建或覆盖玩家的备注，返回保存后的备注
+///
+/// # 参数
+/// - `input`: 备注内容，评分为 1~5 或空；名称为空时沿用之前保存的名称
+#[tauri::command]
+pub async fn save_player_note<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    input: PlayerNoteInput,
+) -> Result<PlayerNote, String> {
+    info!("save_player_note: puuid: {}", input.puuid);
+    note_store::save_player_note(input)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// 把玩家加入或移出回避名单，返回保存后的备注
+///
+/// 回避名单中的玩家出现在英雄选择或对局中时会通过 `avoid-alert` 事件提醒。
+#[tauri::command]
+pub async fn set_player_avoid<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    puuid: String,
+    avoid: bool,
+) -> Result<PlayerNote, String> {
+    info!("set_player_avoid: puuid: {}, avoid: {}", puuid, avoid);
+    note_store::set_player_avoid(&puuid, avoid)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// 获取本局已经发出的回避提醒，供前端中途打开页面时获取
+#[tauri::command]
+pub async fn get_avoid_alerts<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+) -> Result<Vec<AvoidAlert>, String> {
+    info!("get_avoid_alerts");
+    Ok(get_current_alerts().await)
+}
+
+/// 删除玩家的备注，返回备注是否存在
+#[tauri::command]
+pub async fn delete_player_note<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+    puuid: String,
+) -> Result<bool, String> {
+    info!("delete_player_note: puuid: {}", puuid);
+    note_store::delete_player_note(&puuid)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// 获取当前大厅中的玩家及其备注
+#[tauri::command]
+pub async fn get_lobby_players<R: Runtime>(
+    _app: tauri::AppHandle<R>,
+    _window: tauri::Window<R>,
+) -> Result<Vec<LobbyPlayer>, String> {
+    info!("get_lobby_players");
+    lobby::get_lobby_players().await
+}
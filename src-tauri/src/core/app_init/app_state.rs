@@ -1,10 +1,24 @@
+use crate::core::app_init::auto_accept::watch_ready_check;
+use crate::core::app_init::auto_pick::watch_auto_pick;
+use crate::core::app_init::avoid_alert::watch_avoid_list;
+use crate::core::app_init::champ_select_scout::watch_champ_select;
 use crate::core::app_init::init_and_clear::{clear_state, init_state};
+use crate::core::app_init::live_game::watch_live_game;
+use crate::core::app_init::rank_tracker::watch_rank;
 use crate::shared::process::is_running;
+use crate::shared::store::init_store_dir;
+use crate::shared::store::match_store::init_match_store;
+use crate::utils::config::auto_accept::{init_auto_accept_config, AUTO_ACCEPT_CONFIG_FILE};
+use crate::utils::config::auto_pick::{init_auto_pick_config, AUTO_PICK_CONFIG_FILE};
+use crate::utils::config::avoid::{init_avoid_config, AVOID_CONFIG_FILE};
+use crate::utils::config::indicator::{init_indicator_config, INDICATOR_CONFIG_FILE};
+use crate::utils::config::score::{init_score_config, SCORE_CONFIG_FILE};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tauri::{AppHandle, Manager};
 use tokio::sync::watch;
 use tokio::time::{interval, Duration};
+use tracing::error;
 
 // Shared application state managed by Tauri
 #[derive(Debug)]
@@ -19,8 +33,26 @@ pub struct AppState {
 
 pub fn app_setup(app: &mut tauri::App) -> Result<(), Box<dyn std::error::Error>> {
     app.manage(AppState::default());
+    let config_dir = app.path().app_config_dir()?;
+    let data_dir = app.path().app_data_dir()?;
     let app_handle = app.handle().clone();
     tauri::async_runtime::spawn(async move {
+        init_score_config(&config_dir.join(SCORE_CONFIG_FILE)).await;
+        init_indicator_config(&config_dir.join(INDICATOR_CONFIG_FILE)).await;
+        init_avoid_config(&config_dir.join(AVOID_CONFIG_FILE)).await;
+        init_auto_accept_config(&config_dir.join(AUTO_ACCEPT_CONFIG_FILE)).await;
+        init_auto_pick_config(&config_dir.join(AUTO_PICK_CONFIG_FILE)).await;
+        if let Err(e) = init_store_dir(data_dir).await {
+            error!("初始化存储目录失败: {}", e);
+        } else if let Err(e) = init_match_store().await {
+            error!("加载本地对局失败: {}", e);
+        }
+        watch_champ_select(app_handle.clone());
+        watch_live_game(app_handle.clone());
+        watch_rank(app_handle.clone());
+        watch_avoid_list(app_handle.clone());
+        watch_ready_check(app_handle.clone());
+        watch_auto_pick(app_handle.clone());
         let app_handle_for_init = app_handle.clone();
         let app_state = app_handle_for_init.state::<AppState>();
         app_state.init(app_handle).await;
@@ -0,0 +1,251 @@
+use crate::shared::init::lcu::get_lcu_client;
+use crate::shared::init::lcu_event::{subscribe_lcu_events, GAMEFLOW_PHASE_URI, READY_CHECK_URI};
+use crate::shared::store::ready_check_store::{
+    save_ready_check, ReadyCheckAction, ReadyCheckRecord,
+};
+use crate::shared::types::league_client::matchmaking::ReadyCheck;
+use crate::utils::config::auto_accept::get_auto_accept_config;
+use crate::utils::time::now_millis;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, Duration};
+use tracing::{info, warn};
+
+/// 就绪检查开始、自动响应和结束时发送
+pub const READY_CHECK_EVENT: &str = "ready-check";
+
+/// 推送给前端的就绪检查状态
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadyCheckStatus {
+    /// 毫秒
+    pub started_at: i64,
+    /// 计划自动接受的时间（毫秒），在这之前可以取消，没有计划或已取消时为空
+    pub accept_at: Option<i64>,
+    pub action: Option<ReadyCheckAction>,
+    /// 就绪检查是否已经结束
+    pub finished: bool,
+}
+
+/// 正在进行的就绪检查
+struct ActiveCheck {
+    status: ReadyCheckStatus,
+    /// 最后一次收到的就绪检查数据
+    last: ReadyCheck,
+    error: Option<String>,
+    /// 等待延迟结束后接受的任务
+    task: Option<JoinHandle<()>>,
+}
+
+static ACTIVE: Mutex<Option<ActiveCheck>> = Mutex::const_new(None);
+
+/// 获取正在进行的就绪检查，供前端中途打开页面时获取
+pub async fn get_ready_check_status() -> Option<ReadyCheckStatus> {
+    ACTIVE
+        .lock()
+        .await
+        .as_ref()
+        .map(|active| active.status.clone())
+}
+
+/// 取消本次就绪检查的自动接受，已经接受或没有计划时返回 `None`
+pub async fn cancel_auto_accept() -> Option<ReadyCheckStatus> {
+    let mut guard = ACTIVE.lock().await;
+    let active = guard.as_mut()?;
+    let task = active.task.take()?;
+    task.abort();
+    active.status.action = Some(ReadyCheckAction::Cancelled);
+    active.status.accept_at = None;
+    info!("已取消自动接受对局");
+    Some(active.status.clone())
+}
+
+async fn respond(accept: bool) -> Result<(), String> {
+    let client = get_lcu_client().await.map_err(|e| e.to_string())?;
+    let result = if accept {
+        client.matchmaking.accept().await
+    } else {
+        client.matchmaking.decline().await
+    };
+    result.map_err(|e| e.to_string())
+}
+
+/// 记录自动响应的结果
+fn apply_response(active: &mut ActiveCheck, accept: bool, result: Result<(), String>) {
+    match result {
+        Ok(()) => {
+            active.status.action = Some(if accept {
+                ReadyCheckAction::Accepted
+            } else {
+                ReadyCheckAction::Declined
+            });
+        }
+        Err(e) => {
+            warn!("自动响应就绪检查失败: {}", e);
+            active.status.action = Some(ReadyCheckAction::Failed);
+            active.error = Some(e);
+        }
+    }
+}
+
+/// 延迟结束后接受对局，玩家在此期间已经手动接受或拒绝时不再响应
+async fn accept_after(app_handle: AppHandle, delay: Duration) {
+    sleep(delay).await;
+    let started_at = {
+        let mut guard = ACTIVE.lock().await;
+        let Some(active) = guard.as_mut() else {
+            return;
+        };
+        // 开始响应后不能再取消
+        active.task = None;
+        active.status.accept_at = None;
+        if active.last.player_response != "None" {
+            info!("已手动响应就绪检查: {}", active.last.player_response);
+            let _ = app_handle.emit(READY_CHECK_EVENT, active.status.clone());
+            return;
+        }
+        active.status.started_at
+    };
+    // 请求期间不持有锁，避免阻塞就绪检查的更新
+    let result = respond(true).await;
+    let mut guard = ACTIVE.lock().await;
+    // 请求期间就绪检查已经结束或开始了新的一次
+    let Some(active) = guard
+        .as_mut()
+        .filter(|active| active.status.started_at == started_at)
+    else {
+        return;
+    };
+    apply_response(active, true, result);
+    let _ = app_handle.emit(READY_CHECK_EVENT, active.status.clone());
+}
+
+/// 暂时离开时拒绝对局，请求期间不持有锁
+async fn decline_away(app_handle: &AppHandle, started_at: i64) {
+    info!("暂时离开，自动拒绝就绪检查");
+    let result = respond(false).await;
+    let mut guard = ACTIVE.lock().await;
+    // 请求期间就绪检查已经结束或开始了新的一次
+    let Some(active) = guard
+        .as_mut()
+        .filter(|active| active.status.started_at == started_at)
+    else {
+        return;
+    };
+    apply_response(active, false, result);
+    let _ = app_handle.emit(READY_CHECK_EVENT, active.status.clone());
+}
+
+/// 就绪检查开始时按配置安排自动响应，之后的更新只记录最新数据
+async fn on_in_progress(app_handle: &AppHandle, ready_check: ReadyCheck) {
+    let mut guard = ACTIVE.lock().await;
+    if let Some(active) = guard.as_mut() {
+        active.last = ready_check;
+        return;
+    }
+
+    // timer 为已经经过的秒数，连接前已经开始的就绪检查要扣掉
+    let elapsed_ms = (ready_check.timer.max(0.0) * 1000.0) as u64;
+    let now = now_millis();
+    let mut active = ActiveCheck {
+        status: ReadyCheckStatus {
+            started_at: now - elapsed_ms as i64,
+            ..Default::default()
+        },
+        last: ready_check,
+        error: None,
+        task: None,
+    };
+
+    let config = get_auto_accept_config().await;
+    let auto_respond = config.enabled && active.last.player_response == "None";
+    if auto_respond && !config.away {
+        let delay = Duration::from_millis(config.delay_ms().saturating_sub(elapsed_ms));
+        info!("{} 毫秒后自动接受对局", delay.as_millis());
+        active.status.accept_at = Some(now + delay.as_millis() as i64);
+        active.task = Some(tokio::spawn(accept_after(app_handle.clone(), delay)));
+    }
+
+    let status = active.status.clone();
+    *guard = Some(active);
+    drop(guard);
+    let _ = app_handle.emit(READY_CHECK_EVENT, status.clone());
+    if auto_respond && config.away {
+        decline_away(app_handle, status.started_at).await;
+    }
+}
+
+/// 就绪检查结束，保存结果
+///
+/// # 参数
+/// - `ready_check`: 结束时的数据，事件被删除时为空，使用最后一次收到的数据
+async fn on_finished(app_handle: &AppHandle, ready_check: Option<ReadyCheck>) {
+    let Some(mut active) = ACTIVE.lock().await.take() else {
+        return;
+    };
+    if let Some(task) = active.task.take() {
+        task.abort();
+        active.status.accept_at = None;
+    }
+    active.status.finished = true;
+
+    let last = ready_check.unwrap_or(active.last);
+    info!(
+        "就绪检查结束: {}, 自己的响应: {}",
+        last.state, last.player_response
+    );
+    let record = ReadyCheckRecord {
+        started_at: active.status.started_at,
+        ended_at: now_millis(),
+        action: active.status.action,
+        player_response: last.player_response,
+        state: last.state,
+        error: active.error,
+    };
+    if let Err(e) = save_ready_check(record).await {
+        warn!("保存就绪检查记录失败: {}", e);
+    }
+    let _ = app_handle.emit(READY_CHECK_EVENT, active.status);
+}
+
+/// 监听就绪检查，按配置在延迟后自动接受或在暂时离开时自动拒绝，并记录每次就绪检查的结果
+pub fn watch_ready_check(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut events = subscribe_lcu_events();
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Lagged(count)) => {
+                    warn!("就绪检查事件处理过慢，丢失了 {} 个事件", count);
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            };
+            // 客户端断开时不会再收到就绪检查的事件
+            if event.uri == GAMEFLOW_PHASE_URI && event.event_type == "Delete" {
+                on_finished(&app_handle, None).await;
+                continue;
+            }
+            if event.uri != READY_CHECK_URI {
+                continue;
+            }
+            let ready_check = if event.event_type == "Delete" {
+                None
+            } else {
+                let Some(ready_check) = event.parse::<ReadyCheck>() else {
+                    continue;
+                };
+                Some(ready_check)
+            };
+            match ready_check {
+                Some(ready_check) if ready_check.state == "InProgress" => {
+                    on_in_progress(&app_handle, ready_check).await;
+                }
+                ready_check => on_finished(&app_handle, ready_check).await,
+            }
+        }
+    });
+}
@@ -0,0 +1,225 @@
+use crate::shared::analysis::pick_ban::{
+    choose_champion, is_champion_available, lock_delay_ms, my_current_action, my_position,
+    ActionKind,
+};
+use crate::shared::http_api::lcu::champ_select::ChampSelectHttpApi;
+use crate::shared::init::lcu::get_lcu_client;
+use crate::shared::init::lcu_event::{
+    subscribe_lcu_events, CHAMP_SELECT_SESSION_URI, GAMEFLOW_PHASE_URI,
+};
+use crate::shared::types::league_client::champ_select::ChampSelectSession;
+use crate::shared::types::league_client::gameflow::GameflowPhase;
+use crate::utils::config::auto_pick::get_auto_pick_config;
+use crate::utils::time::now_millis;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::task::{JoinHandle, JoinSet};
+use tokio::time::{sleep, Duration};
+use tracing::{info, warn};
+
+/// 自动预选、锁定或放弃锁定时发送
+pub const AUTO_PICK_EVENT: &str = "auto-pick";
+
+/// 推送给前端的自动选择进度
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoPickUpdate {
+    pub action_id: i32,
+    pub kind: ActionKind,
+    pub champion_id: i32,
+    /// 计划锁定的时间（毫秒），已经锁定或放弃时为空
+    pub lock_at: Option<i64>,
+    pub locked: bool,
+}
+
+/// 接口返回的可选或可禁用英雄
+async fn allowed_champions(api: &ChampSelectHttpApi, kind: ActionKind) -> Result<Vec<i32>, String> {
+    let result = match kind {
+        ActionKind::Pick => api.get_pickable_champ_ids().await,
+        ActionKind::Ban => api.get_bannable_champ_ids().await,
+    };
+    result.map_err(|e| e.to_string())
+}
+
+/// 按当前会话选出英雄，没有配置或列表中的英雄都不可用时返回空
+async fn choose(
+    api: &ChampSelectHttpApi,
+    session: &ChampSelectSession,
+    kind: ActionKind,
+) -> Result<Option<i32>, String> {
+    let config = get_auto_pick_config().await;
+    let position = my_position(session);
+    let priorities = match kind {
+        ActionKind::Pick => config.picks_for(position),
+        ActionKind::Ban => config.bans_for(position),
+    };
+    if priorities.is_empty() {
+        return Ok(None);
+    }
+    let allowed = allowed_champions(api, kind).await?;
+    Ok(choose_champion(session, kind, priorities, &allowed))
+}
+
+/// 处理自己的一次选择或禁用：立即预选，在倒计时结束前锁定
+///
+/// 锁定前重新获取会话：玩家手动换了英雄时不再锁定；预选的英雄被队友预选或被禁用时重新预选，
+/// 等到新的锁定时间再检查，不会锁定没有预选过的英雄。
+async fn act(
+    app_handle: AppHandle,
+    api: ChampSelectHttpApi,
+    mut session: ChampSelectSession,
+    action_id: i32,
+    kind: ActionKind,
+) -> Result<(), String> {
+    let Some(mut champion_id) = choose(&api, &session, kind).await? else {
+        info!("没有可用的英雄，跳过自动{}", kind.as_str());
+        return Ok(());
+    };
+    loop {
+        api.pick_or_ban(champion_id, false, kind.as_str(), action_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let config = get_auto_pick_config().await;
+        let delay = Duration::from_millis(lock_delay_ms(
+            &session.base.timer,
+            config.lock_before_end_ms,
+        ));
+        info!(
+            "自动{}预选英雄 {}，{} 毫秒后锁定",
+            kind.as_str(),
+            champion_id,
+            delay.as_millis()
+        );
+        let mut update = AutoPickUpdate {
+            action_id,
+            kind,
+            champion_id,
+            lock_at: Some(now_millis() + delay.as_millis() as i64),
+            locked: false,
+        };
+        let _ = app_handle.emit(AUTO_PICK_EVENT, update.clone());
+        sleep(delay).await;
+
+        update.lock_at = None;
+        session = api.get_session().await.map_err(|e| e.to_string())?;
+        let current = match my_current_action(&session) {
+            Some((action, _)) if action.id == action_id => action.champion_id,
+            _ => return Ok(()),
+        };
+        if current != champion_id {
+            info!("已手动修改为英雄 {}，不再自动锁定", current);
+            let _ = app_handle.emit(AUTO_PICK_EVENT, update);
+            return Ok(());
+        }
+        let allowed = allowed_champions(&api, kind).await?;
+        if is_champion_available(&session, kind, champion_id, &allowed) {
+            break;
+        }
+        let Some(next) = choose(&api, &session, kind).await? else {
+            info!("预选的英雄已不可用，放弃自动锁定");
+            let _ = app_handle.emit(AUTO_PICK_EVENT, update);
+            return Ok(());
+        };
+        info!("预选的英雄 {} 已不可用，改为预选 {}", champion_id, next);
+        champion_id = next;
+    }
+
+    api.pick_or_ban(champion_id, true, kind.as_str(), action_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    info!("自动{}锁定英雄 {}", kind.as_str(), champion_id);
+    let _ = app_handle.emit(
+        AUTO_PICK_EVENT,
+        AutoPickUpdate {
+            action_id,
+            kind,
+            champion_id,
+            lock_at: None,
+            locked: true,
+        },
+    );
+    Ok(())
+}
+
+/// 跟随会话更新，每次轮到自己的操作只处理一次
+///
+/// 任务被取消时 `JoinSet` 被丢弃，等待锁定的操作随之取消。
+async fn auto_pick_champ_select(app_handle: AppHandle) {
+    let mut events = subscribe_lcu_events();
+    let api = match get_lcu_client().await {
+        Ok(client) => client.champ_select.clone(),
+        Err(e) => {
+            warn!("自动选择失败: {}", e);
+            return;
+        }
+    };
+    let mut handled: HashSet<i32> = HashSet::new();
+    let mut tasks = JoinSet::new();
+    let mut session = api.get_session().await.ok();
+
+    loop {
+        if let Some(session) = session.take() {
+            let config = get_auto_pick_config().await;
+            if let Some((action, kind)) = my_current_action(&session) {
+                let enabled = match kind {
+                    ActionKind::Pick => config.auto_pick,
+                    ActionKind::Ban => config.auto_ban,
+                };
+                if enabled && action.champion_id == 0 && handled.insert(action.id) {
+                    // 上一次操作已经结束，不再需要锁定
+                    tasks.abort_all();
+                    let action_id = action.id;
+                    let (app_handle, api) = (app_handle.clone(), api.clone());
+                    tasks.spawn(async move {
+                        if let Err(e) = act(app_handle, api, session, action_id, kind).await {
+                            warn!("自动{}失败: {}", kind.as_str(), e);
+                        }
+                    });
+                }
+            }
+        }
+
+        match events.recv().await {
+            Ok(event) if event.uri == CHAMP_SELECT_SESSION_URI && event.event_type != "Delete" => {
+                session = event.parse::<ChampSelectSession>();
+            }
+            Ok(_) => {}
+            // 丢失了部分事件，重新获取一次会话
+            Err(RecvError::Lagged(_)) => session = api.get_session().await.ok(),
+            Err(RecvError::Closed) => break,
+        }
+    }
+}
+
+/// 监听游戏流程，进入英雄选择时按配置自动选择和禁用英雄，离开时取消
+pub fn watch_auto_pick(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut events = subscribe_lcu_events();
+        let mut task: Option<JoinHandle<()>> = None;
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Lagged(count)) => {
+                    warn!("自动选择事件处理过慢，丢失了 {} 个事件", count);
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            };
+            if event.uri != GAMEFLOW_PHASE_URI {
+                continue;
+            }
+            // Delete 事件的 data 为 null，按 None 处理
+            let phase = event.parse::<GameflowPhase>().unwrap_or_default();
+            if phase == GameflowPhase::ChampSelect {
+                if task.is_none() {
+                    task = Some(tokio::spawn(auto_pick_champ_select(app_handle.clone())));
+                }
+            } else if let Some(running) = task.take() {
+                running.abort();
+            }
+        }
+    });
+}
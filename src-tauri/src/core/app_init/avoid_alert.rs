@@ -0,0 +1,239 @@
+use crate::shared::init::lcu::get_lcu_client;
+use crate::shared::init::lcu_event::{
+    subscribe_lcu_events, CHAMP_SELECT_SESSION_URI, GAMEFLOW_PHASE_URI,
+};
+use crate::shared::init::sgp::get_sgp_client;
+use crate::shared::store::note_store::{load_notes_for_display, PlayerNote};
+use crate::shared::types::league_client::champ_select::ChampSelectSession;
+use crate::shared::types::league_client::gameflow::GameflowPhase;
+use crate::shared::web_api::champ_select::parse_players;
+use crate::shared::web_api::live_game::live_seats;
+use crate::utils::config::avoid::get_avoid_config;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use tauri::{AppHandle, Emitter, Manager, UserAttentionType};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, Duration};
+use tracing::{info, warn};
+
+/// 回避名单中的玩家出现时立即发送
+pub const AVOID_ALERT_EVENT: &str = "avoid-alert";
+/// 游戏刚开始时观战数据可能还没有准备好，失败后重试
+const SPECTATOR_RETRIES: usize = 5;
+const SPECTATOR_RETRY_DELAY: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AlertSource {
+    ChampSelect,
+    LiveGame,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvoidAlert {
+    pub game_id: i64,
+    pub source: AlertSource,
+    pub puuid: String,
+    pub is_ally: bool,
+    /// 还没有选择英雄时为 0
+    pub champion_id: i64,
+    /// 加入回避名单时的备注
+    pub note: PlayerNote,
+}
+
+/// 本局已经发出的提醒，同一名玩家每局只提醒一次
+static ALERTS: RwLock<Vec<AvoidAlert>> = RwLock::const_new(Vec::new());
+
+pub async fn get_avoid_alerts() -> Vec<AvoidAlert> {
+    ALERTS.read().await.clone()
+}
+
+fn display_name(note: &PlayerNote) -> String {
+    if note.game_name.is_empty() {
+        note.puuid.clone()
+    } else {
+        format!("{}#{}", note.game_name, note.tag_line)
+    }
+}
+
+/// 在客户端中弹出通知
+async fn notify_client(alert: &AvoidAlert) {
+    let client = match get_lcu_client().await {
+        Ok(client) => client,
+        Err(e) => {
+            warn!("发送客户端通知失败: {}", e);
+            return;
+        }
+    };
+    let side = if alert.is_ally { "己方" } else { "对方" };
+    let mut details = format!("{} 在{}", display_name(&alert.note), side);
+    if !alert.note.note.is_empty() {
+        details.push_str(&format!("：{}", alert.note.note));
+    }
+    if let Err(e) = client
+        .player_notifications
+        .create_title_details_notification("回避名单提醒".to_string(), details)
+        .await
+    {
+        warn!("发送客户端通知失败: {}", e);
+    }
+}
+
+/// 检查玩家是否在回避名单中，对本局还没有提醒过的玩家发出提醒
+///
+/// # 参数
+/// - `players`: (puuid, 是否己方, 英雄 ID)，隐藏信息的玩家 puuid 为空，会被跳过
+async fn check_players(
+    app_handle: &AppHandle,
+    game_id: i64,
+    source: AlertSource,
+    players: Vec<(String, bool, i64)>,
+) {
+    let config = get_avoid_config().await;
+    if !config.enabled {
+        return;
+    }
+    let notes = load_notes_for_display().await;
+    let mut new_alerts = vec![];
+    {
+        let mut alerts = ALERTS.write().await;
+        for (puuid, is_ally, champion_id) in players {
+            let Some(note) = notes.avoided(&puuid) else {
+                continue;
+            };
+            if alerts.iter().any(|alert| alert.puuid == puuid) {
+                continue;
+            }
+            let alert = AvoidAlert {
+                game_id,
+                source,
+                puuid,
+                is_ally,
+                champion_id,
+                note: note.clone(),
+            };
+            alerts.push(alert.clone());
+            new_alerts.push(alert);
+        }
+    }
+    if new_alerts.is_empty() {
+        return;
+    }
+
+    if config.request_attention {
+        if let Some(window) = app_handle.get_webview_window("main") {
+            let _ = window.request_user_attention(Some(UserAttentionType::Critical));
+        }
+    }
+    for alert in new_alerts {
+        info!(
+            "回避名单中的玩家 {} 出现在对局中",
+            display_name(&alert.note)
+        );
+        let _ = app_handle.emit(AVOID_ALERT_EVENT, alert.clone());
+        if config.client_notification {
+            notify_client(&alert).await;
+        }
+    }
+}
+
+/// 通过观战数据检查双方玩家
+async fn check_live_game(app_handle: AppHandle) {
+    for attempt in 1..=SPECTATOR_RETRIES {
+        let result = async {
+            let lcu = get_lcu_client().await.map_err(|e| e.to_string())?;
+            let sgp = get_sgp_client().await.map_err(|e| e.to_string())?;
+            let puuid = lcu
+                .summoner
+                .get_current_summoner()
+                .await
+                .map_err(|e| e.to_string())?
+                .puuid;
+            let data = sgp
+                .get_spectator_gameflow_by_puuid(&puuid)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok::<_, String>((data.game.id, live_seats(&data, &puuid)))
+        }
+        .await;
+        match result {
+            Ok((game_id, seats)) => {
+                let players = seats
+                    .into_iter()
+                    .map(|seat| (seat.puuid, seat.is_ally, seat.champion_id))
+                    .collect();
+                check_players(&app_handle, game_id, AlertSource::LiveGame, players).await;
+                return;
+            }
+            Err(e) => {
+                warn!("获取观战数据失败（第 {} 次）: {}", attempt, e);
+                sleep(SPECTATOR_RETRY_DELAY).await;
+            }
+        }
+    }
+}
+
+/// 监听英雄选择会话和游戏流程，回避名单中的玩家出现时发出提醒
+///
+/// 英雄选择中每名可见玩家只检查一次，进入游戏后再通过观战数据检查一次双方玩家，
+/// 回到房间或开始匹配时清空本局的提醒。
+pub fn watch_avoid_list(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut events = subscribe_lcu_events();
+        let mut checked: HashSet<String> = HashSet::new();
+        let mut task: Option<JoinHandle<()>> = None;
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Lagged(count)) => {
+                    warn!("回避名单事件处理过慢，丢失了 {} 个事件", count);
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            };
+            if event.uri == CHAMP_SELECT_SESSION_URI && event.event_type != "Delete" {
+                let Some(session) = event.parse::<ChampSelectSession>() else {
+                    continue;
+                };
+                let players: Vec<(String, bool, i64)> = parse_players(&session)
+                    .into_iter()
+                    .filter(|player| {
+                        !player.puuid.is_empty() && checked.insert(player.puuid.clone())
+                    })
+                    .map(|player| (player.puuid, player.is_ally, player.champion_id as i64))
+                    .collect();
+                if !players.is_empty() {
+                    check_players(
+                        &app_handle,
+                        session.game_id,
+                        AlertSource::ChampSelect,
+                        players,
+                    )
+                    .await;
+                }
+                continue;
+            }
+            if event.uri != GAMEFLOW_PHASE_URI {
+                continue;
+            }
+            match event.parse::<GameflowPhase>().unwrap_or_default() {
+                GameflowPhase::InProgress
+                    if task.as_ref().is_none_or(|task| task.is_finished()) =>
+                {
+                    task = Some(tokio::spawn(check_live_game(app_handle.clone())));
+                }
+                GameflowPhase::Lobby | GameflowPhase::Matchmaking | GameflowPhase::None => {
+                    if let Some(running) = task.take() {
+                        running.abort();
+                    }
+                    checked.clear();
+                    ALERTS.write().await.clear();
+                }
+                _ => {}
+            }
+        }
+    });
+}
@@ -0,0 +1,147 @@
+use crate::shared::init::lcu::get_lcu_client;
+use crate::shared::init::lcu_event::{
+    subscribe_lcu_events, CHAMP_SELECT_SESSION_URI, GAMEFLOW_PHASE_URI,
+};
+use crate::shared::types::league_client::champ_select::ChampSelectSession;
+use crate::shared::types::league_client::gameflow::GameflowPhase;
+use crate::shared::web_api::champ_select::{attach_notes, parse_players, ChampSelectPlayer};
+use crate::shared::web_api::scout::{scout_player, PlayerScout};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{RwLock, Semaphore};
+use tokio::task::{JoinHandle, JoinSet};
+use tracing::{info, warn};
+
+/// 每名玩家侦察完成时发送
+pub const SCOUT_EVENT: &str = "champ-select-scout";
+/// 进入或离开英雄选择时发送，前端收到后清空已有结果
+pub const SCOUT_RESET_EVENT: &str = "champ-select-scout-reset";
+/// 同时侦察的玩家数量
+const SCOUT_CONCURRENCY: usize = 5;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoutUpdate {
+    pub game_id: i64,
+    pub player: ChampSelectPlayer,
+    pub scout: PlayerScout,
+}
+
+/// 本次英雄选择中已经完成的侦察结果，供前端中途打开页面时获取
+static SCOUT_RESULTS: RwLock<Vec<ScoutUpdate>> = RwLock::const_new(Vec::new());
+
+pub async fn get_scout_results() -> Vec<ScoutUpdate> {
+    SCOUT_RESULTS.read().await.clone()
+}
+
+async fn reset_scout_results(app_handle: &AppHandle) {
+    SCOUT_RESULTS.write().await.clear();
+    let _ = app_handle.emit(SCOUT_RESET_EVENT, ());
+}
+
+/// 侦察英雄选择中的所有可见玩家
+///
+/// 对方玩家在选择过程中才可能变为可见，因此持续监听会话更新，只侦察新出现的玩家。
+/// 任务被取消时 `JoinSet` 被丢弃，所有正在进行的侦察随之取消。
+async fn scout_champ_select(app_handle: AppHandle) {
+    // 先订阅再获取会话，避免漏掉中间的更新
+    let mut events = subscribe_lcu_events();
+    reset_scout_results(&app_handle).await;
+    let client = match get_lcu_client().await {
+        Ok(client) => client,
+        Err(e) => {
+            warn!("侦察失败: {}", e);
+            return;
+        }
+    };
+
+    let semaphore = Arc::new(Semaphore::new(SCOUT_CONCURRENCY));
+    let mut scouted: HashSet<String> = HashSet::new();
+    let mut tasks = JoinSet::new();
+    let mut session = client.champ_select.get_session().await.ok();
+
+    loop {
+        if let Some(session) = session.take() {
+            let mut players: Vec<ChampSelectPlayer> = parse_players(&session)
+                .into_iter()
+                .filter(|player| !player.puuid.is_empty() && scouted.insert(player.puuid.clone()))
+                .collect();
+            if !players.is_empty() {
+                attach_notes(&mut players).await;
+            }
+            for player in players {
+                let client = client.clone();
+                let semaphore = semaphore.clone();
+                let app_handle = app_handle.clone();
+                let game_id = session.game_id;
+                tasks.spawn(async move {
+                    let Ok(_permit) = semaphore.acquire_owned().await else {
+                        return;
+                    };
+                    let scout = scout_player(&client, &player.puuid).await;
+                    let update = ScoutUpdate {
+                        game_id,
+                        player,
+                        scout,
+                    };
+                    SCOUT_RESULTS.write().await.push(update.clone());
+                    let _ = app_handle.emit(SCOUT_EVENT, update);
+                });
+            }
+        }
+
+        tokio::select! {
+            event = events.recv() => match event {
+                Ok(event) if event.uri == CHAMP_SELECT_SESSION_URI && event.event_type != "Delete" => {
+                    session = event.parse::<ChampSelectSession>();
+                }
+                Ok(_) => {}
+                // 丢失了部分事件，重新获取一次会话
+                Err(RecvError::Lagged(_)) => session = client.champ_select.get_session().await.ok(),
+                Err(RecvError::Closed) => break,
+            },
+            Some(result) = tasks.join_next() => {
+                if let Err(e) = result {
+                    warn!("侦察任务异常结束: {}", e);
+                }
+            }
+        }
+    }
+}
+
+/// 监听游戏流程，进入英雄选择时开始侦察，离开时取消
+pub fn watch_champ_select(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut events = subscribe_lcu_events();
+        let mut task: Option<JoinHandle<()>> = None;
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Lagged(count)) => {
+                    warn!("游戏流程事件处理过慢，丢失了 {} 个事件", count);
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            };
+            if event.uri != GAMEFLOW_PHASE_URI {
+                continue;
+            }
+            // Delete 事件的 data 为 null，按 None 处理
+            let phase = event.parse::<GameflowPhase>().unwrap_or_default();
+            if phase == GameflowPhase::ChampSelect {
+                if task.is_none() {
+                    info!("进入英雄选择，开始侦察");
+                    task = Some(tokio::spawn(scout_champ_select(app_handle.clone())));
+                }
+            } else if let Some(running) = task.take() {
+                info!("离开英雄选择，取消侦察");
+                running.abort();
+                let _ = running.await;
+                reset_scout_results(&app_handle).await;
+            }
+        }
+    });
+}
@@ -1,15 +1,17 @@
 use crate::shared::init::game_data::{
-    clear_champion_info_cache, clear_item_info_cache, clear_perk_info_cache, clear_spell_info_cache, clear_perk_style_info_cache
+    clear_augment_info_cache, clear_champion_info_cache, clear_item_info_cache, clear_perk_info_cache, clear_spell_info_cache, clear_perk_style_info_cache
 };
 use crate::shared::init::game_data::{
-    init_champion_info_cache, init_item_info_cache, init_perk_info_cache, init_spell_info_cache, init_perk_style_info_cache
+    init_augment_info_cache, init_champion_info_cache, init_item_info_cache, init_perk_info_cache, init_spell_info_cache, init_perk_style_info_cache
 };
 
 use crate::shared::init::lcu::get_lcu_client;
 use crate::shared::init::lcu::{clear_lcu_client, init_lcu_client};
+use crate::shared::init::lcu_event::{clear_lcu_event, init_lcu_event};
 use crate::shared::init::process::{clear_process_info, init_process_info};
 use crate::shared::init::sgp::{clear_sgp_client, init_sgp_client};
 use crate::shared::types::league_client::summoner::SummonerInfo;
+use crate::shared::web_api::rank_tracker::record_rank_snapshot;
 use crate::utils::error::init_error::InitError;
 use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Emitter};
@@ -108,6 +110,15 @@ pub async fn init_state(app_handle: Option<AppHandle>) {
     wait_client_ready(&app_handle).await;
     info!("客户端就绪");
 
+    info!("正在连接 LCU 事件...");
+    emit_init_status(&app_handle, false, "正在连接 LCU 事件...", None);
+    retry_with_delay(init_lcu_event, "LCU 事件").await;
+
+    info!("正在记录段位快照...");
+    if let Err(e) = record_rank_snapshot(None).await {
+        warn!("记录段位快照失败: {}", e);
+    }
+
     info!("正在初始化召唤师技能图标缓存...");
     emit_init_status(&app_handle, false, "正在初始化召唤师技能图标缓存...", None);
     retry_with_delay(|| init_spell_info_cache(), "召唤师技能图标缓存").await;
@@ -128,6 +139,13 @@ pub async fn init_state(app_handle: Option<AppHandle>) {
     emit_init_status(&app_handle, false, "正在初始化物品图标缓存...", None);
     retry_with_delay(|| init_item_info_cache(), "物品图标缓存").await;
 
+    // 强化符文只用于斗魂竞技场，失败时不重试
+    info!("正在初始化强化符文缓存...");
+    emit_init_status(&app_handle, false, "正在初始化强化符文缓存...", None);
+    if let Err(e) = init_augment_info_cache().await {
+        warn!("强化符文缓存初始化失败: {}", e);
+    }
+
     // 发送初始化完成事件
     info!("初始化完成");
     emit_init_status(&app_handle, true, "初始化完成", None);
@@ -139,6 +157,8 @@ pub async fn clear_state(app_handle: Option<AppHandle>) {
 
     info!("正在清除进程信息...");
     clear_process_info().await;
+    info!("正在断开 LCU 事件...");
+    clear_lcu_event().await;
     info!("正在清除 LCU 客户端...");
     clear_lcu_client().await;
     info!("正在清除 SGP 客户端...");
@@ -153,6 +173,8 @@ pub async fn clear_state(app_handle: Option<AppHandle>) {
     clear_perk_style_info_cache().await;
     info!("正在清除英雄图标缓存...");
     clear_champion_info_cache().await;
+    info!("正在清除强化符文缓存...");
+    clear_augment_info_cache().await;
 
     info!("清除完成");
     emit_init_status(&app_handle, false, "状态已清除，等待重新初始化...", None);
@@ -0,0 +1,109 @@
+use crate::shared::init::lcu::get_lcu_client;
+use crate::shared::init::lcu_event::{subscribe_lcu_events, GAMEFLOW_PHASE_URI};
+use crate::shared::types::league_client::gameflow::GameflowPhase;
+use crate::shared::web_api::live_game::{attach_live_notes, build_live_game, LiveGame};
+use tauri::{AppHandle, Emitter};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, Duration};
+use tracing::{info, warn};
+
+/// 对局信息解析完成时发送
+pub const LIVE_GAME_EVENT: &str = "live-game";
+/// 游戏刚开始时观战数据可能还没有准备好，失败后重试
+const LIVE_GAME_RETRIES: usize = 5;
+const LIVE_GAME_RETRY_DELAY: Duration = Duration::from_secs(3);
+
+/// 当前对局的信息，每局只解析一次
+static LIVE_GAME: RwLock<Option<LiveGame>> = RwLock::const_new(None);
+
+pub async fn get_cached_live_game() -> Option<LiveGame> {
+    LIVE_GAME.read().await.clone()
+}
+
+async fn current_game_id() -> Option<i64> {
+    let client = get_lcu_client().await.ok()?;
+    let session = client.gameflow.get_gameflow_session().await.ok()?;
+    Some(session.game_data.game_id as i64)
+}
+
+/// 解析当前对局并缓存，已经缓存过的对局直接返回
+///
+/// # 参数
+/// - `refresh`: 是否忽略缓存重新解析
+pub async fn load_live_game(refresh: bool) -> Result<LiveGame, String> {
+    if !refresh {
+        if let (Some(mut cached), Some(game_id)) =
+            (get_cached_live_game().await, current_game_id().await)
+        {
+            if cached.game_id == game_id {
+                attach_live_notes(&mut cached).await;
+                return Ok(cached);
+            }
+        }
+    }
+    let client = get_lcu_client().await.map_err(|e| e.to_string())?;
+    let summoner = client
+        .summoner
+        .get_current_summoner()
+        .await
+        .map_err(|e| e.to_string())?;
+    let mut game = build_live_game(&summoner.puuid).await?;
+    *LIVE_GAME.write().await = Some(game.clone());
+    attach_live_notes(&mut game).await;
+    Ok(game)
+}
+
+async fn publish_live_game(app_handle: AppHandle) {
+    for attempt in 1..=LIVE_GAME_RETRIES {
+        match load_live_game(false).await {
+            Ok(game) => {
+                let _ = app_handle.emit(LIVE_GAME_EVENT, game);
+                return;
+            }
+            Err(e) => {
+                warn!("解析对局失败（第 {} 次）: {}", attempt, e);
+                sleep(LIVE_GAME_RETRY_DELAY).await;
+            }
+        }
+    }
+}
+
+/// 监听游戏流程，进入游戏时解析并推送对局信息
+///
+/// 重新连接回到游戏时使用缓存，开始下一局的英雄选择时清空缓存。
+pub fn watch_live_game(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut events = subscribe_lcu_events();
+        let mut task: Option<JoinHandle<()>> = None;
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Lagged(count)) => {
+                    warn!("游戏流程事件处理过慢，丢失了 {} 个事件", count);
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            };
+            if event.uri != GAMEFLOW_PHASE_URI {
+                continue;
+            }
+            match event.parse::<GameflowPhase>().unwrap_or_default() {
+                GameflowPhase::InProgress
+                    if task.as_ref().is_none_or(|task| task.is_finished()) =>
+                {
+                    info!("进入游戏，解析对局信息");
+                    task = Some(tokio::spawn(publish_live_game(app_handle.clone())));
+                }
+                GameflowPhase::ChampSelect | GameflowPhase::Lobby | GameflowPhase::None => {
+                    if let Some(running) = task.take() {
+                        running.abort();
+                    }
+                    *LIVE_GAME.write().await = None;
+                }
+                _ => {}
+            }
+        }
+    });
+}
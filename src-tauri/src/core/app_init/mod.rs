@@ -1,2 +1,9 @@
 pub mod app_state;
+pub mod auto_accept;
+pub mod auto_pick;
+pub mod avoid_alert;
+pub mod champ_select_scout;
 pub mod init_and_clear;
+pub mod live_game;
+pub mod post_game;
+pub mod rank_tracker;
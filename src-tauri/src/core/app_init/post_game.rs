@@ -0,0 +1,27 @@
+use crate::shared::analysis::post_game::PostGameReport;
+use crate::shared::web_api::post_game::create_post_game_report;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+
+/// 赛后报告生成完成时发送
+pub const POST_GAME_REPORT_EVENT: &str = "post-game-report";
+
+/// 最近一局的赛后报告
+static LAST_REPORT: RwLock<Option<PostGameReport>> = RwLock::const_new(None);
+
+pub async fn get_last_post_game_report() -> Option<PostGameReport> {
+    LAST_REPORT.read().await.clone()
+}
+
+/// 生成刚结束的对局的赛后报告并推送给前端
+pub async fn publish_post_game_report(app_handle: &AppHandle, game_id: i64) {
+    match create_post_game_report(game_id).await {
+        Ok(report) => {
+            info!("对局 {} 的赛后报告已生成", report.match_id);
+            *LAST_REPORT.write().await = Some(report.clone());
+            let _ = app_handle.emit(POST_GAME_REPORT_EVENT, report);
+        }
+        Err(e) => warn!("生成赛后报告失败: {}", e),
+    }
+}
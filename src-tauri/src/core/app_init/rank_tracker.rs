@@ -0,0 +1,57 @@
+use crate::core::app_init::post_game::publish_post_game_report;
+use crate::shared::init::lcu::get_lcu_client;
+use crate::shared::init::lcu_event::{subscribe_lcu_events, GAMEFLOW_PHASE_URI};
+use crate::shared::types::league_client::gameflow::GameflowPhase;
+use crate::shared::web_api::rank_tracker::record_rank_snapshot;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{info, warn};
+
+/// 保存了新的段位快照时发送
+pub const RANK_UPDATED_EVENT: &str = "rank-updated";
+
+async fn track_game_end(app_handle: AppHandle) {
+    let game_id = match get_lcu_client().await {
+        Ok(client) => client
+            .gameflow
+            .get_gameflow_session()
+            .await
+            .ok()
+            .map(|session| session.game_data.game_id as i64),
+        Err(_) => None,
+    };
+    match record_rank_snapshot(game_id).await {
+        Ok(0) => {}
+        Ok(saved) => {
+            info!("对局 {:?} 结束，记录了 {} 条段位快照", game_id, saved);
+            let _ = app_handle.emit(RANK_UPDATED_EVENT, game_id);
+        }
+        Err(e) => warn!("记录段位快照失败: {}", e),
+    }
+    // 赛后报告需要用到这局的胜点变化，在段位快照之后生成
+    if let Some(game_id) = game_id {
+        publish_post_game_report(&app_handle, game_id).await;
+    }
+}
+
+/// 监听游戏流程，每局结束时记录段位快照并生成赛后报告
+pub fn watch_rank(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut events = subscribe_lcu_events();
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Lagged(count)) => {
+                    warn!("游戏流程事件处理过慢，丢失了 {} 个事件", count);
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            };
+            if event.uri == GAMEFLOW_PHASE_URI
+                && event.parse::<GameflowPhase>() == Some(GameflowPhase::EndOfGame)
+            {
+                tokio::spawn(track_game_end(app_handle.clone()));
+            }
+        }
+    });
+}
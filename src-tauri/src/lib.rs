@@ -7,12 +7,33 @@ use crate::core::app_init::app_state::app_setup;
 use crate::utils::log::init_logger;
 
 /// Command handlers
+use crate::command::analysis::{
+    detect_premades, get_arena_stats, get_head_to_head_record, get_match_builds, get_match_timeline,
+    get_matchup_stats, get_player_heatmap, get_player_indicators, get_player_performance,
+};
 use crate::command::asset::{
-    get_champion_icon, get_item_icon, get_perk_icon, get_profile_icon, get_spell_icon,
+    get_augment_icon, get_champion_icon, get_item_icon, get_perk_icon, get_profile_icon,
+    get_spell_icon,
+};
+use crate::command::champ_select::{
+    get_auto_pick_config, get_champ_select_matchups, get_champ_select_players,
+    get_champ_select_scouts, set_auto_pick_config,
+};
+use crate::command::history::{
+    export_records, get_lp_changes, get_post_game_report, get_rank_history, query_records,
+    sync_records,
 };
-use crate::command::history::get_rank_list;
-use crate::command::info::get_info;
+use crate::command::info::{get_info, get_search_servers, search_players};
 use crate::command::init::check_init_status;
+use crate::command::live_game::get_live_game;
+use crate::command::matchmaking::{
+    cancel_auto_accept, get_auto_accept_config, get_ready_check_history, get_ready_check_status,
+    set_auto_accept_config,
+};
+use crate::command::note::{
+    delete_player_note, get_avoid_alerts, get_lobby_players, get_player_note, list_player_notes,
+    save_player_note, set_player_avoid,
+};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -21,7 +42,8 @@ pub fn run() {
         .setup(|app| app_setup(app))
         .plugin(tauri_plugin_opener::init())
         .invoke_handler(tauri::generate_handler![
-            get_rank_list,
+            query_records,
+            sync_records,
             get_profile_icon,
             get_champion_icon,
             get_item_icon,
@@ -29,6 +51,40 @@ pub fn run() {
             get_perk_icon,
             get_info,
             check_init_status,
+            get_player_performance,
+            get_match_timeline,
+            get_player_heatmap,
+            get_match_builds,
+            detect_premades,
+            get_champ_select_players,
+            get_champ_select_scouts,
+            get_live_game,
+            get_player_indicators,
+            get_lp_changes,
+            get_rank_history,
+            export_records,
+            get_head_to_head_record,
+            get_matchup_stats,
+            get_champ_select_matchups,
+            get_augment_icon,
+            get_arena_stats,
+            get_post_game_report,
+            list_player_notes,
+            get_player_note,
+            save_player_note,
+            delete_player_note,
+            get_lobby_players,
+            set_player_avoid,
+            get_avoid_alerts,
+            search_players,
+            get_search_servers,
+            get_auto_accept_config,
+            set_auto_accept_config,
+            get_ready_check_status,
+            cancel_auto_accept,
+            get_ready_check_history,
+            get_auto_pick_config,
+            set_auto_pick_config,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,158 @@
+//! 斗魂竞技场统计
+//!
+//! 斗魂竞技场最多有八支两人小队，没有胜负只有名次。这里基于本地保存的对局，
+//! 统计玩家的平均名次、前四率，以及每个强化符文被选出时的平均名次。
+
+use crate::shared::init::game_data::get_augment_info_cache;
+use crate::shared::types::sgp::history::{Games, Participant};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// 斗魂竞技场的 game_mode
+pub const ARENA_GAME_MODE: &str = "CHERRY";
+/// 名次在此之内视为前四
+const TOP_PLACEMENT: i64 = 4;
+/// 强化符文至少选过这么多局才参与排名
+const MIN_AUGMENT_GAMES: usize = 2;
+
+pub fn is_arena(game: &Games) -> bool {
+    game.json.game_mode == ARENA_GAME_MODE
+}
+
+/// 玩家的最终名次（1~8），没有名次数据时为 0
+pub fn placement_of(participant: &Participant) -> i64 {
+    if participant.placement > 0 {
+        participant.placement
+    } else {
+        participant.subteam_placement
+    }
+}
+
+/// 玩家选择的强化符文，按选择顺序
+pub fn augments_of(participant: &Participant) -> Vec<i64> {
+    [
+        participant.player_augment1,
+        participant.player_augment2,
+        participant.player_augment3,
+        participant.player_augment4,
+        participant.player_augment5,
+        participant.player_augment6,
+    ]
+    .into_iter()
+    .filter(|augment| *augment != 0)
+    .collect()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PlacementStats {
+    pub games: usize,
+    pub average_placement: f64,
+    /// 前四的比例，0~1
+    pub top4_rate: f64,
+    /// 第一名的比例，0~1
+    pub first_rate: f64,
+}
+
+impl PlacementStats {
+    fn from_placements(placements: &[i64]) -> Self {
+        if placements.is_empty() {
+            return PlacementStats::default();
+        }
+        let games = placements.len();
+        let rate = |count: usize| count as f64 / games as f64;
+        PlacementStats {
+            games,
+            average_placement: placements.iter().sum::<i64>() as f64 / games as f64,
+            top4_rate: rate(placements.iter().filter(|p| **p <= TOP_PLACEMENT).count()),
+            first_rate: rate(placements.iter().filter(|p| **p == 1).count()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AugmentStats {
+    pub augment_id: i64,
+    pub name: String,
+    pub icon_path: String,
+    pub stats: PlacementStats,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ArenaReport {
+    pub overall: PlacementStats,
+    /// 按平均名次从好到差排序，选择局数不足 `MIN_AUGMENT_GAMES` 的排在最后
+    pub augments: Vec<AugmentStats>,
+}
+
+/// 统计玩家的斗魂竞技场数据
+///
+/// # 参数
+/// - `games`: 玩家的本地对局（按开始时间从新到旧，可以包含其他模式，会被忽略）
+/// - `count`: 只统计最近多少局斗魂竞技场，为空时统计全部
+pub fn arena_stats(games: &[Arc<Games>], puuid: &str, count: Option<usize>) -> ArenaReport {
+    let mut seen: HashSet<&str> = HashSet::new();
+    let mut placements = vec![];
+    let mut augment_placements: HashMap<i64, Vec<i64>> = HashMap::new();
+    for game in games.iter().filter(|game| is_arena(game)) {
+        if count.is_some_and(|count| placements.len() >= count) {
+            break;
+        }
+        if !seen.insert(game.metadata.match_id.as_str()) {
+            continue;
+        }
+        let Some(participant) = game.json.participants.iter().find(|p| p.puuid == puuid) else {
+            continue;
+        };
+        let placement = placement_of(participant);
+        if placement == 0 {
+            continue;
+        }
+        placements.push(placement);
+        for augment in augments_of(participant) {
+            augment_placements
+                .entry(augment)
+                .or_default()
+                .push(placement);
+        }
+    }
+
+    let mut augments: Vec<AugmentStats> = augment_placements
+        .into_iter()
+        .map(|(augment_id, placements)| AugmentStats {
+            augment_id,
+            stats: PlacementStats::from_placements(&placements),
+            ..Default::default()
+        })
+        .collect();
+    augments.sort_by(|a, b| {
+        (a.stats.games < MIN_AUGMENT_GAMES)
+            .cmp(&(b.stats.games < MIN_AUGMENT_GAMES))
+            .then(
+                a.stats
+                    .average_placement
+                    .total_cmp(&b.stats.average_placement),
+            )
+            .then(b.stats.games.cmp(&a.stats.games))
+            .then(a.augment_id.cmp(&b.augment_id))
+    });
+
+    ArenaReport {
+        overall: PlacementStats::from_placements(&placements),
+        augments,
+    }
+}
+
+/// 填充强化符文的名称和图标，缓存未初始化时保持为空
+pub async fn fill_augment_names(report: &mut ArenaReport) {
+    let augment_cache = get_augment_info_cache().await;
+    for augment in &mut report.augments {
+        if let Some(info) = augment_cache.get(&augment.augment_id) {
+            augment.name = info.name.clone();
+            augment.icon_path = info.icon_path.clone();
+        }
+    }
+}
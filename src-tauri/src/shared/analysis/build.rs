@@ -0,0 +1,231 @@
+//! 出装顺序与加点顺序
+//!
+//! 根据对局详情中的 `ITEM_PURCHASED` / `ITEM_SOLD` / `ITEM_UNDO` 和 `SKILL_LEVEL_UP` 事件，
+//! 还原每位玩家的出门装、出装顺序（已处理撤销和出售）、成装以及技能主升顺序。
+
+use crate::shared::init::game_data::Item;
+use crate::shared::types::sgp::game_detail::SgpGameDetailsLol;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// 在该时间（毫秒）之前购买的装备视为出门装
+const STARTING_ITEMS_END: i64 = 60_000;
+/// 技能满级点数（R 除外）
+const SKILL_MAX_POINTS: usize = 5;
+const SKILL_KEYS: [&str; 4] = ["Q", "W", "E", "R"];
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildItem {
+    pub item_id: i64,
+    pub name: String,
+    /// 购买时间（毫秒）
+    pub timestamp: i64,
+    /// 出售时间，没有出售时为空
+    pub sold_at: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillLevel {
+    /// 1~4 分别对应 Q / W / E / R
+    pub slot: i64,
+    pub key: String,
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipantBuild {
+    pub participant_id: i64,
+    pub puuid: String,
+    pub starting_items: Vec<BuildItem>,
+    /// 出门装之后购买的装备
+    pub build_order: Vec<BuildItem>,
+    /// 成装（不能再合成的装备）
+    pub completed_items: Vec<BuildItem>,
+    pub skill_order: Vec<SkillLevel>,
+    /// 技能主升顺序，如 ["Q", "E", "W"]
+    pub skill_max_order: Vec<String>,
+}
+
+fn is_completed(items: &HashMap<i64, Item>, item_id: i64) -> bool {
+    items
+        .get(&item_id)
+        .is_some_and(|item| item.to.is_empty() && !item.from.is_empty())
+}
+
+/// 按事件顺序还原购买记录（不含装备名称）
+fn resolve_purchases(detail: &SgpGameDetailsLol, participant_id: i64) -> Vec<BuildItem> {
+    let mut purchases: Vec<BuildItem> = vec![];
+    let events = detail
+        .json
+        .frames
+        .iter()
+        .flat_map(|frame| frame.events.iter())
+        .filter(|event| event.participant_id == Some(participant_id));
+
+    for event in events {
+        match event.type_field.as_str() {
+            "ITEM_PURCHASED" => {
+                if let Some(item_id) = event.item_id {
+                    purchases.push(BuildItem {
+                        item_id,
+                        timestamp: event.timestamp,
+                        ..Default::default()
+                    });
+                }
+            }
+            "ITEM_SOLD" => {
+                if let Some(purchase) = purchases
+                    .iter_mut()
+                    .rev()
+                    .find(|item| Some(item.item_id) == event.item_id && item.sold_at.is_none())
+                {
+                    purchase.sold_at = Some(event.timestamp);
+                }
+            }
+            // 撤销购买：before_id 为撤销的装备；撤销出售：after_id 为找回的装备
+            "ITEM_UNDO" => {
+                let before_id = event.before_id.unwrap_or_default();
+                let after_id = event.after_id.unwrap_or_default();
+                if before_id != 0 {
+                    if let Some(index) = purchases
+                        .iter()
+                        .rposition(|item| item.item_id == before_id && item.sold_at.is_none())
+                    {
+                        purchases.remove(index);
+                    }
+                } else if after_id != 0 {
+                    if let Some(purchase) = purchases
+                        .iter_mut()
+                        .rev()
+                        .find(|item| item.item_id == after_id && item.sold_at.is_some())
+                    {
+                        purchase.sold_at = None;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    purchases
+}
+
+fn skill_order(detail: &SgpGameDetailsLol, participant_id: i64) -> Vec<SkillLevel> {
+    detail
+        .json
+        .frames
+        .iter()
+        .flat_map(|frame| frame.events.iter())
+        .filter(|event| {
+            event.type_field == "SKILL_LEVEL_UP"
+                && event.participant_id == Some(participant_id)
+                // EVOLVE 为卡兹克等英雄的进化，不占技能点
+                && event.level_up_type.as_deref() != Some("EVOLVE")
+        })
+        .filter_map(|event| {
+            let slot = event.skill_slot?;
+            let key = SKILL_KEYS.get((slot - 1) as usize)?;
+            Some(SkillLevel {
+                slot,
+                key: key.to_string(),
+                timestamp: event.timestamp,
+            })
+        })
+        .collect()
+}
+
+/// 单个技能的加点情况，序号为在加点顺序中的位置
+struct SkillStat {
+    slot: i64,
+    points: usize,
+    maxed_at: Option<usize>,
+    first_at: usize,
+}
+
+/// 主升顺序：先点满的技能在前，没点满的按点数从多到少，点数相同按先点的在前
+fn skill_max_order(skills: &[SkillLevel]) -> Vec<String> {
+    let mut stats: Vec<SkillStat> = vec![];
+    for (index, skill) in skills
+        .iter()
+        .enumerate()
+        .filter(|(_, skill)| skill.slot != 4)
+    {
+        let position = match stats.iter().position(|stat| stat.slot == skill.slot) {
+            Some(position) => position,
+            None => {
+                stats.push(SkillStat {
+                    slot: skill.slot,
+                    points: 0,
+                    maxed_at: None,
+                    first_at: index,
+                });
+                stats.len() - 1
+            }
+        };
+        let stat = &mut stats[position];
+        stat.points += 1;
+        if stat.points == SKILL_MAX_POINTS {
+            stat.maxed_at = Some(index);
+        }
+    }
+    stats.sort_by(|a, b| match (a.maxed_at, b.maxed_at) {
+        (Some(a_max), Some(b_max)) => a_max.cmp(&b_max),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => b.points.cmp(&a.points).then(a.first_at.cmp(&b.first_at)),
+    });
+    stats
+        .into_iter()
+        .map(|stat| SKILL_KEYS[(stat.slot - 1) as usize].to_string())
+        .collect()
+}
+
+/// 还原对局中所有玩家的出装和加点
+///
+/// # 参数
+/// - `detail`: 对局详情
+/// - `items`: 装备信息缓存，用于获取名称和判断成装；为空时名称为空且无法判断成装
+pub fn extract_builds(
+    detail: &SgpGameDetailsLol,
+    items: &HashMap<i64, Item>,
+) -> Vec<ParticipantBuild> {
+    let with_name = |item: BuildItem| BuildItem {
+        name: items
+            .get(&item.item_id)
+            .map(|info| info.name.clone())
+            .unwrap_or_default(),
+        ..item
+    };
+
+    detail
+        .json
+        .participants
+        .iter()
+        .map(|participant| {
+            let (starting, rest): (Vec<_>, Vec<_>) =
+                resolve_purchases(detail, participant.participant_id)
+                    .into_iter()
+                    .partition(|item| item.timestamp < STARTING_ITEMS_END);
+            let build_order: Vec<BuildItem> = rest.into_iter().map(with_name).collect();
+            let completed_items = build_order
+                .iter()
+                .filter(|item| is_completed(items, item.item_id))
+                .cloned()
+                .collect();
+            let skill_order = skill_order(detail, participant.participant_id);
+
+            ParticipantBuild {
+                participant_id: participant.participant_id,
+                puuid: participant.puuid.clone(),
+                starting_items: starting.into_iter().map(with_name).collect(),
+                build_order,
+                completed_items,
+                skill_max_order: skill_max_order(&skill_order),
+                skill_order,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,134 @@
+//! 交手记录
+//!
+//! 在本地对局中找出两名玩家同时出现的对局，按同队和对位分别统计胜率。
+
+use crate::shared::analysis::role::{resolve_roles, Role};
+use crate::shared::types::sgp::history::{Games, Participant};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// 一局中某名玩家的简要数据
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedGamePlayer {
+    pub champion_id: i64,
+    /// 判定后的位置，没有分路的模式为空
+    pub role: Option<Role>,
+    pub kills: i64,
+    pub deaths: i64,
+    pub assists: i64,
+    pub win: bool,
+}
+
+impl SharedGamePlayer {
+    fn new(participant: &Participant, role: Option<Role>) -> Self {
+        SharedGamePlayer {
+            champion_id: participant.champion_id,
+            role,
+            kills: participant.kills,
+            deaths: participant.deaths,
+            assists: participant.assists,
+            win: participant.win,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedGame {
+    pub match_id: String,
+    pub game_creation: i64,
+    pub queue_id: i64,
+    /// 对局时长（秒）
+    pub game_duration: i64,
+    /// 是否同队
+    pub same_team: bool,
+    /// 第一名玩家的数据，胜负以此为准
+    pub player: SharedGamePlayer,
+    pub other: SharedGamePlayer,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedRecord {
+    pub games: usize,
+    /// 第一名玩家的胜场
+    pub wins: usize,
+    /// 0~1，没有对局时为 0
+    pub win_rate: f64,
+}
+
+impl SharedRecord {
+    fn add(&mut self, win: bool) {
+        self.games += 1;
+        if win {
+            self.wins += 1;
+        }
+        self.win_rate = self.wins as f64 / self.games as f64;
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HeadToHead {
+    pub puuid: String,
+    pub other_puuid: String,
+    /// 同队时的战绩
+    pub together: SharedRecord,
+    /// 对位时的战绩
+    pub against: SharedRecord,
+    /// 共同参与的对局（按开始时间从新到旧）
+    pub games: Vec<SharedGame>,
+}
+
+/// 统计两名玩家的交手记录
+///
+/// # 参数
+/// - `games`: 第一名玩家的本地对局（可以有重复，会按 match_id 去重）
+/// - `puuid`: 第一名玩家，胜率以该玩家为准
+/// - `other_puuid`: 第二名玩家
+pub fn head_to_head(games: &[Arc<Games>], puuid: &str, other_puuid: &str) -> HeadToHead {
+    let mut report = HeadToHead {
+        puuid: puuid.to_string(),
+        other_puuid: other_puuid.to_string(),
+        ..Default::default()
+    };
+
+    let mut seen: HashSet<&str> = HashSet::new();
+    for game in games {
+        let participants = &game.json.participants;
+        let (Some(player_index), Some(other_index)) = (
+            participants.iter().position(|p| p.puuid == puuid),
+            participants.iter().position(|p| p.puuid == other_puuid),
+        ) else {
+            continue;
+        };
+        if !seen.insert(game.metadata.match_id.as_str()) {
+            continue;
+        }
+        let (player, other) = (&participants[player_index], &participants[other_index]);
+        let roles = resolve_roles(participants);
+
+        let same_team = player.team_id == other.team_id;
+        if same_team {
+            report.together.add(player.win);
+        } else {
+            report.against.add(player.win);
+        }
+        report.games.push(SharedGame {
+            match_id: game.metadata.match_id.clone(),
+            game_creation: game.json.game_creation,
+            queue_id: game.json.queue_id,
+            game_duration: game.json.game_duration,
+            same_team,
+            player: SharedGamePlayer::new(player, roles[player_index]),
+            other: SharedGamePlayer::new(other, roles[other_index]),
+        });
+    }
+
+    report
+        .games
+        .sort_by_key(|game| std::cmp::Reverse(game.game_creation));
+    report
+}
@@ -0,0 +1,196 @@
+//! 击杀/死亡/助攻位置热力图
+//!
+//! 把玩家各局 `CHAMPION_KILL` 事件的坐标按地图和时间段（前期/中期/后期）落到网格中，
+//! 每张热力图按最大值归一化到 0~1，前端可以直接叠加在地图图片上渲染。
+
+use crate::shared::types::sgp::game_detail::SgpGameDetailsLol;
+use crate::shared::types::sgp::history::Games;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// 默认网格大小
+pub const DEFAULT_GRID_SIZE: usize = 32;
+/// 前期 / 中期的结束时间（分钟）
+const EARLY_END_MINUTE: i64 = 14;
+const MID_END_MINUTE: i64 = 25;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeWindow {
+    /// 0 ~ 14 分钟
+    Early,
+    /// 14 ~ 25 分钟
+    Mid,
+    /// 25 分钟以后
+    Late,
+}
+
+impl TimeWindow {
+    fn of(timestamp: i64) -> Self {
+        let minute = timestamp / 60_000;
+        if minute < EARLY_END_MINUTE {
+            TimeWindow::Early
+        } else if minute < MID_END_MINUTE {
+            TimeWindow::Mid
+        } else {
+            TimeWindow::Late
+        }
+    }
+}
+
+/// 地图坐标范围（游戏内单位）
+fn map_bounds(map_id: i64) -> (f64, f64) {
+    match map_id {
+        // 嚎哭深渊
+        12 => (12988.0, 13040.0),
+        // 召唤师峡谷及其他地图
+        _ => (14870.0, 14980.0),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Heatmap {
+    /// 事件总数
+    pub total: usize,
+    /// `cells[row][col]`，第 0 行为地图最上方（y 最大），第 0 列为地图最左侧
+    pub cells: Vec<Vec<f64>>,
+}
+
+impl Heatmap {
+    fn new(grid_size: usize) -> Self {
+        Self {
+            total: 0,
+            cells: vec![vec![0.0; grid_size]; grid_size],
+        }
+    }
+
+    fn add(&mut self, x: i64, y: i64, bounds: (f64, f64)) {
+        let grid_size = self.cells.len();
+        let cell = |value: i64, max: f64| {
+            ((value as f64 / max * grid_size as f64).floor().max(0.0) as usize).min(grid_size - 1)
+        };
+        let col = cell(x, bounds.0);
+        let row = grid_size - 1 - cell(y, bounds.1);
+        self.cells[row][col] += 1.0;
+        self.total += 1;
+    }
+
+    fn normalize(&mut self) {
+        let max = self
+            .cells
+            .iter()
+            .flatten()
+            .fold(0.0_f64, |acc, value| acc.max(*value));
+        if max > 0.0 {
+            for value in self.cells.iter_mut().flatten() {
+                *value /= max;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowHeatmap {
+    pub window: TimeWindow,
+    pub kills: Heatmap,
+    pub deaths: Heatmap,
+    pub assists: Heatmap,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MapHeatmap {
+    pub map_id: i64,
+    pub games: usize,
+    pub windows: Vec<WindowHeatmap>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatmapReport {
+    pub puuid: String,
+    pub grid_size: usize,
+    pub maps: Vec<MapHeatmap>,
+}
+
+/// 统计玩家在多局中的击杀/死亡/助攻位置
+///
+/// # 参数
+/// - `games`: 对局概要（用于确定地图）和对应的对局详情
+/// - `puuid`: 玩家 puuid
+/// - `grid_size`: 网格大小，热力图为 `grid_size * grid_size`
+pub fn build_heatmap(
+    games: &[(&Games, &SgpGameDetailsLol)],
+    puuid: &str,
+    grid_size: usize,
+) -> HeatmapReport {
+    let grid_size = grid_size.max(1);
+    let mut maps: BTreeMap<i64, (usize, BTreeMap<TimeWindow, WindowHeatmap>)> = BTreeMap::new();
+
+    for (summary, detail) in games {
+        let Some(participant_id) = detail
+            .json
+            .participants
+            .iter()
+            .find(|p| p.puuid == puuid)
+            .map(|p| p.participant_id)
+        else {
+            continue;
+        };
+        let map_id = summary.json.map_id;
+        let bounds = map_bounds(map_id);
+        let (count, windows) = maps.entry(map_id).or_default();
+        *count += 1;
+
+        let kills = detail
+            .json
+            .frames
+            .iter()
+            .flat_map(|frame| frame.events.iter())
+            .filter(|event| event.type_field == "CHAMPION_KILL");
+        for event in kills {
+            let Some(position) = &event.position else {
+                continue;
+            };
+            let window = TimeWindow::of(event.timestamp);
+            let heatmap = windows.entry(window).or_insert_with(|| WindowHeatmap {
+                window,
+                kills: Heatmap::new(grid_size),
+                deaths: Heatmap::new(grid_size),
+                assists: Heatmap::new(grid_size),
+            });
+            if event.killer_id == Some(participant_id) {
+                heatmap.kills.add(position.x, position.y, bounds);
+            }
+            if event.victim_id == Some(participant_id) {
+                heatmap.deaths.add(position.x, position.y, bounds);
+            }
+            if event.assisting_participant_ids.contains(&participant_id) {
+                heatmap.assists.add(position.x, position.y, bounds);
+            }
+        }
+    }
+
+    HeatmapReport {
+        puuid: puuid.to_string(),
+        grid_size,
+        maps: maps
+            .into_iter()
+            .map(|(map_id, (games, windows))| MapHeatmap {
+                map_id,
+                games,
+                windows: windows
+                    .into_values()
+                    .map(|mut heatmap| {
+                        heatmap.kills.normalize();
+                        heatmap.deaths.normalize();
+                        heatmap.assists.normalize();
+                        heatmap
+                    })
+                    .collect(),
+            })
+            .collect(),
+    }
+}
@@ -0,0 +1,289 @@
+//! 玩家状态指标
+//!
+//! 基于玩家最近的对局给出一组带证据的提示：连胜/连败、近期状态与整体的差距、
+//! 重开和提前投降的频率、疑似消极比赛（伤害和经济占比都很低）以及疑似小号（等级低、胜率和 KDA 高）。
+//! 阈值来自 `IndicatorConfig`。
+
+use crate::shared::analysis::score::kda;
+use crate::shared::types::sgp::history::{Games, Participant};
+use crate::utils::config::indicator::IndicatorConfig;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IndicatorKind {
+    WinStreak,
+    LossStreak,
+    /// 近期状态明显好于整体
+    HotForm,
+    /// 近期状态明显差于整体
+    ColdForm,
+    FrequentRemakes,
+    EarlySurrenders,
+    /// 疑似消极比赛或挂机
+    LowEffort,
+    /// 疑似小号
+    Smurf,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Indicator {
+    pub kind: IndicatorKind,
+    /// 指标的数值，连胜局数、占比或 KDA 变化比例等
+    pub value: f64,
+    pub message: String,
+    /// 作为证据的对局
+    pub match_ids: Vec<String>,
+}
+
+/// 单局中玩家的数据
+struct GameLine<'a> {
+    game: &'a Games,
+    player: &'a Participant,
+    remake: bool,
+}
+
+impl GameLine<'_> {
+    fn match_id(&self) -> String {
+        self.game.metadata.match_id.clone()
+    }
+
+    /// 玩家在队伍中的伤害和经济占比
+    fn shares(&self) -> (f64, f64) {
+        let team = self
+            .game
+            .json
+            .participants
+            .iter()
+            .filter(|p| p.team_id == self.player.team_id);
+        let (damage, gold) = team.fold((0, 0), |(damage, gold), p| {
+            (
+                damage + p.total_damage_dealt_to_champions,
+                gold + p.gold_earned,
+            )
+        });
+        let share = |value: i64, total: i64| {
+            if total > 0 {
+                value as f64 / total as f64
+            } else {
+                0.0
+            }
+        };
+        (
+            share(self.player.total_damage_dealt_to_champions, damage),
+            share(self.player.gold_earned, gold),
+        )
+    }
+}
+
+fn average_kda(lines: &[&GameLine]) -> f64 {
+    let (kills, deaths, assists) = lines.iter().fold((0, 0, 0), |(k, d, a), line| {
+        (
+            k + line.player.kills,
+            d + line.player.deaths,
+            a + line.player.assists,
+        )
+    });
+    kda(kills, deaths, assists)
+}
+
+fn win_rate(lines: &[&GameLine]) -> f64 {
+    if lines.is_empty() {
+        return 0.0;
+    }
+    lines.iter().filter(|line| line.player.win).count() as f64 / lines.len() as f64
+}
+
+fn streak(lines: &[&GameLine], config: &IndicatorConfig) -> Option<Indicator> {
+    let first = lines.first()?;
+    let length = lines
+        .iter()
+        .take_while(|line| line.player.win == first.player.win)
+        .count();
+    if length < config.streak_length {
+        return None;
+    }
+    let (kind, label) = if first.player.win {
+        (IndicatorKind::WinStreak, "连胜")
+    } else {
+        (IndicatorKind::LossStreak, "连败")
+    };
+    Some(Indicator {
+        kind,
+        value: length as f64,
+        message: format!("{} {} 局", label, length),
+        match_ids: lines
+            .iter()
+            .take(length)
+            .map(|line| line.match_id())
+            .collect(),
+    })
+}
+
+fn form(lines: &[&GameLine], config: &IndicatorConfig) -> Option<Indicator> {
+    if lines.len() < config.min_games || config.recent_games == 0 {
+        return None;
+    }
+    let recent = &lines[..config.recent_games.min(lines.len())];
+    let overall = average_kda(lines);
+    if overall <= 0.0 {
+        return None;
+    }
+    let delta = (average_kda(recent) - overall) / overall;
+    let kind = if delta >= config.form_kda_delta {
+        IndicatorKind::HotForm
+    } else if delta <= -config.form_kda_delta {
+        IndicatorKind::ColdForm
+    } else {
+        return None;
+    };
+    Some(Indicator {
+        kind,
+        value: delta,
+        message: format!(
+            "最近 {} 局 KDA {:.2}，整体 {:.2}，胜率 {:.0}%（整体 {:.0}%）",
+            recent.len(),
+            average_kda(recent),
+            overall,
+            win_rate(recent) * 100.0,
+            win_rate(lines) * 100.0
+        ),
+        match_ids: recent.iter().map(|line| line.match_id()).collect(),
+    })
+}
+
+fn remakes(lines: &[GameLine], config: &IndicatorConfig) -> Option<Indicator> {
+    let remade: Vec<&GameLine> = lines.iter().filter(|line| line.remake).collect();
+    let rate = remade.len() as f64 / lines.len().max(1) as f64;
+    if lines.len() < config.min_games
+        || remade.len() < config.min_remakes
+        || rate < config.remake_rate
+    {
+        return None;
+    }
+    Some(Indicator {
+        kind: IndicatorKind::FrequentRemakes,
+        value: rate,
+        message: format!("{} 局中有 {} 局重开", lines.len(), remade.len()),
+        match_ids: remade.iter().map(|line| line.match_id()).collect(),
+    })
+}
+
+fn early_surrenders(lines: &[&GameLine], config: &IndicatorConfig) -> Option<Indicator> {
+    let surrendered: Vec<&&GameLine> = lines
+        .iter()
+        .filter(|line| {
+            !line.player.win
+                && line.player.game_ended_in_surrender
+                && line.game.json.game_duration < config.early_surrender_duration
+        })
+        .collect();
+    let rate = surrendered.len() as f64 / lines.len().max(1) as f64;
+    if lines.len() < config.min_games
+        || surrendered.len() < config.min_early_surrenders
+        || rate < config.early_surrender_rate
+    {
+        return None;
+    }
+    Some(Indicator {
+        kind: IndicatorKind::EarlySurrenders,
+        value: rate,
+        message: format!(
+            "{} 局中有 {} 局在 {} 分钟前投降",
+            lines.len(),
+            surrendered.len(),
+            config.early_surrender_duration / 60
+        ),
+        match_ids: surrendered.iter().map(|line| line.match_id()).collect(),
+    })
+}
+
+fn low_effort(lines: &[&GameLine], config: &IndicatorConfig) -> Option<Indicator> {
+    let suspicious: Vec<&&GameLine> = lines
+        .iter()
+        .filter(|line| {
+            let (damage_share, gold_share) = line.shares();
+            damage_share < config.low_damage_share && gold_share < config.low_gold_share
+        })
+        .collect();
+    if suspicious.is_empty() || suspicious.len() < config.low_effort_games {
+        return None;
+    }
+    Some(Indicator {
+        kind: IndicatorKind::LowEffort,
+        value: suspicious.len() as f64,
+        message: format!(
+            "{} 局伤害占比低于 {:.0}% 且经济占比低于 {:.0}%",
+            suspicious.len(),
+            config.low_damage_share * 100.0,
+            config.low_gold_share * 100.0
+        ),
+        match_ids: suspicious.iter().map(|line| line.match_id()).collect(),
+    })
+}
+
+fn smurf(lines: &[&GameLine], config: &IndicatorConfig) -> Option<Indicator> {
+    let level = lines.first()?.player.summoner_level;
+    if lines.len() < config.min_games || level > config.smurf_max_level {
+        return None;
+    }
+    let (rate, average) = (win_rate(lines), average_kda(lines));
+    if rate < config.smurf_win_rate || average < config.smurf_kda {
+        return None;
+    }
+    Some(Indicator {
+        kind: IndicatorKind::Smurf,
+        value: rate,
+        message: format!(
+            "{} 级，最近 {} 局胜率 {:.0}%，KDA {:.2}",
+            level,
+            lines.len(),
+            rate * 100.0,
+            average
+        ),
+        match_ids: lines.iter().map(|line| line.match_id()).collect(),
+    })
+}
+
+/// 计算玩家的状态指标
+///
+/// # 参数
+/// - `games`: 玩家最近的对局（顺序不限），重开局只参与重开频率的统计
+/// - `puuid`: 玩家 puuid
+/// - `config`: 阈值配置
+pub fn compute_indicators(
+    games: &[Arc<Games>],
+    puuid: &str,
+    config: &IndicatorConfig,
+) -> Vec<Indicator> {
+    let mut lines: Vec<GameLine> = games
+        .iter()
+        .filter_map(|game| {
+            let player = game.json.participants.iter().find(|p| p.puuid == puuid)?;
+            Some(GameLine {
+                game,
+                player,
+                remake: player.game_ended_in_early_surrender
+                    || game.json.game_duration < config.remake_duration,
+            })
+        })
+        .collect();
+    // 从新到旧
+    lines.sort_by_key(|line| Reverse(line.game.json.game_creation));
+    let played: Vec<&GameLine> = lines.iter().filter(|line| !line.remake).collect();
+
+    [
+        streak(&played, config),
+        form(&played, config),
+        remakes(&lines, config),
+        early_surrenders(&played, config),
+        low_effort(&played, config),
+        smurf(&played, config),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
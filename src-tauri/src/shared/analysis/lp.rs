@@ -0,0 +1,115 @@
+//! 胜点变化
+//!
+//! 把段位快照换算成连续的胜点（黄金 II 75 点 = 1200 + 200 + 75），
+//! 计算每局的胜点变化和段位随时间的走势。
+
+use crate::shared::store::rank_store::RankSnapshot;
+use serde::{Deserialize, Serialize};
+
+/// 各段位的起始胜点，大师及以上共用同一起点
+const TIER_BASE: [(&str, i64); 10] = [
+    ("IRON", 0),
+    ("BRONZE", 400),
+    ("SILVER", 800),
+    ("GOLD", 1200),
+    ("PLATINUM", 1600),
+    ("EMERALD", 2000),
+    ("DIAMOND", 2400),
+    ("MASTER", 2800),
+    ("GRANDMASTER", 2800),
+    ("CHALLENGER", 2800),
+];
+
+/// 换算成连续的胜点，没有段位（未定级）时返回 `None`
+pub fn absolute_lp(tier: &str, division: &str, league_points: i64) -> Option<i64> {
+    let base = TIER_BASE
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(tier))?
+        .1;
+    let division = match division {
+        "III" => 100,
+        "II" => 200,
+        "I" => 300,
+        // IV 以及大师以上的 NA
+        _ => 0,
+    };
+    let division = if base >= 2800 { 0 } else { division };
+    Some(base + division + league_points)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RankPoint {
+    pub timestamp: i64,
+    pub tier: String,
+    pub division: String,
+    pub league_points: i64,
+    pub absolute_lp: i64,
+    pub wins: i64,
+    pub losses: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LpChange {
+    /// 对应的对局，启动时补记的变化为空
+    pub game_id: Option<i64>,
+    pub timestamp: i64,
+    pub before: RankPoint,
+    pub after: RankPoint,
+    pub lp_delta: i64,
+    /// 两次快照之间的局数，大于 1 说明中间有对局没有记录到，变化是多局的合计
+    pub games: i64,
+    /// 只有一局时才有值
+    pub win: Option<bool>,
+}
+
+fn rank_point(snapshot: &RankSnapshot) -> Option<RankPoint> {
+    Some(RankPoint {
+        timestamp: snapshot.timestamp,
+        tier: snapshot.tier.clone(),
+        division: snapshot.division.clone(),
+        league_points: snapshot.league_points,
+        absolute_lp: absolute_lp(&snapshot.tier, &snapshot.division, snapshot.league_points)?,
+        wins: snapshot.wins,
+        losses: snapshot.losses,
+    })
+}
+
+/// 段位随时间的走势（未定级的快照不包含在内）
+pub fn rank_over_time(snapshots: &[RankSnapshot], queue_type: &str) -> Vec<RankPoint> {
+    snapshots
+        .iter()
+        .filter(|snapshot| snapshot.queue_type == queue_type)
+        .filter_map(rank_point)
+        .collect()
+}
+
+/// 每局的胜点变化，按时间从旧到新
+pub fn lp_changes(snapshots: &[RankSnapshot], queue_type: &str) -> Vec<LpChange> {
+    let queue: Vec<&RankSnapshot> = snapshots
+        .iter()
+        .filter(|snapshot| snapshot.queue_type == queue_type)
+        .collect();
+    queue
+        .windows(2)
+        .filter_map(|pair| {
+            let (before, after) = (pair[0], pair[1]);
+            let (wins, losses) = (after.wins - before.wins, after.losses - before.losses);
+            // 赛季重置后胜负场会清零
+            if wins < 0 || losses < 0 || wins + losses == 0 {
+                return None;
+            }
+            let (before_point, after_point) = (rank_point(before)?, rank_point(after)?);
+            Some(LpChange {
+                game_id: after.game_id,
+                timestamp: after.timestamp,
+                lp_delta: after_point.absolute_lp - before_point.absolute_lp,
+                before: before_point,
+                after: after_point,
+                games: wins + losses,
+                win: (wins + losses == 1).then_some(wins == 1),
+            })
+        })
+        .collect()
+}
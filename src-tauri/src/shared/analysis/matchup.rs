@@ -0,0 +1,212 @@
+//! 对位统计
+//!
+//! 基于本地保存的对局和位置判定（见 `role`），统计玩家面对每个对位英雄的胜率、KDA，
+//! 以及有时间线数据时 15 分钟的经济差和补刀差；也可以反过来看面对某个英雄时自己哪些英雄表现最好。
+//! 统计本身是纯计算，不访问网络。
+
+use crate::shared::analysis::role::{lane_opponents, resolve_roles, Role};
+use crate::shared::analysis::score::kda;
+use crate::shared::analysis::timeline::frame_at;
+use crate::shared::init::game_data::{get_champion_info_cache, is_cache_initialized};
+use crate::shared::types::sgp::game_detail::SgpGameDetailsLol;
+use crate::shared::types::sgp::history::Games;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// 对比经济和补刀的时间点（分钟）
+const LANE_DIFF_MINUTE: i64 = 15;
+
+/// 一局对位数据
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchupSample {
+    pub match_id: String,
+    pub role: Option<Role>,
+    pub champion_id: i64,
+    pub enemy_champion_id: i64,
+    pub win: bool,
+    pub kills: i64,
+    pub deaths: i64,
+    pub assists: i64,
+    /// 15 分钟时与对位的经济差，没有时间线或对局提前结束时为空
+    pub gold_diff_at_15: Option<i64>,
+    pub cs_diff_at_15: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchupStats {
+    /// 分组的英雄：按对位英雄统计时为对方英雄，按己方英雄统计时为自己的英雄
+    pub champion_id: i64,
+    pub champion_name: String,
+    pub games: usize,
+    pub wins: usize,
+    pub win_rate: f64,
+    pub kda: f64,
+    /// 15 分钟经济差的平均值，只统计有时间线数据的对局
+    pub gold_diff_at_15: Option<f64>,
+    pub cs_diff_at_15: Option<f64>,
+    /// 有时间线数据的局数
+    pub timeline_games: usize,
+}
+
+/// 从对局中取出玩家的对位数据，没有判定出对位的对局会被跳过
+///
+/// # 参数
+/// - `games`: 玩家的本地对局（可以有重复，会按 match_id 去重）
+/// - `details`: match_id 到对局详情的映射，用于计算 15 分钟的差距，可以为空
+pub fn matchup_samples(
+    games: &[Arc<Games>],
+    details: &HashMap<String, SgpGameDetailsLol>,
+    puuid: &str,
+) -> Vec<MatchupSample> {
+    let mut seen: HashSet<&str> = HashSet::new();
+    let mut samples = vec![];
+    for game in games {
+        if !seen.insert(game.metadata.match_id.as_str()) {
+            continue;
+        }
+        let participants = &game.json.participants;
+        let Some(index) = participants.iter().position(|p| p.puuid == puuid) else {
+            continue;
+        };
+        let roles = resolve_roles(participants);
+        let Some(opponent) = lane_opponents(participants, &roles)[index] else {
+            continue;
+        };
+        let (player, enemy) = (&participants[index], &participants[opponent]);
+
+        let frames = details
+            .get(&game.metadata.match_id)
+            .and_then(|detail| frame_at(detail, LANE_DIFF_MINUTE))
+            .map(|frame| &frame.participant_frames);
+        let lane_diff = frames.and_then(|frames| {
+            let mine = frames.get(player.participant_id)?;
+            let theirs = frames.get(enemy.participant_id)?;
+            Some((
+                mine.total_gold - theirs.total_gold,
+                (mine.minions_killed + mine.jungle_minions_killed)
+                    - (theirs.minions_killed + theirs.jungle_minions_killed),
+            ))
+        });
+
+        samples.push(MatchupSample {
+            match_id: game.metadata.match_id.clone(),
+            role: roles[index],
+            champion_id: player.champion_id,
+            enemy_champion_id: enemy.champion_id,
+            win: player.win,
+            kills: player.kills,
+            deaths: player.deaths,
+            assists: player.assists,
+            gold_diff_at_15: lane_diff.map(|(gold, _)| gold),
+            cs_diff_at_15: lane_diff.map(|(_, cs)| cs),
+        });
+    }
+    samples
+}
+
+fn aggregate(samples: &[&MatchupSample], champion_id: i64) -> MatchupStats {
+    let games = samples.len();
+    let wins = samples.iter().filter(|sample| sample.win).count();
+    let (kills, deaths, assists) = samples.iter().fold((0, 0, 0), |(k, d, a), sample| {
+        (k + sample.kills, d + sample.deaths, a + sample.assists)
+    });
+    let lane_diffs: Vec<(i64, i64)> = samples
+        .iter()
+        .filter_map(|sample| Some((sample.gold_diff_at_15?, sample.cs_diff_at_15?)))
+        .collect();
+    let average = |get: fn(&(i64, i64)) -> i64| {
+        (!lane_diffs.is_empty())
+            .then(|| lane_diffs.iter().map(get).sum::<i64>() as f64 / lane_diffs.len() as f64)
+    };
+
+    MatchupStats {
+        champion_id,
+        champion_name: String::new(),
+        games,
+        wins,
+        win_rate: if games > 0 {
+            wins as f64 / games as f64
+        } else {
+            0.0
+        },
+        kda: kda(kills, deaths, assists),
+        gold_diff_at_15: average(|(gold, _)| *gold),
+        cs_diff_at_15: average(|(_, cs)| *cs),
+        timeline_games: lane_diffs.len(),
+    }
+}
+
+/// 按 `key` 分组统计，结果按局数从多到少排序
+fn group_by(
+    samples: &[MatchupSample],
+    filter: impl Fn(&MatchupSample) -> bool,
+    key: impl Fn(&MatchupSample) -> i64,
+) -> Vec<MatchupStats> {
+    let mut groups: HashMap<i64, Vec<&MatchupSample>> = HashMap::new();
+    for sample in samples.iter().filter(|sample| filter(sample)) {
+        groups.entry(key(sample)).or_default().push(sample);
+    }
+    let mut stats: Vec<MatchupStats> = groups
+        .into_iter()
+        .map(|(champion_id, samples)| aggregate(&samples, champion_id))
+        .collect();
+    stats.sort_by(|a, b| {
+        b.games
+            .cmp(&a.games)
+            .then(b.win_rate.total_cmp(&a.win_rate))
+            .then(a.champion_id.cmp(&b.champion_id))
+    });
+    stats
+}
+
+/// 按对位英雄统计玩家的战绩
+///
+/// # 参数
+/// - `champion_id`: 只统计自己使用该英雄的对局，为空时统计全部
+/// - `role`: 只统计该位置的对局，为空时统计全部
+pub fn versus_enemies(
+    samples: &[MatchupSample],
+    champion_id: Option<i64>,
+    role: Option<Role>,
+) -> Vec<MatchupStats> {
+    group_by(
+        samples,
+        |sample| {
+            champion_id.is_none_or(|id| id == sample.champion_id)
+                && role.is_none_or(|role| sample.role == Some(role))
+        },
+        |sample| sample.enemy_champion_id,
+    )
+}
+
+/// 面对某个对位英雄时，按自己使用的英雄统计战绩
+pub fn picks_into(
+    samples: &[MatchupSample],
+    enemy_champion_id: i64,
+    role: Option<Role>,
+) -> Vec<MatchupStats> {
+    group_by(
+        samples,
+        |sample| {
+            sample.enemy_champion_id == enemy_champion_id
+                && role.is_none_or(|role| sample.role == Some(role))
+        },
+        |sample| sample.champion_id,
+    )
+}
+
+/// 填充英雄名称，游戏数据缓存未初始化时保持为空
+pub async fn fill_matchup_names(stats: &mut [MatchupStats]) {
+    if !is_cache_initialized().await {
+        return;
+    }
+    let champion_cache = get_champion_info_cache().await;
+    for stat in stats {
+        if let Some(info) = champion_cache.get(&stat.champion_id) {
+            stat.champion_name = info.name.clone();
+        }
+    }
+}
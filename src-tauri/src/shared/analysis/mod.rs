@@ -0,0 +1,17 @@
+#[cfg(test)]
+pub mod tests;
+
+pub mod arena;
+pub mod build;
+pub mod head_to_head;
+pub mod heatmap;
+pub mod indicator;
+pub mod lp;
+pub mod matchup;
+pub mod performance;
+pub mod pick_ban;
+pub mod post_game;
+pub mod premade;
+pub mod role;
+pub mod score;
+pub mod timeline;
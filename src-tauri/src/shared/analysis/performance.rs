@@ -0,0 +1,282 @@
+//! 玩家表现统计
+//!
+//! 基于本地保存的对局，按英雄、分路、队列汇总玩家最近 N 局的数据，并给出胜率走势。
+//! 统计本身是纯计算，不访问网络。
+
+use crate::shared::analysis::score::kda;
+use crate::shared::init::game_data::{get_champion_info_cache, is_cache_initialized};
+use crate::shared::store::filter::MatchFilter;
+use crate::shared::types::sgp::history::{Games, Participant};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+/// 走势中滚动胜率的窗口大小
+const TREND_WINDOW: usize = 10;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PerformanceStats {
+    pub games: usize,
+    pub wins: usize,
+    pub win_rate: f64,
+    pub avg_kills: f64,
+    pub avg_deaths: f64,
+    pub avg_assists: f64,
+    pub kda: f64,
+    pub cs_per_minute: f64,
+    /// 对英雄伤害占队伍的比例
+    pub damage_share: f64,
+    pub vision_score: f64,
+    pub gold_per_minute: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChampionPerformance {
+    pub champion_id: i64,
+    pub champion_name: String,
+    pub stats: PerformanceStats,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RolePerformance {
+    pub position: String,
+    pub stats: PerformanceStats,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuePerformance {
+    pub queue_id: i64,
+    pub stats: PerformanceStats,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TrendPoint {
+    pub match_id: String,
+    pub game_creation: i64,
+    pub champion_id: i64,
+    pub win: bool,
+    pub kda: f64,
+    /// 截至该局（含）最近 `TREND_WINDOW` 局的胜率
+    pub rolling_win_rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PerformanceReport {
+    pub puuid: String,
+    pub overall: PerformanceStats,
+    /// 按场次从多到少排序
+    pub champions: Vec<ChampionPerformance>,
+    pub roles: Vec<RolePerformance>,
+    pub queues: Vec<QueuePerformance>,
+    /// 按时间从旧到新排序
+    pub trend: Vec<TrendPoint>,
+}
+
+/// 单局中玩家的数据
+struct GameLine {
+    win: bool,
+    kills: i64,
+    deaths: i64,
+    assists: i64,
+    cs: i64,
+    gold: i64,
+    vision: i64,
+    damage_share: f64,
+    minutes: f64,
+}
+
+impl GameLine {
+    fn new(game: &Games, participant: &Participant) -> Self {
+        let team_damage: i64 = game
+            .json
+            .participants
+            .iter()
+            .filter(|p| p.team_id == participant.team_id)
+            .map(|p| p.total_damage_dealt_to_champions)
+            .sum();
+        Self {
+            win: participant.win,
+            kills: participant.kills,
+            deaths: participant.deaths,
+            assists: participant.assists,
+            cs: participant.total_minions_killed + participant.neutral_minions_killed,
+            gold: participant.gold_earned,
+            vision: participant.vision_score,
+            damage_share: if team_damage > 0 {
+                participant.total_damage_dealt_to_champions as f64 / team_damage as f64
+            } else {
+                0.0
+            },
+            minutes: game.json.game_duration as f64 / 60.0,
+        }
+    }
+}
+
+fn summarize(lines: &[&GameLine]) -> PerformanceStats {
+    let games = lines.len();
+    if games == 0 {
+        return PerformanceStats::default();
+    }
+    let n = games as f64;
+    let wins = lines.iter().filter(|line| line.win).count();
+    let kills: i64 = lines.iter().map(|line| line.kills).sum();
+    let deaths: i64 = lines.iter().map(|line| line.deaths).sum();
+    let assists: i64 = lines.iter().map(|line| line.assists).sum();
+    let minutes: f64 = lines.iter().map(|line| line.minutes).sum();
+    let per_minute = |total: i64| {
+        if minutes > 0.0 {
+            total as f64 / minutes
+        } else {
+            0.0
+        }
+    };
+
+    PerformanceStats {
+        games,
+        wins,
+        win_rate: wins as f64 / n,
+        avg_kills: kills as f64 / n,
+        avg_deaths: deaths as f64 / n,
+        avg_assists: assists as f64 / n,
+        kda: kda(kills, deaths, assists),
+        cs_per_minute: per_minute(lines.iter().map(|line| line.cs).sum()),
+        damage_share: lines.iter().map(|line| line.damage_share).sum::<f64>() / n,
+        vision_score: lines.iter().map(|line| line.vision).sum::<i64>() as f64 / n,
+        gold_per_minute: per_minute(lines.iter().map(|line| line.gold).sum()),
+    }
+}
+
+/// 统计玩家表现
+///
+/// `games` 需按开始时间从新到旧排列；先按 `filter` 筛选，再取最近 `count` 局（为 `None` 时取全部）。
+/// 返回的英雄名称为空，由调用方按需填充。
+pub fn aggregate_performance(
+    games: &[Arc<Games>],
+    puuid: &str,
+    filter: &MatchFilter,
+    count: Option<usize>,
+) -> PerformanceReport {
+    let selected: Vec<(&Games, &Participant)> = games
+        .iter()
+        .filter(|game| filter.matches(game, puuid))
+        .filter_map(|game| {
+            game.json
+                .participants
+                .iter()
+                .find(|p| p.puuid == puuid)
+                .map(|participant| (game.as_ref(), participant))
+        })
+        .take(count.unwrap_or(usize::MAX))
+        .collect();
+    let lines: Vec<GameLine> = selected
+        .iter()
+        .map(|(game, participant)| GameLine::new(game, participant))
+        .collect();
+
+    let mut by_champion: HashMap<i64, Vec<&GameLine>> = HashMap::new();
+    let mut by_role: HashMap<String, Vec<&GameLine>> = HashMap::new();
+    let mut by_queue: HashMap<i64, Vec<&GameLine>> = HashMap::new();
+    for ((game, participant), line) in selected.iter().zip(&lines) {
+        by_champion
+            .entry(participant.champion_id)
+            .or_default()
+            .push(line);
+        if !participant.team_position.is_empty() {
+            by_role
+                .entry(participant.team_position.clone())
+                .or_default()
+                .push(line);
+        }
+        by_queue.entry(game.json.queue_id).or_default().push(line);
+    }
+
+    let mut champions: Vec<ChampionPerformance> = by_champion
+        .into_iter()
+        .map(|(champion_id, lines)| ChampionPerformance {
+            champion_id,
+            champion_name: String::new(),
+            stats: summarize(&lines),
+        })
+        .collect();
+    champions.sort_by(|a, b| {
+        b.stats
+            .games
+            .cmp(&a.stats.games)
+            .then(a.champion_id.cmp(&b.champion_id))
+    });
+
+    let mut roles: Vec<RolePerformance> = by_role
+        .into_iter()
+        .map(|(position, lines)| RolePerformance {
+            position,
+            stats: summarize(&lines),
+        })
+        .collect();
+    roles.sort_by(|a, b| {
+        b.stats
+            .games
+            .cmp(&a.stats.games)
+            .then(a.position.cmp(&b.position))
+    });
+
+    let mut queues: Vec<QueuePerformance> = by_queue
+        .into_iter()
+        .map(|(queue_id, lines)| QueuePerformance {
+            queue_id,
+            stats: summarize(&lines),
+        })
+        .collect();
+    queues.sort_by(|a, b| {
+        b.stats
+            .games
+            .cmp(&a.stats.games)
+            .then(a.queue_id.cmp(&b.queue_id))
+    });
+
+    // 走势按时间正序计算
+    let mut trend = Vec::with_capacity(selected.len());
+    let mut recent_wins = VecDeque::with_capacity(TREND_WINDOW);
+    for ((game, participant), line) in selected.iter().zip(&lines).rev() {
+        if recent_wins.len() == TREND_WINDOW {
+            recent_wins.pop_front();
+        }
+        recent_wins.push_back(line.win);
+        trend.push(TrendPoint {
+            match_id: game.metadata.match_id.clone(),
+            game_creation: game.json.game_creation,
+            champion_id: participant.champion_id,
+            win: line.win,
+            kda: kda(line.kills, line.deaths, line.assists),
+            rolling_win_rate: recent_wins.iter().filter(|win| **win).count() as f64
+                / recent_wins.len() as f64,
+        });
+    }
+
+    PerformanceReport {
+        puuid: puuid.to_string(),
+        overall: summarize(&lines.iter().collect::<Vec<_>>()),
+        champions,
+        roles,
+        queues,
+        trend,
+    }
+}
+
+/// 填充英雄名称，游戏数据缓存未初始化时保持为空
+pub async fn fill_champion_names(report: &mut PerformanceReport) {
+    if !is_cache_initialized().await {
+        return;
+    }
+    let champion_cache = get_champion_info_cache().await;
+    for champion in &mut report.champions {
+        if let Some(info) = champion_cache.get(&champion.champion_id) {
+            champion.champion_name = info.name.clone();
+        }
+    }
+}
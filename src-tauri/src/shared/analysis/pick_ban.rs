@@ -0,0 +1,153 @@
+//! 自动选择和禁用英雄的决策
+//!
+//! 只根据英雄选择会话和可选英雄列表做决策，不调用接口。队友预选或已经选择的英雄既不会被选走，
+//! 也不会被禁用。
+
+use crate::shared::types::league_client::champ_select::{Action, ChampSelectSession, Timer};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ActionKind {
+    Pick,
+    Ban,
+}
+
+impl ActionKind {
+    pub fn parse(value: &str) -> Option<ActionKind> {
+        match value {
+            "pick" => Some(ActionKind::Pick),
+            "ban" => Some(ActionKind::Ban),
+            _ => None,
+        }
+    }
+
+    /// `pick_or_ban` 接口使用的类型
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ActionKind::Pick => "pick",
+            ActionKind::Ban => "ban",
+        }
+    }
+}
+
+/// 正在进行的自己的选择或禁用操作
+pub fn my_current_action(session: &ChampSelectSession) -> Option<(&Action, ActionKind)> {
+    let local_cell_id = session.base.local_player_cell_id;
+    session
+        .base
+        .actions
+        .iter()
+        .flatten()
+        .filter(|action| {
+            action.actor_cell_id == local_cell_id && action.is_in_progress && !action.completed
+        })
+        .find_map(|action| ActionKind::parse(&action.r#type).map(|kind| (action, kind)))
+}
+
+/// 自己被分配的位置（top / jungle / middle / bottom / utility），没有分路的模式为空
+pub fn my_position(session: &ChampSelectSession) -> &str {
+    let local_cell_id = session.base.local_player_cell_id;
+    session
+        .base
+        .my_team
+        .iter()
+        .find(|member| member.cell_id == local_cell_id)
+        .map_or("", |member| member.assigned_position.as_str())
+}
+
+/// 已经被禁用或锁定的英雄
+fn unavailable_champions(session: &ChampSelectSession) -> HashSet<i32> {
+    let bans = &session.base.bans;
+    bans.my_team_bans
+        .iter()
+        .chain(&bans.their_team_bans)
+        .copied()
+        .chain(
+            session
+                .base
+                .actions
+                .iter()
+                .flatten()
+                .filter(|action| action.completed)
+                .map(|action| action.champion_id),
+        )
+        .filter(|champion_id| *champion_id > 0)
+        .collect()
+}
+
+/// 队友预选或已经选择的英雄，不包括自己
+fn teammate_champions(session: &ChampSelectSession) -> HashSet<i32> {
+    let local_cell_id = session.base.local_player_cell_id;
+    session
+        .base
+        .my_team
+        .iter()
+        .filter(|member| member.cell_id != local_cell_id)
+        .flat_map(|member| [member.champion_id, member.champion_pick_intent])
+        .filter(|champion_id| *champion_id > 0)
+        .collect()
+}
+
+/// 英雄是否可以选择或禁用：在接口返回的列表中，且没有被禁用、锁定或被队友预选
+///
+/// # 参数
+/// - `allowed`: 接口返回的可选（选择时）或可禁用（禁用时）的英雄 ID
+pub fn is_champion_available(
+    session: &ChampSelectSession,
+    kind: ActionKind,
+    champion_id: i32,
+    allowed: &[i32],
+) -> bool {
+    allowed.contains(&champion_id)
+        && !unavailable_champions(session).contains(&champion_id)
+        && !excluded_champions(session, kind).contains(&champion_id)
+}
+
+/// 不能选走或禁用的英雄：队友预选或已经选择的英雄，禁用时还包括自己预选的英雄
+fn excluded_champions(session: &ChampSelectSession, kind: ActionKind) -> HashSet<i32> {
+    let mut excluded = teammate_champions(session);
+    if kind == ActionKind::Ban {
+        // 不禁用自己预选的英雄
+        let local_cell_id = session.base.local_player_cell_id;
+        excluded.extend(
+            session
+                .base
+                .my_team
+                .iter()
+                .filter(|member| member.cell_id == local_cell_id)
+                .map(|member| member.champion_pick_intent),
+        );
+    }
+    excluded
+}
+
+/// 按优先级选出第一个可用的英雄
+///
+/// # 参数
+/// - `priorities`: 英雄 ID，优先级从高到低
+/// - `allowed`: 接口返回的可选（选择时）或可禁用（禁用时）的英雄 ID
+pub fn choose_champion(
+    session: &ChampSelectSession,
+    kind: ActionKind,
+    priorities: &[i32],
+    allowed: &[i32],
+) -> Option<i32> {
+    let allowed: HashSet<i32> = allowed.iter().copied().collect();
+    let unavailable = unavailable_champions(session);
+    let excluded = excluded_champions(session, kind);
+    priorities.iter().copied().find(|champion_id| {
+        allowed.contains(champion_id)
+            && !unavailable.contains(champion_id)
+            && !excluded.contains(champion_id)
+    })
+}
+
+/// 距离现在多久后锁定：在操作倒计时结束前 `lock_before_end_ms` 毫秒锁定，没有倒计时时立即锁定
+pub fn lock_delay_ms(timer: &Timer, lock_before_end_ms: u64) -> u64 {
+    if timer.is_infinite {
+        return 0;
+    }
+    (timer.adjusted_time_left_in_phase.max(0) as u64).saturating_sub(lock_before_end_ms)
+}
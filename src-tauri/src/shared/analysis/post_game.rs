@@ -0,0 +1,155 @@
+//! 赛后报告
+//!
+//! 对局结束后汇总评分和 MVP、每名玩家在队伍中的伤害和经济占比、时间线要点以及胜点变化，
+//! 由对局结束时的流程自动生成。这里只做计算，数据的获取和保存见 `web_api::post_game`。
+
+use crate::shared::analysis::arena::{is_arena, placement_of};
+use crate::shared::analysis::lp::LpChange;
+use crate::shared::analysis::score::{kda, score_sgp_game, ScoreBadge};
+use crate::shared::analysis::timeline::{analyze_timeline, TimelineReport};
+use crate::shared::types::sgp::game_detail::SgpGameDetailsLol;
+use crate::shared::types::sgp::history::{Games, Participant};
+use crate::utils::config::score::ScoreConfig;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PostGamePlayer {
+    pub puuid: String,
+    pub game_name: String,
+    pub tag_line: String,
+    pub team_id: i64,
+    /// 斗魂竞技场的小队编号，其他模式为 0
+    pub subteam_id: i64,
+    pub champion_id: i64,
+    pub win: bool,
+    /// 斗魂竞技场的名次，其他模式为 0
+    pub placement: i64,
+    pub score: f64,
+    pub badge: Option<ScoreBadge>,
+    pub kills: i64,
+    pub deaths: i64,
+    pub assists: i64,
+    pub kda: f64,
+    /// 对英雄伤害占队伍（斗魂竞技场为小队）的比例
+    pub damage_share: f64,
+    /// 经济占队伍（斗魂竞技场为小队）的比例
+    pub gold_share: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PostGameReport {
+    pub match_id: String,
+    pub game_id: i64,
+    /// 报告所属的玩家
+    pub puuid: String,
+    pub queue_id: i64,
+    pub game_mode: String,
+    pub game_creation: i64,
+    /// 对局时长（秒）
+    pub game_duration: i64,
+    pub win: bool,
+    pub players: Vec<PostGamePlayer>,
+    /// 没有对局详情时为空
+    pub timeline: Option<TimelineReport>,
+    /// 非排位对局或段位没有更新时为空
+    pub lp_change: Option<LpChange>,
+}
+
+impl PostGameReport {
+    /// 报告所属玩家的数据
+    pub fn player(&self) -> Option<&PostGamePlayer> {
+        self.players
+            .iter()
+            .find(|player| player.puuid == self.puuid)
+    }
+}
+
+/// 计算占比时的分组：斗魂竞技场按小队，其他模式按队伍
+fn group_of(participant: &Participant, arena: bool) -> i64 {
+    if arena {
+        participant.player_subteam_id
+    } else {
+        participant.team_id
+    }
+}
+
+fn share(value: i64, total: i64) -> f64 {
+    if total > 0 {
+        value as f64 / total as f64
+    } else {
+        0.0
+    }
+}
+
+/// 生成赛后报告
+///
+/// # 参数
+/// - `game`: 对局概要
+/// - `detail`: 对局详情，用于时间线要点，可以为空
+/// - `puuid`: 报告所属的玩家
+/// - `lp_change`: 这局的胜点变化
+pub fn build_post_game_report(
+    game: &Games,
+    detail: Option<&SgpGameDetailsLol>,
+    puuid: &str,
+    lp_change: Option<LpChange>,
+    score_config: &ScoreConfig,
+) -> PostGameReport {
+    let arena = is_arena(game);
+    let participants = &game.json.participants;
+
+    // 分组 -> (伤害, 经济)
+    let mut totals: HashMap<i64, (i64, i64)> = HashMap::new();
+    for participant in participants {
+        let total = totals.entry(group_of(participant, arena)).or_default();
+        total.0 += participant.total_damage_dealt_to_champions;
+        total.1 += participant.gold_earned;
+    }
+
+    let scores = score_sgp_game(game, score_config);
+    let players: Vec<PostGamePlayer> = participants
+        .iter()
+        .zip(scores)
+        .map(|(participant, result)| {
+            let (damage, gold) = totals[&group_of(participant, arena)];
+            PostGamePlayer {
+                puuid: participant.puuid.clone(),
+                game_name: participant.riot_id_game_name.clone(),
+                tag_line: participant.riot_id_tagline.clone(),
+                team_id: participant.team_id,
+                subteam_id: participant.player_subteam_id,
+                champion_id: participant.champion_id,
+                win: participant.win,
+                placement: if arena { placement_of(participant) } else { 0 },
+                score: result.score,
+                badge: result.badge,
+                kills: participant.kills,
+                deaths: participant.deaths,
+                assists: participant.assists,
+                kda: kda(participant.kills, participant.deaths, participant.assists),
+                damage_share: share(participant.total_damage_dealt_to_champions, damage),
+                gold_share: share(participant.gold_earned, gold),
+            }
+        })
+        .collect();
+
+    PostGameReport {
+        match_id: game.metadata.match_id.clone(),
+        game_id: game.json.game_id,
+        puuid: puuid.to_string(),
+        queue_id: game.json.queue_id,
+        game_mode: game.json.game_mode.clone(),
+        game_creation: game.json.game_creation,
+        game_duration: game.json.game_duration,
+        win: players
+            .iter()
+            .find(|player| player.puuid == puuid)
+            .is_some_and(|player| player.win),
+        players,
+        timeline: detail.map(|detail| analyze_timeline(detail, Some(game))),
+        lp_change,
+    }
+}
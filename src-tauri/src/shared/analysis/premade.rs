@@ -0,0 +1,158 @@
+//! 开黑检测
+//!
+//! 统计当前对局中同队的每两名玩家在近期对局里作为队友一起出现的次数，
+//! 多次同队的玩家视为一起排队，按连通关系合并成车队并给出置信度。
+
+use crate::shared::types::sgp::history::Games;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// 至少一起同队这么多局才视为开黑
+const MIN_SHARED_GAMES: usize = 2;
+/// 一起同队达到这么多局时置信度为 1
+const CONFIDENT_SHARED_GAMES: usize = 4;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PremadeCandidate {
+    pub puuid: String,
+    /// 当前对局中的队伍，只在同队玩家之间检测
+    pub team_id: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PartyGroup {
+    /// 车队编号，从 1 开始
+    pub id: usize,
+    pub team_id: i64,
+    pub puuids: Vec<String>,
+    /// 车队中两两同队次数的最大值
+    pub shared_games: usize,
+    /// 0~1
+    pub confidence: f64,
+}
+
+/// 附加在玩家信息上的车队标记
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PartyTag {
+    pub group_id: usize,
+    pub size: usize,
+    pub confidence: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PremadeReport {
+    pub groups: Vec<PartyGroup>,
+}
+
+impl PremadeReport {
+    pub fn tag_of(&self, puuid: &str) -> Option<PartyTag> {
+        self.groups
+            .iter()
+            .find(|group| group.puuids.iter().any(|p| p == puuid))
+            .map(|group| PartyTag {
+                group_id: group.id,
+                size: group.puuids.len(),
+                confidence: group.confidence,
+            })
+    }
+}
+
+fn confidence(shared_games: usize) -> f64 {
+    (shared_games as f64 / CONFIDENT_SHARED_GAMES as f64).min(1.0)
+}
+
+/// 检测开黑
+///
+/// # 参数
+/// - `players`: 当前对局的玩家
+/// - `games`: 这些玩家的近期对局（可以有重复，会按 match_id 去重）
+pub fn detect_premades(players: &[PremadeCandidate], games: &[Arc<Games>]) -> PremadeReport {
+    let players: Vec<&PremadeCandidate> = players.iter().filter(|p| !p.puuid.is_empty()).collect();
+    let index_of: HashMap<&str, usize> = players
+        .iter()
+        .enumerate()
+        .map(|(index, player)| (player.puuid.as_str(), index))
+        .collect();
+
+    // (i, j) -> 同队次数，i < j
+    let mut shared: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut seen: HashSet<&str> = HashSet::new();
+    for game in games {
+        if !seen.insert(game.metadata.match_id.as_str()) {
+            continue;
+        }
+        let mut teams: HashMap<i64, Vec<usize>> = HashMap::new();
+        for participant in &game.json.participants {
+            if let Some(index) = index_of.get(participant.puuid.as_str()) {
+                teams.entry(participant.team_id).or_default().push(*index);
+            }
+        }
+        for members in teams.values_mut() {
+            members.sort();
+            for (n, a) in members.iter().enumerate() {
+                for b in &members[n + 1..] {
+                    if players[*a].team_id == players[*b].team_id {
+                        *shared.entry((*a, *b)).or_default() += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    // 并查集合并车队
+    let mut parent: Vec<usize> = (0..players.len()).collect();
+    fn find(parent: &mut [usize], x: usize) -> usize {
+        if parent[x] != x {
+            let root = find(parent, parent[x]);
+            parent[x] = root;
+        }
+        parent[x]
+    }
+    let edges: Vec<((usize, usize), usize)> = shared
+        .into_iter()
+        .filter(|(_, count)| *count >= MIN_SHARED_GAMES)
+        .collect();
+    for ((a, b), _) in &edges {
+        let (root_a, root_b) = (find(&mut parent, *a), find(&mut parent, *b));
+        parent[root_a.max(root_b)] = root_a.min(root_b);
+    }
+
+    // 每名玩家与车队中其他人最多的同队次数，车队置信度取其中的最小值
+    let mut best: HashMap<usize, usize> = HashMap::new();
+    for ((a, b), count) in &edges {
+        for index in [a, b] {
+            let entry = best.entry(*index).or_default();
+            *entry = (*entry).max(*count);
+        }
+    }
+    let mut members: HashMap<usize, Vec<usize>> = HashMap::new();
+    for index in best.keys() {
+        let root = find(&mut parent, *index);
+        members.entry(root).or_default().push(*index);
+    }
+
+    let mut groups: Vec<(usize, Vec<usize>)> = members.into_iter().collect();
+    groups.sort_by_key(|(root, _)| *root);
+    PremadeReport {
+        groups: groups
+            .into_iter()
+            .enumerate()
+            .map(|(n, (_, mut indexes))| {
+                indexes.sort();
+                let weakest = indexes.iter().map(|i| best[i]).min().unwrap_or_default();
+                PartyGroup {
+                    id: n + 1,
+                    team_id: players[indexes[0]].team_id,
+                    puuids: indexes.iter().map(|i| players[*i].puuid.clone()).collect(),
+                    shared_games: indexes.iter().map(|i| best[i]).max().unwrap_or_default(),
+                    confidence: confidence(weakest),
+                }
+            })
+            .collect(),
+    }
+}
@@ -0,0 +1,199 @@
+//! 位置判定
+//!
+//! SGP 战绩中的 `lane` 经常是 NONE，辅助也常被记成 BOTTOM。这里综合 `team_position`、
+//! `individual_position`、是否携带惩戒以及补刀构成，为每支五人队伍分配上、野、中、下、辅五个位置，
+//! 再把两队同一位置的玩家互相作为对位对手。
+
+use crate::shared::types::sgp::history::Participant;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// 惩戒的召唤师技能 id
+pub const SMITE_SPELL_ID: i64 = 11;
+
+/// 官方判定的位置
+const TEAM_POSITION_WEIGHT: f64 = 10.0;
+/// 按游戏内行为判定的位置
+const INDIVIDUAL_POSITION_WEIGHT: f64 = 4.0;
+/// 原始的 `lane` 字段
+const LANE_WEIGHT: f64 = 2.0;
+/// 携带惩戒视为打野
+const SMITE_WEIGHT: f64 = 8.0;
+/// 野怪补刀占比较高视为打野
+const JUNGLE_CS_WEIGHT: f64 = 4.0;
+const JUNGLE_CS_SHARE: f64 = 0.5;
+/// 补刀远低于队伍平均视为辅助
+const SUPPORT_CS_WEIGHT: f64 = 3.0;
+const SUPPORT_CS_RATIO: f64 = 0.4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Role {
+    Top,
+    Jungle,
+    Mid,
+    Bottom,
+    Utility,
+}
+
+impl Role {
+    pub const ALL: [Role; 5] = [
+        Role::Top,
+        Role::Jungle,
+        Role::Mid,
+        Role::Bottom,
+        Role::Utility,
+    ];
+
+    /// 解析 Riot 的位置字符串，无法识别（如 NONE、Invalid 或空字符串）时返回 `None`
+    pub fn parse(value: &str) -> Option<Role> {
+        match value.to_ascii_uppercase().as_str() {
+            "TOP" => Some(Role::Top),
+            "JUNGLE" => Some(Role::Jungle),
+            "MID" | "MIDDLE" => Some(Role::Mid),
+            "BOTTOM" | "BOT" | "ADC" | "CARRY" => Some(Role::Bottom),
+            "UTILITY" | "SUPPORT" => Some(Role::Utility),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Top => "TOP",
+            Role::Jungle => "JUNGLE",
+            Role::Mid => "MID",
+            Role::Bottom => "BOTTOM",
+            Role::Utility => "UTILITY",
+        }
+    }
+}
+
+/// 玩家在每个位置上的得分，顺序与 `Role::ALL` 一致
+fn role_scores(participant: &Participant, average_cs: f64) -> [f64; 5] {
+    let mut scores = [0.0; 5];
+    let mut add = |role: Option<Role>, weight: f64| {
+        if let Some(role) = role {
+            scores[role as usize] += weight;
+        }
+    };
+    add(
+        Role::parse(&participant.team_position),
+        TEAM_POSITION_WEIGHT,
+    );
+    add(
+        Role::parse(&participant.individual_position),
+        INDIVIDUAL_POSITION_WEIGHT,
+    );
+    add(Role::parse(&participant.lane), LANE_WEIGHT);
+
+    if participant.spell1id == SMITE_SPELL_ID || participant.spell2id == SMITE_SPELL_ID {
+        add(Some(Role::Jungle), SMITE_WEIGHT);
+    }
+    let cs = participant.total_minions_killed + participant.neutral_minions_killed;
+    if cs > 0 && participant.neutral_minions_killed as f64 / cs as f64 >= JUNGLE_CS_SHARE {
+        add(Some(Role::Jungle), JUNGLE_CS_WEIGHT);
+    }
+    if average_cs > 0.0 && (cs as f64) < average_cs * SUPPORT_CS_RATIO {
+        add(Some(Role::Utility), SUPPORT_CS_WEIGHT);
+    }
+    scores
+}
+
+fn has_signal(participant: &Participant) -> bool {
+    Role::parse(&participant.team_position).is_some()
+        || Role::parse(&participant.individual_position).is_some()
+        || participant.spell1id == SMITE_SPELL_ID
+        || participant.spell2id == SMITE_SPELL_ID
+}
+
+/// 在所有排列中找出总得分最高的位置分配，返回每名玩家在 `Role::ALL` 中的下标
+fn best_assignment(scores: &[[f64; 5]]) -> Vec<usize> {
+    fn search(
+        scores: &[[f64; 5]],
+        current: &mut Vec<usize>,
+        total: f64,
+        best: &mut (f64, Vec<usize>),
+    ) {
+        if current.len() == scores.len() {
+            if total > best.0 {
+                *best = (total, current.clone());
+            }
+            return;
+        }
+        let player = current.len();
+        for role in 0..Role::ALL.len() {
+            if current.contains(&role) {
+                continue;
+            }
+            current.push(role);
+            search(scores, current, total + scores[player][role], best);
+            current.pop();
+        }
+    }
+
+    let mut best = (f64::MIN, vec![]);
+    search(scores, &mut vec![], 0.0, &mut best);
+    best.1
+}
+
+/// 判定每名玩家的位置，返回值与 `participants` 顺序一致
+///
+/// 只处理恰好五人的队伍，并且队伍中至少有一名玩家带有位置信息；
+/// 大乱斗、斗魂竞技场等没有分路的模式返回 `None`。
+pub fn resolve_roles(participants: &[Participant]) -> Vec<Option<Role>> {
+    let mut roles = vec![None; participants.len()];
+    let mut teams: BTreeMap<i64, Vec<usize>> = BTreeMap::new();
+    for (index, participant) in participants.iter().enumerate() {
+        teams.entry(participant.team_id).or_default().push(index);
+    }
+
+    for members in teams.values() {
+        if members.len() != Role::ALL.len()
+            || !members.iter().any(|i| has_signal(&participants[*i]))
+        {
+            continue;
+        }
+        let average_cs = members
+            .iter()
+            .map(|i| {
+                let p = &participants[*i];
+                (p.total_minions_killed + p.neutral_minions_killed) as f64
+            })
+            .sum::<f64>()
+            / members.len() as f64;
+        let scores: Vec<[f64; 5]> = members
+            .iter()
+            .map(|i| role_scores(&participants[*i], average_cs))
+            .collect();
+        for (index, role) in members.iter().zip(best_assignment(&scores)) {
+            roles[*index] = Some(Role::ALL[role]);
+        }
+    }
+    roles
+}
+
+/// 判定某名玩家的位置，玩家不在对局中或没有分路时返回 `None`
+pub fn player_role(participants: &[Participant], puuid: &str) -> Option<Role> {
+    let index = participants.iter().position(|p| p.puuid == puuid)?;
+    resolve_roles(participants)[index]
+}
+
+/// 每名玩家的对位对手在 `participants` 中的下标
+///
+/// # 参数
+/// - `roles`: `resolve_roles` 的结果
+pub fn lane_opponents(participants: &[Participant], roles: &[Option<Role>]) -> Vec<Option<usize>> {
+    participants
+        .iter()
+        .zip(roles)
+        .map(|(participant, role)| {
+            let role = (*role)?;
+            participants
+                .iter()
+                .zip(roles)
+                .position(|(other, other_role)| {
+                    other.team_id != participant.team_id && *other_role == Some(role)
+                })
+        })
+        .collect()
+}
@@ -0,0 +1,262 @@
+//! 对局评分
+//!
+//! 根据 KDA、参团率、伤害/经济/视野占比、目标伤害和分均补刀为每位玩家计算 0~10 的评分，
+//! 并在每支队伍中标记最高分：胜方为 MVP，败方为 ACE。斗魂竞技场按小队计算。
+
+use crate::shared::types::league_client::match_history::Participant as LcuParticipant;
+use crate::shared::types::sgp::history::{Games, Participant as SgpParticipant};
+use crate::utils::config::score::{ScoreConfig, ScoreWeights};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 评分所需的单个玩家数据，SGP 和 LCU 的战绩都先转换成这个结构
+#[derive(Debug, Clone, Default)]
+pub struct ScoreInput {
+    /// 汇总队伍数据和标记最佳的分组：斗魂竞技场为小队，其他模式为队伍
+    pub group_id: i64,
+    pub win: bool,
+    pub kills: i64,
+    pub deaths: i64,
+    pub assists: i64,
+    pub damage_to_champions: i64,
+    pub gold_earned: i64,
+    pub vision_score: i64,
+    pub damage_to_objectives: i64,
+    /// 补刀数（小兵 + 野怪）
+    pub cs: i64,
+}
+
+impl ScoreInput {
+    pub fn from_sgp(participant: &SgpParticipant) -> Self {
+        Self {
+            group_id: group_id(participant.player_subteam_id, participant.team_id),
+            win: participant.win,
+            kills: participant.kills,
+            deaths: participant.deaths,
+            assists: participant.assists,
+            damage_to_champions: participant.total_damage_dealt_to_champions,
+            gold_earned: participant.gold_earned,
+            vision_score: participant.vision_score,
+            damage_to_objectives: participant.damage_dealt_to_objectives,
+            cs: participant.total_minions_killed + participant.neutral_minions_killed,
+        }
+    }
+
+    pub fn from_lcu(participant: &LcuParticipant) -> Self {
+        let stats = &participant.stats;
+        Self {
+            group_id: group_id(stats.player_subteam_id as i64, participant.team_id as i64),
+            win: stats.win,
+            kills: stats.kills as i64,
+            deaths: stats.deaths as i64,
+            assists: stats.assists as i64,
+            damage_to_champions: stats.total_damage_dealt_to_champions as i64,
+            gold_earned: stats.gold_earned as i64,
+            vision_score: stats.vision_score as i64,
+            damage_to_objectives: stats.damage_dealt_to_objectives as i64,
+            cs: (stats.total_minions_killed + stats.neutral_minions_killed) as i64,
+        }
+    }
+}
+
+/// 有小队（斗魂竞技场）时按小队分组，否则按队伍分组
+fn group_id(subteam_id: i64, team_id: i64) -> i64 {
+    if subteam_id != 0 {
+        subteam_id
+    } else {
+        team_id
+    }
+}
+
+/// 队内最佳标记
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ScoreBadge {
+    /// 胜方最高分
+    Mvp,
+    /// 败方最高分
+    Ace,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoreResult {
+    pub score: f64,
+    pub badge: Option<ScoreBadge>,
+}
+
+/// 各项原始指标（归一化之前）
+#[derive(Debug, Clone, Copy, Default)]
+struct Metrics {
+    kda: f64,
+    kill_participation: f64,
+    damage_share: f64,
+    gold_share: f64,
+    vision_share: f64,
+    objective_damage: f64,
+    cs_per_minute: f64,
+}
+
+impl Metrics {
+    fn weighted(&self, weights: &ScoreWeights) -> f64 {
+        self.kda * weights.kda
+            + self.kill_participation * weights.kill_participation
+            + self.damage_share * weights.damage_share
+            + self.gold_share * weights.gold_share
+            + self.vision_share * weights.vision_share
+            + self.objective_damage * weights.objective_damage
+            + self.cs_per_minute * weights.cs_per_minute
+    }
+
+    fn max(&self, other: &Metrics) -> Metrics {
+        Metrics {
+            kda: self.kda.max(other.kda),
+            kill_participation: self.kill_participation.max(other.kill_participation),
+            damage_share: self.damage_share.max(other.damage_share),
+            gold_share: self.gold_share.max(other.gold_share),
+            vision_share: self.vision_share.max(other.vision_share),
+            objective_damage: self.objective_damage.max(other.objective_damage),
+            cs_per_minute: self.cs_per_minute.max(other.cs_per_minute),
+        }
+    }
+
+    /// 以全场最大值为基准归一化到 0~1
+    fn normalize(&self, max: &Metrics) -> Metrics {
+        Metrics {
+            kda: ratio(self.kda, max.kda),
+            kill_participation: ratio(self.kill_participation, max.kill_participation),
+            damage_share: ratio(self.damage_share, max.damage_share),
+            gold_share: ratio(self.gold_share, max.gold_share),
+            vision_share: ratio(self.vision_share, max.vision_share),
+            objective_damage: ratio(self.objective_damage, max.objective_damage),
+            cs_per_minute: ratio(self.cs_per_minute, max.cs_per_minute),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct TeamTotals {
+    kills: i64,
+    damage_to_champions: i64,
+    gold_earned: i64,
+    vision_score: i64,
+    damage_to_objectives: i64,
+}
+
+fn ratio(value: f64, total: f64) -> f64 {
+    if total > 0.0 {
+        value / total
+    } else {
+        0.0
+    }
+}
+
+pub fn kda(kills: i64, deaths: i64, assists: i64) -> f64 {
+    (kills + assists) as f64 / deaths.max(1) as f64
+}
+
+/// 计算一局中所有玩家的评分，返回值与 `inputs` 顺序一致
+///
+/// # 参数
+/// - `inputs`: 所有玩家的数据
+/// - `duration_secs`: 对局时长（秒）
+/// - `weights`: 当前游戏模式的权重
+pub fn compute_scores(
+    inputs: &[ScoreInput],
+    duration_secs: i64,
+    weights: &ScoreWeights,
+) -> Vec<ScoreResult> {
+    let mut team_totals: HashMap<i64, TeamTotals> = HashMap::new();
+    for input in inputs {
+        let totals = team_totals.entry(input.group_id).or_default();
+        totals.kills += input.kills;
+        totals.damage_to_champions += input.damage_to_champions;
+        totals.gold_earned += input.gold_earned;
+        totals.vision_score += input.vision_score;
+        totals.damage_to_objectives += input.damage_to_objectives;
+    }
+
+    let minutes = duration_secs as f64 / 60.0;
+    let metrics: Vec<Metrics> = inputs
+        .iter()
+        .map(|input| {
+            let totals = team_totals[&input.group_id];
+            Metrics {
+                kda: kda(input.kills, input.deaths, input.assists),
+                kill_participation: ratio(
+                    (input.kills + input.assists) as f64,
+                    totals.kills as f64,
+                ),
+                damage_share: ratio(
+                    input.damage_to_champions as f64,
+                    totals.damage_to_champions as f64,
+                ),
+                gold_share: ratio(input.gold_earned as f64, totals.gold_earned as f64),
+                vision_share: ratio(input.vision_score as f64, totals.vision_score as f64),
+                objective_damage: ratio(
+                    input.damage_to_objectives as f64,
+                    totals.damage_to_objectives as f64,
+                ),
+                cs_per_minute: ratio(input.cs as f64, minutes),
+            }
+        })
+        .collect();
+
+    let max = metrics
+        .iter()
+        .fold(Metrics::default(), |acc, metric| acc.max(metric));
+    let total_weight = Metrics {
+        kda: 1.0,
+        kill_participation: 1.0,
+        damage_share: 1.0,
+        gold_share: 1.0,
+        vision_share: 1.0,
+        objective_damage: 1.0,
+        cs_per_minute: 1.0,
+    }
+    .weighted(weights);
+
+    let mut results: Vec<ScoreResult> = metrics
+        .iter()
+        .map(|metric| {
+            let score = 10.0 * ratio(metric.normalize(&max).weighted(weights), total_weight);
+            ScoreResult {
+                score: (score * 10.0).round() / 10.0,
+                badge: None,
+            }
+        })
+        .collect();
+
+    // 每支队伍（斗魂竞技场为小队）中分数最高的玩家：胜方 MVP，败方 ACE（同分取靠前的玩家）
+    let mut best_of_team: HashMap<i64, usize> = HashMap::new();
+    for (index, input) in inputs.iter().enumerate() {
+        let best = best_of_team.entry(input.group_id).or_insert(index);
+        if results[index].score > results[*best].score {
+            *best = index;
+        }
+    }
+    for index in best_of_team.into_values() {
+        results[index].badge = Some(if inputs[index].win {
+            ScoreBadge::Mvp
+        } else {
+            ScoreBadge::Ace
+        });
+    }
+
+    results
+}
+
+/// 计算 SGP 对局中所有玩家的评分，返回值与 `game.json.participants` 顺序一致
+pub fn score_sgp_game(game: &Games, config: &ScoreConfig) -> Vec<ScoreResult> {
+    let inputs: Vec<ScoreInput> = game
+        .json
+        .participants
+        .iter()
+        .map(ScoreInput::from_sgp)
+        .collect();
+    compute_scores(
+        &inputs,
+        game.json.game_duration,
+        config.weights_for(&game.json.game_mode),
+    )
+}
@@ -0,0 +1,67 @@
+use super::fixture_solo_game;
+use crate::shared::analysis::arena::{arena_stats, ARENA_GAME_MODE};
+use crate::shared::fixture::GameBuilder;
+use crate::shared::types::sgp::history::Games;
+use std::sync::Arc;
+
+/// 八支两人小队的斗魂竞技场对局，"me" 与 "mate" 同队，`augments` 为 "me" 的强化符文
+fn fixture_arena_game(match_id: &str, placement: i64, augments: [i64; 3]) -> Arc<Games> {
+    (0..16)
+        .fold(
+            GameBuilder::new(match_id).mode(ARENA_GAME_MODE),
+            |builder, i| {
+                let subteam = i / 2 + 1;
+                let puuid = match i {
+                    0 => "me".to_string(),
+                    1 => "mate".to_string(),
+                    _ => format!("p{}", i),
+                };
+                builder.player(&puuid, 100 + 100 * (i % 2)).with(|p| {
+                    p.player_subteam_id = subteam;
+                    p.placement = if subteam == 1 {
+                        placement
+                    } else if subteam <= placement {
+                        subteam - 1
+                    } else {
+                        subteam
+                    };
+                })
+            },
+        )
+        .edit("me", |me| {
+            me.player_augment1 = augments[0];
+            me.player_augment2 = augments[1];
+            me.player_augment3 = augments[2];
+        })
+        .arc()
+}
+
+#[test]
+fn test_arena_stats_placement_and_augments() {
+    let games = vec![
+        fixture_arena_game("A1", 1, [10, 20, 0]),
+        fixture_arena_game("A2", 3, [10, 30, 0]),
+        fixture_arena_game("A3", 6, [20, 30, 40]),
+        fixture_arena_game("A4", 8, [30, 0, 0]),
+        // 其他模式的对局会被忽略
+        fixture_solo_game("G1", true).created(1000).queue(420).arc(),
+    ];
+
+    let report = arena_stats(&games, "me", None);
+    assert_eq!(report.overall.games, 4);
+    assert_eq!(report.overall.average_placement, 4.5);
+    assert_eq!(report.overall.top4_rate, 0.5);
+    assert_eq!(report.overall.first_rate, 0.25);
+
+    // 10: 1/3 -> 2.0，20: 1/6 -> 3.5，30: 3/6/8 -> 5.67，40 只选过一局排在最后
+    let augments: Vec<(i64, usize)> = report
+        .augments
+        .iter()
+        .map(|augment| (augment.augment_id, augment.stats.games))
+        .collect();
+    assert_eq!(augments, vec![(10, 2), (20, 2), (30, 3), (40, 1)]);
+
+    let report = arena_stats(&games, "me", Some(2));
+    assert_eq!(report.overall.games, 2);
+    assert_eq!(report.overall.average_placement, 2.0);
+}
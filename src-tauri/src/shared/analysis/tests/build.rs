@@ -0,0 +1,88 @@
+use crate::shared::analysis::build::extract_builds;
+use crate::shared::init::game_data::Item as GameDataItem;
+use crate::shared::types::sgp::game_detail::{
+    Event, Frames, Participant as DetailParticipant, SgpGameDetailsLol,
+};
+use std::collections::HashMap;
+
+#[test]
+fn test_extract_builds_resolves_undo_and_sell() {
+    let mut detail = SgpGameDetailsLol::default();
+    detail.json.participants = vec![DetailParticipant {
+        participant_id: 1,
+        puuid: "p1".to_string(),
+    }];
+    let item_event = |type_field: &str, timestamp: i64, item_id: i64| Event {
+        type_field: type_field.to_string(),
+        timestamp,
+        participant_id: Some(1),
+        item_id: Some(item_id),
+        ..Default::default()
+    };
+    let skill = |timestamp: i64, slot: i64| Event {
+        type_field: "SKILL_LEVEL_UP".to_string(),
+        timestamp,
+        participant_id: Some(1),
+        skill_slot: Some(slot),
+        level_up_type: Some("NORMAL".to_string()),
+        ..Default::default()
+    };
+    let mut events = vec![
+        item_event("ITEM_PURCHASED", 1_000, 1055),
+        item_event("ITEM_PURCHASED", 2_000, 2003),
+        // 买错了，撤销
+        item_event("ITEM_PURCHASED", 300_000, 1036),
+        Event {
+            before_id: Some(1036),
+            after_id: Some(0),
+            ..item_event("ITEM_UNDO", 301_000, 0)
+        },
+        item_event("ITEM_PURCHASED", 600_000, 3031),
+        item_event("ITEM_SOLD", 1_500_000, 1055),
+    ];
+    // Q 主升，E 副升
+    for (index, slot) in [1, 3, 2, 1, 1, 4, 1, 1, 3, 3, 4, 3, 3]
+        .into_iter()
+        .enumerate()
+    {
+        events.push(skill(index as i64 * 60_000, slot));
+    }
+    detail.json.frames = vec![Frames {
+        events,
+        ..Default::default()
+    }];
+
+    let items: HashMap<i64, GameDataItem> = [
+        (1055, "多兰之刃", vec![], vec![1038]),
+        (2003, "生命药水", vec![], vec![]),
+        (3031, "无尽之刃", vec![1038, 1018], vec![]),
+    ]
+    .into_iter()
+    .map(|(id, name, from, to)| {
+        let item = GameDataItem {
+            id,
+            name: name.to_string(),
+            from,
+            to,
+            ..Default::default()
+        };
+        (id, item)
+    })
+    .collect();
+
+    let builds = extract_builds(&detail, &items);
+    let build = &builds[0];
+    let starting: Vec<(i64, Option<i64>)> = build
+        .starting_items
+        .iter()
+        .map(|item| (item.item_id, item.sold_at))
+        .collect();
+    assert_eq!(starting, vec![(1055, Some(1_500_000)), (2003, None)]);
+    assert_eq!(build.starting_items[0].name, "多兰之刃");
+    let order: Vec<i64> = build.build_order.iter().map(|item| item.item_id).collect();
+    assert_eq!(order, vec![3031]);
+    assert_eq!(build.completed_items[0].name, "无尽之刃");
+
+    assert_eq!(build.skill_order.len(), 13);
+    assert_eq!(build.skill_max_order, vec!["Q", "E", "W"]);
+}
@@ -0,0 +1,33 @@
+use crate::shared::analysis::head_to_head::head_to_head;
+use crate::shared::fixture::GameBuilder;
+
+#[test]
+fn test_head_to_head_splits_same_and_opposite_team() {
+    // 胜负只设置 a
+    let game = |match_id: &str, game_creation: i64, a_team: i64, other: (&str, i64), win: bool| {
+        GameBuilder::new(match_id)
+            .created(game_creation)
+            .player("a", a_team)
+            .win(win)
+            .player(other.0, other.1)
+            .arc()
+    };
+    let games = vec![
+        game("H1", 1000, 100, ("b", 100), true),
+        game("H2", 2000, 100, ("b", 200), true),
+        game("H3", 3000, 200, ("b", 200), false),
+        game("H4", 4000, 100, ("c", 200), true),
+        // 重复的对局只统计一次
+        game("H2", 2000, 100, ("b", 200), true),
+    ];
+
+    let report = head_to_head(&games, "a", "b");
+    let ids: Vec<&str> = report.games.iter().map(|g| g.match_id.as_str()).collect();
+    assert_eq!(ids, vec!["H3", "H2", "H1"]);
+    assert_eq!((report.together.games, report.together.wins), (2, 1));
+    assert_eq!(report.together.win_rate, 0.5);
+    assert_eq!((report.against.games, report.against.wins), (1, 1));
+    assert!(report.games[0].same_team);
+    assert!(!report.games[1].same_team);
+    assert!(report.games[1].player.win);
+}
@@ -0,0 +1,36 @@
+use super::fixture_detail;
+use crate::shared::analysis::heatmap::{build_heatmap, TimeWindow};
+use crate::shared::fixture::GameBuilder;
+use crate::shared::types::sgp::game_detail::{Event, Position};
+
+#[test]
+fn test_heatmap_buckets_by_window_and_role() {
+    let mut detail = fixture_detail();
+    // 第 20 分钟 1 号在地图右上角击杀 6 号，2 号助攻
+    detail.json.frames[16].events = vec![Event {
+        type_field: "CHAMPION_KILL".to_string(),
+        timestamp: 20 * 60_000,
+        killer_id: Some(1),
+        victim_id: Some(6),
+        assisting_participant_ids: vec![2],
+        position: Some(Position { x: 14000, y: 14000 }),
+        ..Default::default()
+    }];
+    let summary = GameBuilder::new("HN1_1").map(11).build();
+
+    let report = build_heatmap(&[(&summary, &detail)], "p1", 4);
+    assert_eq!(report.maps.len(), 1);
+    assert_eq!(report.maps[0].games, 1);
+    // 前期的击杀事件没有坐标，只有中期有数据
+    let windows: Vec<TimeWindow> = report.maps[0].windows.iter().map(|w| w.window).collect();
+    assert_eq!(windows, vec![TimeWindow::Mid]);
+    let mid = &report.maps[0].windows[0];
+    assert_eq!(mid.kills.total, 1);
+    assert_eq!(mid.kills.cells[0][3], 1.0);
+    assert_eq!(mid.deaths.total, 0);
+
+    let report = build_heatmap(&[(&summary, &detail)], "p2", 4);
+    assert_eq!(report.maps[0].windows[0].assists.cells[0][3], 1.0);
+    let report = build_heatmap(&[(&summary, &detail)], "p6", 4);
+    assert_eq!(report.maps[0].windows[0].deaths.total, 1);
+}
@@ -0,0 +1,91 @@
+use super::fixture_solo_game;
+use crate::shared::analysis::indicator::{compute_indicators, IndicatorKind};
+use crate::shared::types::sgp::history::Games;
+use crate::utils::config::indicator::IndicatorConfig;
+use std::sync::Arc;
+
+/// `wins` 从新到旧，`level` 为召唤师等级
+fn fixture_indicator_history(wins: &[bool], level: i64) -> Vec<Games> {
+    wins.iter()
+        .enumerate()
+        .map(|(i, win)| {
+            fixture_solo_game(&format!("I{}", i), *win)
+                .created(10_000 - i as i64 * 100)
+                .queue(420)
+                .edit("me", |me| me.summoner_level = level)
+                .build()
+        })
+        .collect()
+}
+
+#[test]
+fn test_indicators_streak_remake_and_low_effort() {
+    let config = IndicatorConfig::default();
+    let mut games = fixture_indicator_history(
+        &[
+            false, false, false, true, true, false, true, true, true, true,
+        ],
+        200,
+    );
+    // 两局重开，不参与其他指标
+    games[4].json.game_duration = 200;
+    games[6].json.game_duration = 180;
+    // 两局伤害和经济都很低
+    for index in [3, 7] {
+        let me = &mut games[index].json.participants[0];
+        me.total_damage_dealt_to_champions = 500;
+        me.gold_earned = 1000;
+    }
+    let games: Vec<Arc<Games>> = games.into_iter().map(Arc::new).collect();
+
+    let indicators = compute_indicators(&games, "me", &config);
+    let kinds: Vec<IndicatorKind> = indicators.iter().map(|i| i.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            IndicatorKind::LossStreak,
+            IndicatorKind::FrequentRemakes,
+            IndicatorKind::LowEffort
+        ]
+    );
+    assert_eq!(indicators[0].value, 3.0);
+    assert_eq!(indicators[0].match_ids, vec!["I0", "I1", "I2"]);
+    assert_eq!(indicators[1].match_ids, vec!["I4", "I6"]);
+    assert_eq!(indicators[2].match_ids, vec!["I3", "I7"]);
+
+    // 重开局数低于最少局数时不提示
+    let strict = IndicatorConfig {
+        min_remakes: 3,
+        ..config
+    };
+    assert!(compute_indicators(&games, "me", &strict)
+        .iter()
+        .all(|i| i.kind != IndicatorKind::FrequentRemakes));
+}
+
+#[test]
+fn test_indicators_smurf_and_thresholds() {
+    let games: Vec<Arc<Games>> = fixture_indicator_history(&[true; 8], 30)
+        .into_iter()
+        .map(Arc::new)
+        .collect();
+    let kinds = |config: &IndicatorConfig| -> Vec<IndicatorKind> {
+        compute_indicators(&games, "me", config)
+            .iter()
+            .map(|i| i.kind)
+            .collect()
+    };
+
+    let config = IndicatorConfig::default();
+    assert_eq!(
+        kinds(&config),
+        vec![IndicatorKind::WinStreak, IndicatorKind::Smurf]
+    );
+    // 等级阈值调低后不再提示小号，连胜阈值调高后不再提示连胜
+    let strict = IndicatorConfig {
+        smurf_max_level: 20,
+        streak_length: 9,
+        ..config
+    };
+    assert!(kinds(&strict).is_empty());
+}
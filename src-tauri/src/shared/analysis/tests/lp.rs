@@ -0,0 +1,70 @@
+use crate::shared::analysis::lp::{absolute_lp, lp_changes, rank_over_time};
+use crate::shared::store::rank_store::RankSnapshot;
+
+fn fixture_rank(
+    timestamp: i64,
+    game_id: Option<i64>,
+    rank: (&str, &str, i64),
+    wins: i64,
+    losses: i64,
+) -> RankSnapshot {
+    RankSnapshot {
+        timestamp,
+        game_id,
+        queue_type: "RANKED_SOLO_5x5".to_string(),
+        tier: rank.0.to_string(),
+        division: rank.1.to_string(),
+        league_points: rank.2,
+        wins,
+        losses,
+    }
+}
+
+#[test]
+fn test_absolute_lp() {
+    assert_eq!(absolute_lp("GOLD", "II", 75), Some(1475));
+    assert_eq!(absolute_lp("IRON", "IV", 0), Some(0));
+    assert_eq!(absolute_lp("MASTER", "NA", 120), Some(2920));
+    assert_eq!(absolute_lp("CHALLENGER", "I", 900), Some(3700));
+    assert_eq!(absolute_lp("", "", 0), None);
+}
+
+#[test]
+fn test_lp_changes_across_promotion_and_gaps() {
+    let mut snapshots = vec![
+        fixture_rank(1, None, ("GOLD", "I", 80), 10, 10),
+        // 晋级：黄金 I 80 -> 铂金 IV 5
+        fixture_rank(2, Some(101), ("PLATINUM", "IV", 5), 11, 10),
+        fixture_rank(3, Some(102), ("GOLD", "I", 75), 11, 11),
+        // 中间漏记了两局
+        fixture_rank(4, None, ("GOLD", "I", 95), 13, 12),
+        // 赛季重置
+        fixture_rank(5, None, ("", "", 0), 0, 0),
+    ];
+    // 其他队列的快照不影响结果
+    snapshots.insert(
+        2,
+        RankSnapshot {
+            queue_type: "RANKED_FLEX_SR".to_string(),
+            ..fixture_rank(2, Some(101), ("SILVER", "II", 10), 1, 0)
+        },
+    );
+
+    let changes = lp_changes(&snapshots, "RANKED_SOLO_5x5");
+    let summary: Vec<(Option<i64>, i64, i64, Option<bool>)> = changes
+        .iter()
+        .map(|c| (c.game_id, c.lp_delta, c.games, c.win))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (Some(101), 25, 1, Some(true)),
+            (Some(102), -30, 1, Some(false)),
+            (None, 20, 3, None),
+        ]
+    );
+
+    let history = rank_over_time(&snapshots, "RANKED_SOLO_5x5");
+    assert_eq!(history.len(), 4);
+    assert_eq!(history[1].absolute_lp, 1605);
+}
@@ -0,0 +1,62 @@
+use super::fixture_detail;
+use crate::shared::analysis::matchup::{matchup_samples, picks_into, versus_enemies};
+use crate::shared::analysis::role::Role;
+use crate::shared::fixture::GameBuilder;
+use std::collections::HashMap;
+
+#[test]
+fn test_matchup_stats_group_by_enemy_and_pick() {
+    // p1 上单使用 `champion_id` 对位 p6 的 `enemy_champion_id`
+    let game = |match_id: &str, champion_id: i64, enemy_champion_id: i64, win: bool| {
+        GameBuilder::lobby(match_id)
+            .each(|p| {
+                p.kills = 2;
+                p.deaths = 1;
+                p.assists = 3;
+            })
+            .edit("p1", |p| p.champion_id = champion_id)
+            .edit("p6", |p| p.champion_id = enemy_champion_id)
+            .winner(if win { 100 } else { 200 })
+    };
+    let games = vec![
+        game("HN1_1", 103, 200, true).arc(),
+        game("HN1_2", 103, 200, false).arc(),
+        game("HN1_3", 222, 200, true).arc(),
+        game("HN1_4", 103, 300, true).arc(),
+        // 没有分路的对局不参与统计
+        game("HN1_5", 103, 200, true)
+            .each(|p| p.team_position.clear())
+            .arc(),
+    ];
+    // 只有 HN1_1 有时间线：15 分钟时 p1 多 1500 经济，少 150 补刀
+    let details = HashMap::from([("HN1_1".to_string(), fixture_detail())]);
+
+    let samples = matchup_samples(&games, &details, "p1");
+    assert_eq!(samples.len(), 4);
+    assert_eq!(samples[0].role, Some(Role::Top));
+    assert_eq!(samples[0].gold_diff_at_15, Some(1500));
+    assert_eq!(samples[0].cs_diff_at_15, Some(-150));
+    assert_eq!(samples[1].gold_diff_at_15, None);
+
+    let versus = versus_enemies(&samples, None, None);
+    let rows: Vec<(i64, usize, usize)> = versus
+        .iter()
+        .map(|stats| (stats.champion_id, stats.games, stats.wins))
+        .collect();
+    assert_eq!(rows, vec![(200, 3, 2), (300, 1, 1)]);
+    assert_eq!(versus[0].gold_diff_at_15, Some(1500.0));
+    assert_eq!(versus[0].timeline_games, 1);
+    assert_eq!(versus[0].kda, 5.0);
+    assert_eq!(versus[1].gold_diff_at_15, None);
+
+    let versus = versus_enemies(&samples, Some(103), None);
+    assert_eq!((versus[0].champion_id, versus[0].games), (200, 2));
+    assert!(versus_enemies(&samples, None, Some(Role::Mid)).is_empty());
+
+    let picks = picks_into(&samples, 200, Some(Role::Top));
+    let rows: Vec<(i64, usize)> = picks
+        .iter()
+        .map(|stats| (stats.champion_id, stats.games))
+        .collect();
+    assert_eq!(rows, vec![(103, 2), (222, 1)]);
+}
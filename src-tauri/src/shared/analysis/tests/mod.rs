@@ -0,0 +1,106 @@
+mod arena;
+mod build;
+mod head_to_head;
+mod heatmap;
+mod indicator;
+mod lp;
+mod matchup;
+mod performance;
+mod pick_ban;
+mod post_game;
+mod premade;
+mod role;
+mod score;
+mod timeline;
+
+use crate::shared::fixture::GameBuilder;
+use crate::shared::types::sgp::game_detail::{
+    Event, Frames, Participant as DetailParticipant, SgpGameDetailsLol, N1,
+};
+
+/// 玩家 "me" 在蓝方的一局 20 分钟对局，同队玩家数据相同
+fn fixture_solo_game(match_id: &str, win: bool) -> GameBuilder {
+    (0..10)
+        .fold(
+            GameBuilder::new(match_id).mode("CLASSIC").duration(1200),
+            |builder, i| {
+                let builder = match i {
+                    0 => builder.player("me", 100),
+                    1..=4 => builder.player(&format!("p{}", i), 100),
+                    _ => builder.player(&format!("r{}", i - 5), 200),
+                }
+                .damage(10000)
+                .vision(20)
+                .objectives(1000)
+                .cs(150);
+                if i < 5 {
+                    builder.kda(5, 2, 5).gold(10000)
+                } else {
+                    builder.kda(2, 5, 2).gold(8000)
+                }
+            },
+        )
+        .winner(if win { 100 } else { 200 })
+}
+
+/// 16 分钟的对局详情：蓝方 1 号每分钟比其他人多 100 经济，红方 6 号每分钟多 10 补刀
+fn fixture_detail() -> SgpGameDetailsLol {
+    let mut detail = SgpGameDetailsLol::default();
+    detail.metadata.match_id = "HN1_1".to_string();
+    detail.json.frame_interval = 60_000;
+    detail.json.participants = (1..=10)
+        .map(|participant_id| DetailParticipant {
+            participant_id,
+            puuid: format!("p{}", participant_id),
+        })
+        .collect();
+    for minute in 0..=16 {
+        let mut frame = Frames {
+            timestamp: minute * 60_000 + 20,
+            ..Default::default()
+        };
+        for participant_id in 1..=10 {
+            let stats = N1 {
+                total_gold: 500 + minute * (300 + if participant_id == 1 { 100 } else { 0 }),
+                minions_killed: minute * (5 + if participant_id == 6 { 10 } else { 0 }),
+                xp: minute * 400,
+                ..Default::default()
+            };
+            match participant_id {
+                1 => frame.participant_frames.n1 = stats,
+                6 => frame.participant_frames.n6 = stats,
+                _ => {}
+            }
+        }
+        detail.json.frames.push(frame);
+    }
+    let event = |type_field: &str, timestamp: i64| Event {
+        type_field: type_field.to_string(),
+        timestamp,
+        ..Default::default()
+    };
+    detail.json.frames[3].events = vec![Event {
+        killer_id: Some(6),
+        victim_id: Some(1),
+        ..event("CHAMPION_KILL", 180_000)
+    }];
+    detail.json.frames[8].events = vec![
+        Event {
+            killer_team_id: Some(100),
+            monster_type: Some("DRAGON".to_string()),
+            ..event("ELITE_MONSTER_KILL", 480_000)
+        },
+        Event {
+            killer_id: Some(0),
+            victim_id: Some(6),
+            ..event("CHAMPION_KILL", 490_000)
+        },
+    ];
+    detail.json.frames[14].events = vec![Event {
+        killer_id: Some(1),
+        team_id: Some(200),
+        building_type: Some("TOWER_BUILDING".to_string()),
+        ..event("BUILDING_KILL", 840_000)
+    }];
+    detail
+}
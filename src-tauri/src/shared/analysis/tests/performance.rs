@@ -0,0 +1,74 @@
+use super::fixture_solo_game;
+use crate::shared::analysis::performance::{aggregate_performance, PerformanceStats};
+use crate::shared::store::filter::MatchFilter;
+use crate::shared::types::sgp::history::Games;
+use std::sync::Arc;
+
+/// 从新到旧：最近两局 420 单双排，最早一局 440 灵活排位
+fn fixture_history() -> Vec<Arc<Games>> {
+    [
+        ("G3", 3000, 420, 103, "MIDDLE", true),
+        ("G2", 2000, 420, 103, "MIDDLE", false),
+        ("G1", 1000, 440, 222, "BOTTOM", true),
+    ]
+    .into_iter()
+    .map(
+        |(match_id, game_creation, queue_id, champion_id, position, win)| {
+            fixture_solo_game(match_id, win)
+                .created(game_creation)
+                .queue(queue_id)
+                .edit("me", |me| {
+                    me.champion_id = champion_id;
+                    me.team_position = position.to_string();
+                })
+                .arc()
+        },
+    )
+    .collect()
+}
+
+#[test]
+fn test_performance_aggregates_by_champion_role_and_queue() {
+    let report = aggregate_performance(&fixture_history(), "me", &MatchFilter::default(), None);
+
+    assert_eq!(report.overall.games, 3);
+    assert_eq!(report.overall.wins, 2);
+    // (5 + 5) / 2 = 5
+    assert_eq!(report.overall.kda, 5.0);
+    // 150 补刀 / 20 分钟
+    assert_eq!(report.overall.cs_per_minute, 7.5);
+    assert_eq!(report.overall.gold_per_minute, 500.0);
+    assert!((report.overall.damage_share - 0.2).abs() < 1e-9);
+
+    assert_eq!(report.champions[0].champion_id, 103);
+    assert_eq!(report.champions[0].stats.games, 2);
+    assert_eq!(report.champions[0].stats.win_rate, 0.5);
+    assert_eq!(report.roles[0].position, "MIDDLE");
+    assert_eq!(report.queues[0].queue_id, 420);
+    assert_eq!(report.queues[1].stats.games, 1);
+
+    // 走势按时间正序
+    let trend: Vec<(&str, f64)> = report
+        .trend
+        .iter()
+        .map(|point| (point.match_id.as_str(), point.rolling_win_rate))
+        .collect();
+    assert_eq!(trend, vec![("G1", 1.0), ("G2", 0.5), ("G3", 2.0 / 3.0)]);
+}
+
+#[test]
+fn test_performance_applies_filter_before_count() {
+    let filter = MatchFilter {
+        queue_ids: vec![440],
+        ..Default::default()
+    };
+    let report = aggregate_performance(&fixture_history(), "me", &filter, Some(1));
+    assert_eq!(report.overall.games, 1);
+    assert_eq!(report.champions[0].champion_id, 222);
+
+    let report = aggregate_performance(&fixture_history(), "me", &MatchFilter::default(), Some(1));
+    assert_eq!(report.trend[0].match_id, "G3");
+
+    let report = aggregate_performance(&fixture_history(), "nobody", &MatchFilter::default(), None);
+    assert_eq!(report.overall, PerformanceStats::default());
+}
@@ -0,0 +1,110 @@
+use crate::shared::analysis::pick_ban::{
+    choose_champion, is_champion_available, lock_delay_ms, my_current_action, my_position,
+    ActionKind,
+};
+use crate::shared::types::league_client::champ_select::{
+    Action, ChampSelectSession, ChampSelectTeam, Timer,
+};
+
+fn fixture_champ_select() -> ChampSelectSession {
+    let mut session = ChampSelectSession::default();
+    session.base.local_player_cell_id = 1;
+    session.base.my_team = vec![
+        ChampSelectTeam {
+            cell_id: 0,
+            champion_pick_intent: 103,
+            ..Default::default()
+        },
+        ChampSelectTeam {
+            cell_id: 1,
+            assigned_position: "middle".to_string(),
+            champion_pick_intent: 61,
+            ..Default::default()
+        },
+    ];
+    session.base.bans.their_team_bans = vec![157];
+    session.base.actions = vec![
+        vec![Action {
+            id: 1,
+            actor_cell_id: 5,
+            champion_id: 238,
+            completed: true,
+            r#type: "pick".to_string(),
+            ..Default::default()
+        }],
+        vec![Action {
+            id: 2,
+            actor_cell_id: 1,
+            is_in_progress: true,
+            r#type: "ban".to_string(),
+            ..Default::default()
+        }],
+    ];
+    session
+}
+
+#[test]
+fn test_choose_champion_skips_unavailable_and_teammates() {
+    let session = fixture_champ_select();
+    let (action, kind) = my_current_action(&session).unwrap();
+    assert_eq!((action.id, kind), (2, ActionKind::Ban));
+    assert_eq!(my_position(&session), "middle");
+
+    let allowed = [103, 157, 238, 61, 7, 84];
+    // 队友预选、已被禁用、已锁定和不在可选列表中的英雄都跳过
+    assert_eq!(
+        choose_champion(
+            &session,
+            ActionKind::Pick,
+            &[103, 157, 238, 99, 61],
+            &allowed
+        ),
+        Some(61)
+    );
+    // 禁用时还要跳过自己预选的英雄
+    assert_eq!(
+        choose_champion(&session, ActionKind::Ban, &[103, 61, 7], &allowed),
+        Some(7)
+    );
+    assert_eq!(
+        choose_champion(&session, ActionKind::Ban, &[61], &allowed),
+        None
+    );
+    // 锁定前只检查预选的英雄是否仍然可用
+    assert!(is_champion_available(
+        &session,
+        ActionKind::Pick,
+        61,
+        &allowed
+    ));
+    assert!(!is_champion_available(
+        &session,
+        ActionKind::Ban,
+        61,
+        &allowed
+    ));
+    assert!(!is_champion_available(
+        &session,
+        ActionKind::Pick,
+        103,
+        &allowed
+    ));
+    assert!(!is_champion_available(
+        &session,
+        ActionKind::Pick,
+        99,
+        &allowed
+    ));
+
+    let timer = Timer {
+        adjusted_time_left_in_phase: 27_500,
+        ..Default::default()
+    };
+    assert_eq!(lock_delay_ms(&timer, 3000), 24_500);
+    assert_eq!(lock_delay_ms(&timer, 30_000), 0);
+    let infinite = Timer {
+        is_infinite: true,
+        ..Default::default()
+    };
+    assert_eq!(lock_delay_ms(&infinite, 3000), 0);
+}
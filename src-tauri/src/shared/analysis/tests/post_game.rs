@@ -0,0 +1,33 @@
+use super::{fixture_detail, fixture_solo_game};
+use crate::shared::analysis::post_game::build_post_game_report;
+use crate::utils::config::score::ScoreConfig;
+
+#[test]
+fn test_post_game_report_shares_and_timeline() {
+    let game = fixture_solo_game("G1", true).created(1000).queue(420).arc();
+    let report = build_post_game_report(&game, None, "me", None, &ScoreConfig::default());
+
+    assert_eq!(report.match_id, "G1");
+    assert!(report.win);
+    assert!(report.timeline.is_none());
+    assert_eq!(report.players.len(), 10);
+    // 蓝方五人数据相同，各占 20%
+    let me = report.player().unwrap();
+    assert_eq!(me.damage_share, 0.2);
+    assert_eq!(me.gold_share, 0.2);
+    assert_eq!(me.kda, 5.0);
+    assert_eq!(me.placement, 0);
+    // 每队各有一名最佳玩家
+    assert_eq!(
+        report
+            .players
+            .iter()
+            .filter(|player| player.badge.is_some())
+            .count(),
+        2
+    );
+
+    let detail = fixture_detail();
+    let report = build_post_game_report(&game, Some(&detail), "me", None, &ScoreConfig::default());
+    assert_eq!(report.timeline.unwrap().match_id, "HN1_1");
+}
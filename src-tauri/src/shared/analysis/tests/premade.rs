@@ -0,0 +1,50 @@
+use crate::shared::analysis::premade::{detect_premades, PremadeCandidate};
+use crate::shared::fixture::GameBuilder;
+
+#[test]
+fn test_detect_premades_groups_repeated_teammates() {
+    let players: Vec<PremadeCandidate> =
+        [("a", 100), ("b", 100), ("c", 100), ("d", 100), ("x", 200)]
+            .iter()
+            .map(|(puuid, team_id)| PremadeCandidate {
+                puuid: puuid.to_string(),
+                team_id: *team_id,
+            })
+            .collect();
+    let games = vec![
+        // a、b 同队三局，b、c 同队两局，合并为一个车队
+        GameBuilder::new("M1")
+            .players(&[("a", 100), ("b", 100), ("c", 100)])
+            .arc(),
+        GameBuilder::new("M2")
+            .players(&[("a", 200), ("b", 200), ("c", 200)])
+            .arc(),
+        GameBuilder::new("M3")
+            .players(&[("a", 100), ("b", 100), ("d", 200)])
+            .arc(),
+        // 同一局重复出现只统计一次
+        GameBuilder::new("M3")
+            .players(&[("a", 100), ("b", 100), ("d", 200)])
+            .arc(),
+        // d 只和 a 同队一次；x 在对面，和 d 多次同队也不算
+        GameBuilder::new("M4")
+            .players(&[("a", 100), ("d", 100), ("x", 100)])
+            .arc(),
+        GameBuilder::new("M5")
+            .players(&[("d", 100), ("x", 100)])
+            .arc(),
+    ];
+
+    let report = detect_premades(&players, &games);
+    assert_eq!(report.groups.len(), 1);
+    let group = &report.groups[0];
+    assert_eq!(group.puuids, vec!["a", "b", "c"]);
+    assert_eq!(group.team_id, 100);
+    assert_eq!(group.shared_games, 3);
+    assert_eq!(group.confidence, 0.5);
+
+    let tag = report.tag_of("b").unwrap();
+    assert_eq!((tag.group_id, tag.size), (1, 3));
+    assert!(report.tag_of("d").is_none());
+    assert!(report.tag_of("x").is_none());
+}
@@ -0,0 +1,81 @@
+use crate::shared::analysis::role::{lane_opponents, resolve_roles, Role, SMITE_SPELL_ID};
+use crate::shared::fixture::GameBuilder;
+use crate::shared::types::sgp::history::Participant;
+
+/// (team_id, team_position, lane, spell1, minions, neutral)
+type Seat = (i64, &'static str, &'static str, i64, i64, i64);
+
+/// 按顺序添加玩家，返回所有玩家
+fn seat_participants(seats: &[(&str, Seat)]) -> Vec<Participant> {
+    seats
+        .iter()
+        .fold(GameBuilder::new("HN1_1"), |builder, (puuid, seat)| {
+            let (team_id, team_position, lane, spell1id, minions, neutral) = *seat;
+            builder
+                .player(puuid, team_id)
+                .position(team_position)
+                .cs(minions)
+                .with(|p| {
+                    p.lane = lane.to_string();
+                    p.spell1id = spell1id;
+                    p.spell2id = 4;
+                    p.neutral_minions_killed = neutral;
+                })
+        })
+        .build()
+        .json
+        .participants
+}
+
+#[test]
+fn test_resolve_roles_and_lane_opponents() {
+    let participants = seat_participants(&[
+        // 蓝方位置完整
+        ("b_top", (100, "TOP", "TOP", 12, 200, 0)),
+        ("b_jg", (100, "JUNGLE", "JUNGLE", SMITE_SPELL_ID, 20, 160)),
+        ("b_mid", (100, "MIDDLE", "MIDDLE", 14, 210, 10)),
+        ("b_adc", (100, "BOTTOM", "BOTTOM", 7, 230, 0)),
+        ("b_sup", (100, "UTILITY", "BOTTOM", 3, 30, 0)),
+        // 红方没有 team_position：靠惩戒判定打野，补刀最少的下路玩家判定为辅助
+        ("r_sup", (200, "", "BOTTOM", 3, 25, 0)),
+        ("r_top", (200, "", "TOP", 12, 190, 0)),
+        ("r_jg", (200, "", "NONE", SMITE_SPELL_ID, 15, 150)),
+        ("r_adc", (200, "", "BOTTOM", 7, 220, 0)),
+        ("r_mid", (200, "", "MIDDLE", 14, 200, 5)),
+    ]);
+
+    let roles = resolve_roles(&participants);
+    assert_eq!(
+        roles,
+        vec![
+            Some(Role::Top),
+            Some(Role::Jungle),
+            Some(Role::Mid),
+            Some(Role::Bottom),
+            Some(Role::Utility),
+            Some(Role::Utility),
+            Some(Role::Top),
+            Some(Role::Jungle),
+            Some(Role::Bottom),
+            Some(Role::Mid),
+        ]
+    );
+
+    let opponents = lane_opponents(&participants, &roles);
+    assert_eq!(opponents[0], Some(6));
+    assert_eq!(opponents[4], Some(5));
+    assert_eq!(opponents[8], Some(3));
+
+    // 没有分路信息的模式（如大乱斗）不判定位置
+    let aram = (0..10)
+        .fold(GameBuilder::new("HN1_2"), |builder, i| {
+            builder
+                .player(&i.to_string(), 100 + 100 * (i / 5))
+                .cs(50)
+                .with(|p| p.lane = "NONE".to_string())
+        })
+        .build()
+        .json
+        .participants;
+    assert!(resolve_roles(&aram).iter().all(Option::is_none));
+}
@@ -0,0 +1,141 @@
+use crate::shared::analysis::arena::ARENA_GAME_MODE;
+use crate::shared::analysis::score::{score_sgp_game, ScoreBadge};
+use crate::shared::fixture::GameBuilder;
+use crate::shared::types::sgp::history::Games;
+use crate::utils::config::score::{ScoreConfig, ScoreWeights};
+
+/// (team_id, win, kills, deaths, assists, damage, gold, vision, objective, cs)
+type Line = (i64, bool, i64, i64, i64, i64, i64, i64, i64, i64);
+
+/// 按 `lines` 依次添加玩家 p0、p1……
+fn add_lines(builder: GameBuilder, lines: &[Line]) -> GameBuilder {
+    lines
+        .iter()
+        .enumerate()
+        .fold(builder, |builder, (i, line)| {
+            let (team_id, win, kills, deaths, assists, damage, gold, vision, objective, cs) = *line;
+            builder
+                .player(&format!("p{}", i), team_id)
+                .win(win)
+                .kda(kills, deaths, assists)
+                .damage(damage)
+                .gold(gold)
+                .vision(vision)
+                .objectives(objective)
+                .cs(cs)
+        })
+}
+
+/// 一局 30 分钟的排位：蓝方 p0 明显 carry，红方 p7 是败方最好的玩家
+fn fixture_ranked_game() -> Games {
+    add_lines(
+        GameBuilder::new("HN1_1").mode("CLASSIC").duration(1800),
+        &[
+            (100, true, 12, 2, 8, 32000, 15000, 30, 12000, 250),
+            (100, true, 4, 3, 12, 14000, 10000, 25, 3000, 40),
+            (100, true, 5, 4, 9, 18000, 11500, 20, 6000, 210),
+            (100, true, 3, 5, 6, 12000, 11000, 22, 4000, 230),
+            (100, true, 1, 4, 18, 6000, 7500, 80, 1000, 30),
+            (200, false, 2, 6, 3, 12000, 9500, 18, 2000, 200),
+            (200, false, 3, 5, 4, 9000, 8500, 30, 3000, 35),
+            (200, false, 5, 4, 4, 21000, 11000, 20, 4000, 220),
+            (200, false, 1, 6, 5, 10000, 9000, 15, 2500, 190),
+            (200, false, 0, 4, 7, 4000, 6500, 55, 500, 25),
+        ],
+    )
+    .build()
+}
+
+fn score_game(game: &Games, config: &ScoreConfig) -> Vec<(f64, Option<ScoreBadge>)> {
+    score_sgp_game(game, config)
+        .into_iter()
+        .map(|result| (result.score, result.badge))
+        .collect()
+}
+
+#[test]
+fn test_score_marks_mvp_and_ace() {
+    let results = score_game(&fixture_ranked_game(), &ScoreConfig::default());
+
+    assert_eq!(results[0].1, Some(ScoreBadge::Mvp));
+    assert_eq!(results[7].1, Some(ScoreBadge::Ace));
+    // 每支队伍只有一个最佳
+    assert_eq!(results.iter().filter(|r| r.1.is_some()).count(), 2);
+    for (score, _) in &results {
+        assert!((0.0..=10.0).contains(score));
+    }
+}
+
+#[test]
+fn test_score_handles_empty_stats() {
+    // 重开局：所有数据为 0，不能出现 NaN
+    let game = add_lines(
+        GameBuilder::new("HN1_1").mode("CLASSIC"),
+        &[
+            (100, false, 0, 0, 0, 0, 0, 0, 0, 0),
+            (200, true, 0, 0, 0, 0, 0, 0, 0, 0),
+        ],
+    )
+    .build();
+    let results = score_game(&game, &ScoreConfig::default());
+
+    assert_eq!(
+        results,
+        vec![(0.0, Some(ScoreBadge::Ace)), (0.0, Some(ScoreBadge::Mvp))]
+    );
+}
+
+#[test]
+fn test_score_uses_mode_profile() {
+    // 两名玩家只有视野得分不同
+    let lines = [
+        (100, true, 5, 5, 5, 10000, 10000, 60, 1000, 100),
+        (100, true, 5, 5, 5, 10000, 10000, 10, 1000, 100),
+    ];
+    let config = ScoreConfig::default();
+    let game =
+        |mode: &str| add_lines(GameBuilder::new("HN1_1").mode(mode).duration(1200), &lines).build();
+
+    let classic = score_game(&game("CLASSIC"), &config);
+    assert!(classic[0].0 > classic[1].0);
+
+    // 大乱斗配置中视野权重为 0
+    let aram = score_game(&game("ARAM"), &config);
+    assert_eq!(aram[0].0, aram[1].0);
+}
+
+#[test]
+fn test_score_groups_arena_by_subteam() {
+    // 两支小队在同一个 team_id 下，各自标记最佳
+    let game = add_lines(
+        GameBuilder::new("HN1_1")
+            .mode(ARENA_GAME_MODE)
+            .duration(900),
+        &[
+            (100, true, 8, 2, 4, 20000, 9000, 0, 0, 0),
+            (100, true, 2, 3, 8, 8000, 7000, 0, 0, 0),
+            (100, false, 4, 4, 2, 12000, 8000, 0, 0, 0),
+            (100, false, 1, 4, 3, 5000, 6000, 0, 0, 0),
+        ],
+    )
+    .each(|p| p.player_subteam_id = if p.participant_id <= 2 { 1 } else { 2 })
+    .build();
+    let results = score_game(&game, &ScoreConfig::default());
+
+    let badges: Vec<Option<ScoreBadge>> = results.iter().map(|r| r.1).collect();
+    assert_eq!(
+        badges,
+        vec![Some(ScoreBadge::Mvp), None, Some(ScoreBadge::Ace), None]
+    );
+}
+
+#[test]
+fn test_score_config_fallback_and_partial_json() {
+    let config: ScoreConfig =
+        serde_json::from_str(r#"{"profiles": {"URF": {"kda": 1.0}}}"#).unwrap();
+
+    assert_eq!(config.weights_for("CLASSIC"), &ScoreWeights::default());
+    let urf = config.weights_for("URF");
+    assert_eq!(urf.kda, 1.0);
+    assert_eq!(urf.damage_share, ScoreWeights::default().damage_share);
+}
@@ -0,0 +1,42 @@
+use super::fixture_detail;
+use crate::shared::analysis::timeline::{analyze_timeline, ObjectiveTiming};
+use crate::shared::fixture::GameBuilder;
+
+#[test]
+fn test_timeline_report() {
+    let detail = fixture_detail();
+    let summary = GameBuilder::lobby("HN1_1").build();
+
+    let report = analyze_timeline(&detail, Some(&summary));
+
+    // 只有 1 号和 6 号有帧数据
+    assert_eq!(report.curve[10].minute, 10);
+    assert_eq!(report.curve[10].gold_diff, 1000);
+    assert_eq!(report.curve[10].xp_diff, 0);
+
+    let top = &report.players[0];
+    assert_eq!(top.lane_opponent, Some(6));
+    assert_eq!(top.snapshots.len(), 2);
+    assert_eq!(top.snapshots[0].gold_diff, Some(1000));
+    assert_eq!(top.snapshots[1].cs_diff, Some(-150));
+
+    assert_eq!(
+        report.first_blood,
+        Some(ObjectiveTiming {
+            timestamp: 180_000,
+            team_id: 200,
+            participant_id: 6,
+        })
+    );
+    assert_eq!(report.first_dragon.as_ref().unwrap().team_id, 100);
+    assert_eq!(report.first_tower.as_ref().unwrap().team_id, 100);
+    // 被防御塔击杀算作对方的击杀
+    assert_eq!(report.objectives[0].kills, 1);
+    assert_eq!(report.objectives[0].towers, 1);
+    assert_eq!(report.objectives[1].kills, 1);
+
+    // 没有对局概要时仍能计算曲线，但没有对位
+    let report = analyze_timeline(&detail, None);
+    assert_eq!(report.curve[10].gold_diff, 1000);
+    assert_eq!(report.players[0].lane_opponent, None);
+}
@@ -0,0 +1,357 @@
+//! 对局时间线分析
+//!
+//! 基于对局详情中每分钟的 `participantFrames` 和事件，计算双方经济/经验差曲线、
+//! 玩家 10/15 分钟时与对位的差距、一血/一塔/首条小龙的时间以及资源控制情况。
+
+use crate::shared::types::sgp::game_detail::{Event, Frames, SgpGameDetailsLol};
+use crate::shared::types::sgp::history::Games;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const BLUE_TEAM: i64 = 100;
+const RED_TEAM: i64 = 200;
+/// 对比数据的时间点（分钟）
+const SNAPSHOT_MINUTES: [i64; 2] = [10, 15];
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffPoint {
+    pub minute: i64,
+    /// 蓝方减红方
+    pub gold_diff: i64,
+    pub xp_diff: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Snapshot {
+    pub minute: i64,
+    pub gold: i64,
+    /// 补刀数（小兵 + 野怪）
+    pub cs: i64,
+    pub xp: i64,
+    pub level: i64,
+    /// 与对位的差距，没有对位时为空
+    pub gold_diff: Option<i64>,
+    pub cs_diff: Option<i64>,
+    pub xp_diff: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerTimeline {
+    pub participant_id: i64,
+    pub puuid: String,
+    pub team_id: i64,
+    pub champion_id: i64,
+    pub position: String,
+    /// 对位玩家的 participant_id
+    pub lane_opponent: Option<i64>,
+    /// 对局在该时间点前结束时不包含对应的数据
+    pub snapshots: Vec<Snapshot>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectiveTiming {
+    /// 毫秒
+    pub timestamp: i64,
+    pub team_id: i64,
+    /// 击杀者，被小兵/防御塔击杀时为 0
+    pub participant_id: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamObjectives {
+    pub team_id: i64,
+    pub kills: i64,
+    pub towers: i64,
+    pub turret_plates: i64,
+    pub inhibitors: i64,
+    pub dragons: i64,
+    pub heralds: i64,
+    /// 虚空巢虫
+    pub void_grubs: i64,
+    pub barons: i64,
+    pub atakhans: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineReport {
+    pub match_id: String,
+    pub curve: Vec<DiffPoint>,
+    pub players: Vec<PlayerTimeline>,
+    pub first_blood: Option<ObjectiveTiming>,
+    pub first_tower: Option<ObjectiveTiming>,
+    pub first_dragon: Option<ObjectiveTiming>,
+    pub first_baron: Option<ObjectiveTiming>,
+    pub objectives: Vec<TeamObjectives>,
+}
+
+/// 参与者的基础信息，来自对局概要；缺少概要时按 1~5 蓝方、6~10 红方处理
+#[derive(Debug, Clone, Default)]
+struct Seat {
+    puuid: String,
+    team_id: i64,
+    champion_id: i64,
+    position: String,
+}
+
+fn seats(detail: &SgpGameDetailsLol, summary: Option<&Games>) -> HashMap<i64, Seat> {
+    let mut seats: HashMap<i64, Seat> = detail
+        .json
+        .participants
+        .iter()
+        .map(|participant| {
+            let seat = Seat {
+                puuid: participant.puuid.clone(),
+                team_id: if participant.participant_id <= 5 {
+                    BLUE_TEAM
+                } else {
+                    RED_TEAM
+                },
+                ..Default::default()
+            };
+            (participant.participant_id, seat)
+        })
+        .collect();
+
+    if let Some(summary) = summary {
+        for participant in &summary.json.participants {
+            let seat = seats.entry(participant.participant_id).or_default();
+            seat.puuid = participant.puuid.clone();
+            seat.team_id = participant.team_id;
+            seat.champion_id = participant.champion_id;
+            seat.position = participant.team_position.clone();
+        }
+    }
+    seats
+}
+
+/// 取 `minute` 分钟时的帧，对局在此之前结束时返回 `None`
+pub fn frame_at(detail: &SgpGameDetailsLol, minute: i64) -> Option<&Frames> {
+    let interval = if detail.json.frame_interval > 0 {
+        detail.json.frame_interval
+    } else {
+        60_000
+    };
+    detail
+        .json
+        .frames
+        .get((minute * 60_000 / interval) as usize)
+}
+
+fn events(detail: &SgpGameDetailsLol) -> impl Iterator<Item = &Event> {
+    detail
+        .json
+        .frames
+        .iter()
+        .flat_map(|frame| frame.events.iter())
+}
+
+fn team_of(seats: &HashMap<i64, Seat>, participant_id: i64) -> Option<i64> {
+    seats.get(&participant_id).map(|seat| seat.team_id)
+}
+
+fn opposing(team_id: i64) -> i64 {
+    if team_id == BLUE_TEAM {
+        RED_TEAM
+    } else {
+        BLUE_TEAM
+    }
+}
+
+fn diff_curve(detail: &SgpGameDetailsLol, seats: &HashMap<i64, Seat>) -> Vec<DiffPoint> {
+    detail
+        .json
+        .frames
+        .iter()
+        .map(|frame| {
+            let mut point = DiffPoint {
+                minute: frame.timestamp / 60_000,
+                ..Default::default()
+            };
+            for participant_id in 1..=10 {
+                let Some(stats) = frame.participant_frames.get(participant_id) else {
+                    continue;
+                };
+                match team_of(seats, participant_id) {
+                    Some(BLUE_TEAM) => {
+                        point.gold_diff += stats.total_gold;
+                        point.xp_diff += stats.xp;
+                    }
+                    Some(RED_TEAM) => {
+                        point.gold_diff -= stats.total_gold;
+                        point.xp_diff -= stats.xp;
+                    }
+                    _ => {}
+                }
+            }
+            point
+        })
+        .collect()
+}
+
+fn player_timelines(detail: &SgpGameDetailsLol, seats: &HashMap<i64, Seat>) -> Vec<PlayerTimeline> {
+    let mut participant_ids: Vec<i64> = seats.keys().copied().collect();
+    participant_ids.sort();
+
+    participant_ids
+        .iter()
+        .map(|participant_id| {
+            let seat = &seats[participant_id];
+            let lane_opponent = participant_ids.iter().copied().find(|other| {
+                let other_seat = &seats[other];
+                !seat.position.is_empty()
+                    && other_seat.team_id != seat.team_id
+                    && other_seat.position == seat.position
+            });
+
+            let snapshots = SNAPSHOT_MINUTES
+                .iter()
+                .filter_map(|minute| {
+                    let frames = &frame_at(detail, *minute)?.participant_frames;
+                    let stats = frames.get(*participant_id)?;
+                    let opponent = lane_opponent.and_then(|id| frames.get(id));
+                    let cs = stats.minions_killed + stats.jungle_minions_killed;
+                    Some(Snapshot {
+                        minute: *minute,
+                        gold: stats.total_gold,
+                        cs,
+                        xp: stats.xp,
+                        level: stats.level,
+                        gold_diff: opponent.map(|o| stats.total_gold - o.total_gold),
+                        cs_diff: opponent.map(|o| cs - o.minions_killed - o.jungle_minions_killed),
+                        xp_diff: opponent.map(|o| stats.xp - o.xp),
+                    })
+                })
+                .collect();
+
+            PlayerTimeline {
+                participant_id: *participant_id,
+                puuid: seat.puuid.clone(),
+                team_id: seat.team_id,
+                champion_id: seat.champion_id,
+                position: seat.position.clone(),
+                lane_opponent,
+                snapshots,
+            }
+        })
+        .collect()
+}
+
+/// 分析对局时间线
+///
+/// # 参数
+/// - `detail`: 对局详情
+/// - `summary`: 对局概要，用于确定队伍、英雄和分路，没有时无法计算对位差距
+pub fn analyze_timeline(detail: &SgpGameDetailsLol, summary: Option<&Games>) -> TimelineReport {
+    let seats = seats(detail, summary);
+    let mut report = TimelineReport {
+        match_id: detail.metadata.match_id.clone(),
+        curve: diff_curve(detail, &seats),
+        players: player_timelines(detail, &seats),
+        ..Default::default()
+    };
+
+    let mut objectives: HashMap<i64, TeamObjectives> = [BLUE_TEAM, RED_TEAM]
+        .into_iter()
+        .map(|team_id| {
+            let objective = TeamObjectives {
+                team_id,
+                ..Default::default()
+            };
+            (team_id, objective)
+        })
+        .collect();
+
+    for event in events(detail) {
+        let killer_id = event.killer_id.unwrap_or_default();
+        let timing = |team_id: i64| ObjectiveTiming {
+            timestamp: event.timestamp,
+            team_id,
+            participant_id: killer_id,
+        };
+
+        match event.type_field.as_str() {
+            "CHAMPION_KILL" => {
+                // 被防御塔等击杀时 killer_id 为 0，按被击杀者的对方计算
+                let Some(team_id) = team_of(&seats, killer_id)
+                    .or_else(|| team_of(&seats, event.victim_id?).map(opposing))
+                else {
+                    continue;
+                };
+                if let Some(team) = objectives.get_mut(&team_id) {
+                    team.kills += 1;
+                }
+                if report.first_blood.is_none() {
+                    report.first_blood = Some(timing(team_id));
+                }
+            }
+            // 建筑事件中的 team_id 是失去建筑的一方
+            "BUILDING_KILL" => {
+                let Some(team_id) = event.team_id.map(opposing) else {
+                    continue;
+                };
+                let Some(team) = objectives.get_mut(&team_id) else {
+                    continue;
+                };
+                match event.building_type.as_deref() {
+                    Some("TOWER_BUILDING") => {
+                        team.towers += 1;
+                        if report.first_tower.is_none() {
+                            report.first_tower = Some(timing(team_id));
+                        }
+                    }
+                    Some("INHIBITOR_BUILDING") => team.inhibitors += 1,
+                    _ => {}
+                }
+            }
+            "TURRET_PLATE_DESTROYED" => {
+                if let Some(team) = event
+                    .team_id
+                    .map(opposing)
+                    .and_then(|team_id| objectives.get_mut(&team_id))
+                {
+                    team.turret_plates += 1;
+                }
+            }
+            "ELITE_MONSTER_KILL" => {
+                let Some(team_id) = event.killer_team_id.or_else(|| team_of(&seats, killer_id))
+                else {
+                    continue;
+                };
+                let Some(team) = objectives.get_mut(&team_id) else {
+                    continue;
+                };
+                match event.monster_type.as_deref() {
+                    Some("DRAGON") => {
+                        team.dragons += 1;
+                        if report.first_dragon.is_none() {
+                            report.first_dragon = Some(timing(team_id));
+                        }
+                    }
+                    Some("BARON_NASHOR") => {
+                        team.barons += 1;
+                        if report.first_baron.is_none() {
+                            report.first_baron = Some(timing(team_id));
+                        }
+                    }
+                    Some("RIFTHERALD") => team.heralds += 1,
+                    Some("HORDE") => team.void_grubs += 1,
+                    Some("ATAKHAN") => team.atakhans += 1,
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    report.objectives = vec![
+        objectives[&BLUE_TEAM].clone(),
+        objectives[&RED_TEAM].clone(),
+    ];
+    report
+}
@@ -0,0 +1,163 @@
+//! 测试用的 SGP 对局构造器
+
+use crate::shared::types::sgp::history::{Games, Participant};
+use std::sync::Arc;
+
+/// 每支队伍按顺序的五个位置
+pub const POSITIONS: [&str; 5] = ["TOP", "JUNGLE", "MIDDLE", "BOTTOM", "UTILITY"];
+
+/// 逐个添加玩家构造对局，玩家相关的设置作用于最后添加的玩家
+#[derive(Default)]
+pub struct GameBuilder {
+    game: Games,
+}
+
+impl GameBuilder {
+    pub fn new(match_id: &str) -> Self {
+        let mut game = Games::default();
+        game.metadata.match_id = match_id.to_string();
+        Self { game }
+    }
+
+    /// 十名玩家 p1 ~ p10，蓝方 p1 ~ p5，红方 p6 ~ p10，两队都按 [`POSITIONS`] 分路
+    pub fn lobby(match_id: &str) -> Self {
+        (1..=10).fold(Self::new(match_id), |builder, i| {
+            builder
+                .player(&format!("p{}", i), if i <= 5 { 100 } else { 200 })
+                .position(POSITIONS[(i - 1) % 5])
+        })
+    }
+
+    pub fn mode(mut self, game_mode: &str) -> Self {
+        self.game.json.game_mode = game_mode.to_string();
+        self
+    }
+
+    /// 对局时长（秒）
+    pub fn duration(mut self, seconds: i64) -> Self {
+        self.game.json.game_duration = seconds;
+        self
+    }
+
+    pub fn created(mut self, game_creation: i64) -> Self {
+        self.game.json.game_creation = game_creation;
+        self
+    }
+
+    pub fn queue(mut self, queue_id: i64) -> Self {
+        self.game.json.queue_id = queue_id;
+        self
+    }
+
+    pub fn version(mut self, game_version: &str) -> Self {
+        self.game.json.game_version = game_version.to_string();
+        self
+    }
+
+    pub fn map(mut self, map_id: i64) -> Self {
+        self.game.json.map_id = map_id;
+        self
+    }
+
+    /// 添加一名玩家，`participant_id` 按添加顺序从 1 开始
+    pub fn player(mut self, puuid: &str, team_id: i64) -> Self {
+        let participant_id = self.game.json.participants.len() as i64 + 1;
+        self.game.json.participants.push(Participant {
+            participant_id,
+            puuid: puuid.to_string(),
+            team_id,
+            ..Default::default()
+        });
+        self
+    }
+
+    /// 按顺序添加多名玩家
+    pub fn players(self, players: &[(&str, i64)]) -> Self {
+        players.iter().fold(self, |builder, (puuid, team_id)| {
+            builder.player(puuid, *team_id)
+        })
+    }
+
+    pub fn win(self, win: bool) -> Self {
+        self.with(|p| p.win = win)
+    }
+
+    pub fn champion(self, champion_id: i64) -> Self {
+        self.with(|p| p.champion_id = champion_id)
+    }
+
+    pub fn position(self, team_position: &str) -> Self {
+        self.with(|p| p.team_position = team_position.to_string())
+    }
+
+    pub fn kda(self, kills: i64, deaths: i64, assists: i64) -> Self {
+        self.with(|p| {
+            p.kills = kills;
+            p.deaths = deaths;
+            p.assists = assists;
+        })
+    }
+
+    pub fn damage(self, damage: i64) -> Self {
+        self.with(|p| p.total_damage_dealt_to_champions = damage)
+    }
+
+    pub fn gold(self, gold: i64) -> Self {
+        self.with(|p| p.gold_earned = gold)
+    }
+
+    pub fn vision(self, vision_score: i64) -> Self {
+        self.with(|p| p.vision_score = vision_score)
+    }
+
+    pub fn objectives(self, damage: i64) -> Self {
+        self.with(|p| p.damage_dealt_to_objectives = damage)
+    }
+
+    /// 小兵补刀
+    pub fn cs(self, minions: i64) -> Self {
+        self.with(|p| p.total_minions_killed = minions)
+    }
+
+    /// 修改最后添加的玩家
+    pub fn with(mut self, f: impl FnOnce(&mut Participant)) -> Self {
+        f(self
+            .game
+            .json
+            .participants
+            .last_mut()
+            .expect("先调用 player 添加玩家"));
+        self
+    }
+
+    /// 修改指定玩家
+    pub fn edit(mut self, puuid: &str, f: impl FnOnce(&mut Participant)) -> Self {
+        f(self
+            .game
+            .json
+            .participants
+            .iter_mut()
+            .find(|p| p.puuid == puuid)
+            .expect("玩家不在对局中"));
+        self
+    }
+
+    /// 修改所有已添加的玩家
+    pub fn each(mut self, f: impl FnMut(&mut Participant)) -> Self {
+        self.game.json.participants.iter_mut().for_each(f);
+        self
+    }
+
+    /// 按队伍设置所有已添加玩家的胜负
+    pub fn winner(self, team_id: i64) -> Self {
+        self.each(|p| p.win = p.team_id == team_id)
+    }
+
+    pub fn build(self) -> Games {
+        self.game
+    }
+
+    pub fn arc(self) -> Arc<Games> {
+        Arc::new(self.game)
+    }
+}
@@ -1,6 +1,6 @@
 use crate::shared::http_api::lcu::http::HttpClient;
 use crate::shared::init::game_data::{
-    get_champion_info_cache, get_item_info_cache, get_perk_info_cache, get_spell_info_cache, get_perk_style_info_cache
+    get_augment_info_cache, get_champion_info_cache, get_item_info_cache, get_perk_info_cache, get_spell_info_cache, get_perk_style_info_cache
 };
 use crate::utils::error::http_error::HttpError;
 use base64::engine::general_purpose::STANDARD;
@@ -144,6 +144,23 @@ impl AssetHttpApi {
         self.get_image_as_base64(&item.icon_path.as_str()).await
     }
 
+    /// 获取斗魂竞技场强化符文图标（Base64 编码）
+    ///
+    /// # 参数
+    /// - `augment_id`: 强化符文 ID
+    ///
+    /// # 返回
+    /// - Base64 编码的图片数据 URL
+    pub async fn get_augment_icon_base64(&self, augment_id: i64) -> Result<String, HttpError> {
+        let cache = get_augment_info_cache().await;
+
+        let item = cache
+            .get(&augment_id)
+            .ok_or_else(|| HttpError::NotFound(format!("未找到强化符文图标: {}", augment_id)))?;
+
+        self.get_image_as_base64(&item.icon_path).await
+    }
+
     /// 获取图片并转换为 Base64 编码
     ///
     /// **推荐用于小体积图片（< 50KB）**，如头像、图标等
@@ -52,6 +52,7 @@ impl ChampSelectHttpApi {
         action_id: i32,
     ) -> Result<(), HttpError> {
         #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct ActionBody<'a> {
             champion_id: i32,
             completed: bool,
@@ -68,6 +69,7 @@ impl ChampSelectHttpApi {
 
     pub async fn intent_champion(&self, action_id: i32, champion_id: i32) -> Result<(), HttpError> {
         #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct IntentBody {
             champion_id: i32,
         }
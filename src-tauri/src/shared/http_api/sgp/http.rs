@@ -56,6 +56,11 @@ impl HttpClient {
         })
     }
 
+    /// SGP 服务器名，即 `SGP_SERVERS` 中的键（例如：`TENCENT_HN1`）
+    pub fn server_name(&self) -> &str {
+        &self.sgp_server_name
+    }
+
     pub fn get_match_history_url(&self) -> String {
         sgp::SGP_SERVERS
             .get(self.sgp_server_name.as_str())
@@ -1,5 +1,9 @@
 pub mod http;
 
+use crate::shared::constants::sgp::{
+    SGP_SERVERS, TENCENT_SERVER_MATCH_HISTORY_INTEROPERABILITY,
+    TENCENT_SERVER_SPECTATOR_INTEROPERABILITY, TENCENT_SERVER_SUMMONER_INTEROPERABILITY,
+};
 use crate::shared::http_api::sgp::http::HttpClient;
 use crate::shared::init::lcu::get_lcu_client;
 use crate::shared::types::sgp::game_detail::SgpGameDetailsLol;
@@ -11,6 +15,33 @@ use crate::shared::types::sgp::summoner::SgpSummoner;
 use crate::utils::error::http_error::HttpError;
 use tungstenite::Bytes;
 
+/// SGP 接口类别，不同类别可以跨区查询的服务器不同
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SgpService {
+    MatchHistory,
+    Summoner,
+    Spectator,
+}
+
+impl SgpService {
+    /// 可以互相查询的服务器
+    pub fn interoperable_servers(&self) -> &'static [&'static str] {
+        match self {
+            SgpService::MatchHistory => TENCENT_SERVER_MATCH_HISTORY_INTEROPERABILITY,
+            SgpService::Summoner => TENCENT_SERVER_SUMMONER_INTEROPERABILITY,
+            SgpService::Spectator => TENCENT_SERVER_SPECTATOR_INTEROPERABILITY,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SgpService::MatchHistory => "战绩",
+            SgpService::Summoner => "召唤师",
+            SgpService::Spectator => "对局",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SgpApi {
     pub rso_platform_id: String,
@@ -27,6 +58,33 @@ impl SgpApi {
         }
     }
 
+    /// 根据服务器 ID（例如：`TENCENT_HN1`）创建客户端，没有该服务器的配置时返回 `None`
+    pub fn for_server(server_id: &str) -> Option<Self> {
+        if !SGP_SERVERS.contains_key(server_id) {
+            return None;
+        }
+        let (region, rso_platform_id) = server_id.split_once('_')?;
+        Some(Self::new(rso_platform_id, region))
+    }
+
+    /// 客户端所在的服务器 ID（例如：`TENCENT_HN1`）
+    pub fn server_id(&self) -> &str {
+        self.client.server_name()
+    }
+
+    /// 把服务器 ID 或平台 ID（例如：`HN10`）转换为服务器 ID，平台 ID 按当前客户端的地区补全
+    pub fn resolve_server_id(&self, server: &str) -> Option<String> {
+        let server = server.to_uppercase();
+        if SGP_SERVERS.contains_key(server.as_str()) {
+            return Some(server);
+        }
+        let (region, _) = self.server_id().split_once('_')?;
+        let server_id = format!("{}_{}", region, server);
+        SGP_SERVERS
+            .contains_key(server_id.as_str())
+            .then_some(server_id)
+    }
+
     pub async fn get_match_history(
         &self,
         player_puuid: &str,
@@ -1,7 +1,7 @@
 use crate::shared::init::lcu::get_lcu_client;
 use crate::utils::error::init_error::InitError;
-use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -10,6 +10,10 @@ pub struct Item {
     pub id: i64,
     pub name: String,
     pub icon_path: String,
+    /// 合成材料，只有装备有
+    pub from: Vec<i64>,
+    /// 可合成的装备，只有装备有
+    pub to: Vec<i64>,
 }
 
 static CHAMPION_ICONS_CACHE: RwLock<Option<Arc<HashMap<i64, Item>>>> = RwLock::const_new(None);
@@ -17,7 +21,7 @@ static ITEM_ICONS_CACHE: RwLock<Option<Arc<HashMap<i64, Item>>>> = RwLock::const
 static SPELL_ICONS_CACHE: RwLock<Option<Arc<HashMap<i64, Item>>>> = RwLock::const_new(None);
 static PERK_ICONS_CACHE: RwLock<Option<Arc<HashMap<i64, Item>>>> = RwLock::const_new(None);
 static PERKSTYLE_ICONS_CACHE: RwLock<Option<Arc<HashMap<i64, Item>>>> = RwLock::const_new(None);
-
+static AUGMENT_ICONS_CACHE: RwLock<Option<Arc<HashMap<i64, Item>>>> = RwLock::const_new(None);
 
 /// 检查缓存是否已初始化
 pub async fn is_cache_initialized() -> bool {
@@ -45,6 +49,7 @@ pub async fn init_perk_style_info_cache() -> Result<(), InitError> {
                 id: perk.id,
                 name: perk.name,
                 icon_path: perk.icon_path,
+                ..Default::default()
             },
         );
     }
@@ -83,6 +88,7 @@ pub async fn init_champion_info_cache() -> Result<(), InitError> {
                 id: champion.id,
                 name: champion.name,
                 icon_path: champion.square_portrait_path,
+                ..Default::default()
             },
         );
     }
@@ -121,6 +127,8 @@ pub async fn init_item_info_cache() -> Result<(), InitError> {
                 id: item.id,
                 name: item.name,
                 icon_path: item.icon_path,
+                from: item.from.into_iter().map(i64::from).collect(),
+                to: item.to.into_iter().map(i64::from).collect(),
             },
         );
     }
@@ -164,6 +172,7 @@ pub async fn init_spell_info_cache() -> Result<(), InitError> {
                 id: spell.id,
                 name: spell.name,
                 icon_path: spell.icon_path,
+                ..Default::default()
             },
         );
     }
@@ -207,6 +216,7 @@ pub async fn init_perk_info_cache() -> Result<(), InitError> {
                 id: perk.id,
                 name: perk.name,
                 icon_path: perk.icon_path,
+                ..Default::default()
             },
         );
     }
@@ -232,3 +242,43 @@ pub async fn clear_perk_info_cache() {
     let mut guard = PERK_ICONS_CACHE.write().await;
     *guard = None;
 }
+
+/// 初始化斗魂竞技场强化符文缓存
+pub async fn init_augment_info_cache() -> Result<(), InitError> {
+    let client = get_lcu_client()
+        .await
+        .map_err(|e: InitError| InitError::Init(e.to_string()))?;
+    let augments = client
+        .game_data
+        .get_augments()
+        .await
+        .map_err(|e| InitError::Init(e.to_string()))?;
+    let mut augments_map = HashMap::new();
+    for augment in augments {
+        augments_map.insert(
+            augment.id as i64,
+            Item {
+                id: augment.id as i64,
+                name: augment.name_tra,
+                icon_path: augment.augment_small_icon_path,
+                ..Default::default()
+            },
+        );
+    }
+    let mut guard = AUGMENT_ICONS_CACHE.write().await;
+    *guard = Some(Arc::new(augments_map));
+    Ok(())
+}
+
+/// 获取强化符文缓存
+///
+/// 强化符文只用于斗魂竞技场，初始化失败不影响其他功能，因此未初始化时返回空表而不是 panic
+pub async fn get_augment_info_cache() -> Arc<HashMap<i64, Item>> {
+    let guard = AUGMENT_ICONS_CACHE.read().await;
+    guard.as_ref().map(Arc::clone).unwrap_or_default()
+}
+
+pub async fn clear_augment_info_cache() {
+    let mut guard = AUGMENT_ICONS_CACHE.write().await;
+    *guard = None;
+}
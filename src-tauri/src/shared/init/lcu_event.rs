@@ -0,0 +1,95 @@
+use crate::shared::http_api::websocket::WebsocketClient;
+use crate::shared::init::lcu::get_lcu_client;
+use crate::shared::init::process::get_process_info;
+use crate::utils::error::init_error::InitError;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::LazyLock;
+use tokio::sync::{broadcast, RwLock};
+
+pub const GAMEFLOW_PHASE_URI: &str = "/lol-gameflow/v1/gameflow-phase";
+pub const CHAMP_SELECT_SESSION_URI: &str = "/lol-champ-select/v1/session";
+pub const READY_CHECK_URI: &str = "/lol-matchmaking/v1/ready-check";
+
+/// 事件通道容量，订阅者处理过慢时会丢弃最旧的事件
+const EVENT_CAPACITY: usize = 256;
+
+/// LCU 推送的事件，`event_type` 为 Create / Update / Delete
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LcuEvent {
+    pub uri: String,
+    pub event_type: String,
+    pub data: Value,
+}
+
+impl LcuEvent {
+    pub fn parse<T: DeserializeOwned>(&self) -> Option<T> {
+        serde_json::from_value(self.data.clone()).ok()
+    }
+}
+
+/// 事件通道在连接前就可以订阅，客户端重启后订阅者不需要重新订阅
+static LCU_EVENT_SENDER: LazyLock<broadcast::Sender<LcuEvent>> =
+    LazyLock::new(|| broadcast::channel(EVENT_CAPACITY).0);
+static LCU_WEBSOCKET: RwLock<Option<WebsocketClient>> = RwLock::const_new(None);
+
+/// 解析 `[8, "OnJsonApiEvent", { uri, eventType, data }]` 格式的消息
+fn parse_message(message: Value) -> Option<LcuEvent> {
+    let payload = message.as_array()?.get(2)?.clone();
+    serde_json::from_value(payload).ok()
+}
+
+pub fn subscribe_lcu_events() -> broadcast::Receiver<LcuEvent> {
+    LCU_EVENT_SENDER.subscribe()
+}
+
+pub fn publish_lcu_event(event: LcuEvent) {
+    // 没有订阅者时发送失败，忽略即可
+    let _ = LCU_EVENT_SENDER.send(event);
+}
+
+pub async fn init_lcu_event() -> Result<(), InitError> {
+    let info = get_process_info().await?;
+    let mut client = WebsocketClient::new(info.port, info.auth_token.clone());
+    client.on_message(|message| {
+        if let Some(event) = parse_message(message) {
+            publish_lcu_event(event);
+        }
+    });
+    // 连接断开时视为游戏流程结束，让订阅者取消正在进行的任务
+    client.on_close(|| {
+        publish_lcu_event(LcuEvent {
+            uri: GAMEFLOW_PHASE_URI.to_string(),
+            event_type: "Delete".to_string(),
+            data: Value::Null,
+        });
+    });
+    client
+        .connect()
+        .await
+        .map_err(|e| InitError::Init(e.to_string()))?;
+    client.send(r#"[5, "OnJsonApiEvent"]"#);
+    let mut guard = LCU_WEBSOCKET.write().await;
+    *guard = Some(client);
+    drop(guard);
+
+    // 连接前已经进入的阶段不会再推送，主动同步一次
+    let lcu = get_lcu_client().await?;
+    if let Ok(phase) = lcu.gameflow.get_gameflow_phase().await {
+        publish_lcu_event(LcuEvent {
+            uri: GAMEFLOW_PHASE_URI.to_string(),
+            event_type: "Update".to_string(),
+            data: serde_json::to_value(phase).unwrap_or_default(),
+        });
+    }
+    Ok(())
+}
+
+pub async fn clear_lcu_event() {
+    let mut guard = LCU_WEBSOCKET.write().await;
+    if let Some(client) = guard.take() {
+        client.close();
+    }
+}
@@ -1,4 +1,5 @@
 pub mod lcu;
+pub mod lcu_event;
 pub mod sgp;
 pub mod process;
 pub mod game_data;
\ No newline at end of file
@@ -1,9 +1,13 @@
-use crate::shared::http_api::sgp::SgpApi;
+use crate::shared::http_api::sgp::{SgpApi, SgpService};
 use crate::shared::process::get_client_info;
 use crate::utils::error::init_error::InitError;
+use crate::utils::error::sgp_error::SgpError;
+use std::collections::BTreeMap;
 use tokio::sync::RwLock;
 
 static SGP_CLIENT: RwLock<Option<SgpApi>> = RwLock::const_new(None);
+/// 跨区查询时其他服务器的客户端，服务器 ID -> 客户端
+static SGP_CLIENT_POOL: RwLock<BTreeMap<String, SgpApi>> = RwLock::const_new(BTreeMap::new());
 
 pub async fn init_sgp_client() -> Result<(), InitError> {
     let info = get_client_info().map_err(|e| InitError::Init(e.to_string()))?;
@@ -21,7 +25,50 @@ pub async fn get_sgp_client() -> Result<SgpApi, InitError> {
         .ok_or_else(|| InitError::Get("获取客户端信息失败".to_string()))
 }
 
+/// 获取指定服务器的客户端，用于跨区查询
+///
+/// # 参数
+/// - `server`: 服务器 ID（如 `TENCENT_HN10`）或平台 ID（如 `HN10`），为空时返回当前服务器的客户端
+/// - `service`: 要使用的接口类别，当前服务器和目标服务器都在该类别的互通列表中才能查询
+pub async fn get_sgp_client_for(
+    server: Option<&str>,
+    service: SgpService,
+) -> Result<SgpApi, SgpError> {
+    let current = get_sgp_client()
+        .await
+        .map_err(|_| SgpError::NotInitialized)?;
+    let Some(server) = server else {
+        return Ok(current);
+    };
+    let target = current
+        .resolve_server_id(server)
+        .ok_or_else(|| SgpError::UnknownServer(server.to_string()))?;
+    if target == current.server_id() {
+        return Ok(current);
+    }
+    let servers = service.interoperable_servers();
+    if !servers.contains(&current.server_id()) || !servers.contains(&target.as_str()) {
+        return Err(SgpError::NotInteroperable {
+            current: current.server_id().to_string(),
+            target,
+            service: service.name(),
+        });
+    }
+
+    if let Some(client) = SGP_CLIENT_POOL.read().await.get(&target) {
+        return Ok(client.clone());
+    }
+    let client =
+        SgpApi::for_server(&target).ok_or_else(|| SgpError::UnknownServer(target.clone()))?;
+    SGP_CLIENT_POOL
+        .write()
+        .await
+        .insert(target, client.clone());
+    Ok(client)
+}
+
 pub async fn clear_sgp_client() {
     let mut guard = SGP_CLIENT.write().await;
     *guard = None;
+    SGP_CLIENT_POOL.write().await.clear();
 }
@@ -1,7 +1,11 @@
+pub mod analysis;
 pub mod constants;
+#[cfg(test)]
+pub mod fixture;
 pub mod http_api;
 pub mod init;
 pub mod process;
+pub mod store;
 pub mod task_runner;
 pub mod types;
 pub mod web_api;
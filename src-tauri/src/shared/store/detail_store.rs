@@ -0,0 +1,23 @@
+//! 对局详情存储
+//!
+//! 对局详情（时间线）体积较大，只保存在磁盘上（`details/<match_id>.json`），按需读取。
+
+use crate::shared::store::{get_store_dir, match_file_name, read_json, write_json};
+use crate::shared::types::sgp::game_detail::SgpGameDetailsLol;
+use crate::utils::error::store_error::StoreError;
+use std::path::PathBuf;
+
+async fn detail_path(match_id: &str) -> Result<PathBuf, StoreError> {
+    Ok(get_store_dir()
+        .await?
+        .join("details")
+        .join(match_file_name(match_id)?))
+}
+
+pub async fn get_stored_detail(match_id: &str) -> Result<Option<SgpGameDetailsLol>, StoreError> {
+    read_json(&detail_path(match_id).await?)
+}
+
+pub async fn save_detail(detail: &SgpGameDetailsLol) -> Result<(), StoreError> {
+    write_json(&detail_path(&detail.metadata.match_id).await?, detail)
+}
@@ -0,0 +1,338 @@
+//! 战绩导出
+//!
+//! 把本地对局按参与者展开成扁平的行，写入 CSV、JSON Lines 或 Parquet 文件。
+//! CSV 和 JSON Lines 逐行写入带缓冲的文件；Parquet 每攒够 `EXPORT_BATCH_ROWS` 行写出一批，
+//! 导出几千局时内存中最多只保留一批行数据。
+
+use crate::shared::analysis::role::{resolve_roles, Role};
+use crate::shared::analysis::score::kda;
+use crate::shared::store::filter::patch_of;
+use crate::shared::types::sgp::history::{Games, Participant};
+use crate::utils::error::export_error::ExportError;
+use arrow_array::{ArrayRef, BooleanArray, Float64Array, Int64Array, RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use parquet::arrow::ArrowWriter;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Parquet 每批写出的行数
+const EXPORT_BATCH_ROWS: usize = 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Csv,
+    Jsonl,
+    Parquet,
+}
+
+/// 导出的一行：一局中一名玩家的数据
+///
+/// 字段名保持 snake_case，方便直接在表格或数据分析工具中使用
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ExportRow {
+    pub match_id: String,
+    pub game_creation: i64,
+    pub queue_id: i64,
+    pub patch: String,
+    /// 对局时长（秒）
+    pub game_duration: i64,
+    pub puuid: String,
+    /// `名称#标签`
+    pub riot_id: String,
+    pub team_id: i64,
+    pub champion_id: i64,
+    pub champion_name: String,
+    /// 判定后的位置（见 `resolve_roles`），没有分路的模式为空
+    pub position: String,
+    pub kills: i64,
+    pub deaths: i64,
+    pub assists: i64,
+    pub kda: f64,
+    pub damage_to_champions: i64,
+    pub damage_taken: i64,
+    pub gold_earned: i64,
+    pub cs: i64,
+    pub vision_score: i64,
+    /// 装备 id（item0 ~ item6），以 `;` 分隔
+    pub items: String,
+    /// 符文 id（主系、副系、属性碎片），以 `;` 分隔
+    pub runes: String,
+    pub win: bool,
+}
+
+impl ExportRow {
+    /// # 参数
+    /// - `role`: 玩家判定后的位置
+    /// - `champion_names`: 英雄 id 到展示名称的映射，找不到时使用对局数据中的英雄名
+    pub fn new(
+        game: &Games,
+        participant: &Participant,
+        role: Option<Role>,
+        champion_names: &HashMap<i64, String>,
+    ) -> Self {
+        let items = [
+            participant.item0,
+            participant.item1,
+            participant.item2,
+            participant.item3,
+            participant.item4,
+            participant.item5,
+            participant.item6,
+        ];
+        let stat_perks = &participant.perks.stat_perks;
+        let runes = participant
+            .perks
+            .styles
+            .iter()
+            .flat_map(|style| style.selections.iter().map(|selection| selection.perk))
+            .chain([stat_perks.offense, stat_perks.flex, stat_perks.defense])
+            .filter(|perk| *perk != 0);
+
+        ExportRow {
+            match_id: game.metadata.match_id.clone(),
+            game_creation: game.json.game_creation,
+            queue_id: game.json.queue_id,
+            patch: patch_of(&game.json.game_version),
+            game_duration: game.json.game_duration,
+            puuid: participant.puuid.clone(),
+            riot_id: format!(
+                "{}#{}",
+                participant.riot_id_game_name, participant.riot_id_tagline
+            ),
+            team_id: participant.team_id,
+            champion_id: participant.champion_id,
+            champion_name: champion_names
+                .get(&participant.champion_id)
+                .cloned()
+                .unwrap_or_else(|| participant.champion_name.clone()),
+            position: role
+                .map(|role| role.as_str().to_string())
+                .unwrap_or_default(),
+            kills: participant.kills,
+            deaths: participant.deaths,
+            assists: participant.assists,
+            kda: kda(participant.kills, participant.deaths, participant.assists),
+            damage_to_champions: participant.total_damage_dealt_to_champions,
+            damage_taken: participant.total_damage_taken,
+            gold_earned: participant.gold_earned,
+            cs: participant.total_minions_killed + participant.neutral_minions_killed,
+            vision_score: participant.vision_score,
+            items: join_ids(items.into_iter().filter(|item| *item != 0)),
+            runes: join_ids(runes),
+            win: participant.win,
+        }
+    }
+}
+
+fn join_ids(ids: impl Iterator<Item = i64>) -> String {
+    ids.map(|id| id.to_string()).collect::<Vec<_>>().join(";")
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportSummary {
+    /// 导出的对局数
+    pub games: usize,
+    /// 导出的行数
+    pub rows: usize,
+    pub path: String,
+}
+
+trait RowWriter {
+    fn write_row(&mut self, row: ExportRow) -> Result<(), ExportError>;
+    /// 写出剩余的数据并关闭文件
+    fn finish(self: Box<Self>) -> Result<(), ExportError>;
+}
+
+struct CsvRowWriter(csv::Writer<BufWriter<File>>);
+
+impl RowWriter for CsvRowWriter {
+    fn write_row(&mut self, row: ExportRow) -> Result<(), ExportError> {
+        self.0.serialize(row).map_err(ExportError::Csv)
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<(), ExportError> {
+        self.0.flush().map_err(ExportError::Io)
+    }
+}
+
+struct JsonlRowWriter(BufWriter<File>);
+
+impl RowWriter for JsonlRowWriter {
+    fn write_row(&mut self, row: ExportRow) -> Result<(), ExportError> {
+        serde_json::to_writer(&mut self.0, &row).map_err(ExportError::Json)?;
+        self.0.write_all(b"\n").map_err(ExportError::Io)
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<(), ExportError> {
+        self.0.flush().map_err(ExportError::Io)
+    }
+}
+
+struct ParquetRowWriter {
+    writer: ArrowWriter<File>,
+    schema: SchemaRef,
+    buffer: Vec<ExportRow>,
+}
+
+impl ParquetRowWriter {
+    fn new(file: File) -> Result<Self, ExportError> {
+        let schema = Arc::new(export_schema());
+        let writer =
+            ArrowWriter::try_new(file, Arc::clone(&schema), None).map_err(ExportError::Parquet)?;
+        Ok(ParquetRowWriter {
+            writer,
+            schema,
+            buffer: Vec::with_capacity(EXPORT_BATCH_ROWS),
+        })
+    }
+
+    fn flush_batch(&mut self) -> Result<(), ExportError> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let batch = export_batch(Arc::clone(&self.schema), &self.buffer)?;
+        self.writer.write(&batch).map_err(ExportError::Parquet)?;
+        self.buffer.clear();
+        Ok(())
+    }
+}
+
+impl RowWriter for ParquetRowWriter {
+    fn write_row(&mut self, row: ExportRow) -> Result<(), ExportError> {
+        self.buffer.push(row);
+        if self.buffer.len() >= EXPORT_BATCH_ROWS {
+            self.flush_batch()?;
+        }
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<(), ExportError> {
+        self.flush_batch()?;
+        self.writer.close().map_err(ExportError::Parquet)?;
+        Ok(())
+    }
+}
+
+/// Parquet 文件的列定义，与 `ExportRow` 的字段一一对应
+fn export_schema() -> Schema {
+    let string = |name| Field::new(name, DataType::Utf8, false);
+    let int = |name| Field::new(name, DataType::Int64, false);
+    Schema::new(vec![
+        string("match_id"),
+        int("game_creation"),
+        int("queue_id"),
+        string("patch"),
+        int("game_duration"),
+        string("puuid"),
+        string("riot_id"),
+        int("team_id"),
+        int("champion_id"),
+        string("champion_name"),
+        string("position"),
+        int("kills"),
+        int("deaths"),
+        int("assists"),
+        Field::new("kda", DataType::Float64, false),
+        int("damage_to_champions"),
+        int("damage_taken"),
+        int("gold_earned"),
+        int("cs"),
+        int("vision_score"),
+        string("items"),
+        string("runes"),
+        Field::new("win", DataType::Boolean, false),
+    ])
+}
+
+fn export_batch(schema: SchemaRef, rows: &[ExportRow]) -> Result<RecordBatch, ExportError> {
+    let string = |get: fn(&ExportRow) -> &str| -> ArrayRef {
+        Arc::new(StringArray::from_iter_values(rows.iter().map(get)))
+    };
+    let int = |get: fn(&ExportRow) -> i64| -> ArrayRef {
+        Arc::new(Int64Array::from_iter_values(rows.iter().map(get)))
+    };
+    let columns = vec![
+        string(|row| &row.match_id),
+        int(|row| row.game_creation),
+        int(|row| row.queue_id),
+        string(|row| &row.patch),
+        int(|row| row.game_duration),
+        string(|row| &row.puuid),
+        string(|row| &row.riot_id),
+        int(|row| row.team_id),
+        int(|row| row.champion_id),
+        string(|row| &row.champion_name),
+        string(|row| &row.position),
+        int(|row| row.kills),
+        int(|row| row.deaths),
+        int(|row| row.assists),
+        Arc::new(Float64Array::from_iter_values(
+            rows.iter().map(|row| row.kda),
+        )) as ArrayRef,
+        int(|row| row.damage_to_champions),
+        int(|row| row.damage_taken),
+        int(|row| row.gold_earned),
+        int(|row| row.cs),
+        int(|row| row.vision_score),
+        string(|row| &row.items),
+        string(|row| &row.runes),
+        Arc::new(BooleanArray::from(
+            rows.iter().map(|row| row.win).collect::<Vec<_>>(),
+        )),
+    ];
+    RecordBatch::try_new(schema, columns).map_err(ExportError::Arrow)
+}
+
+fn open_writer(format: ExportFormat, path: &Path) -> Result<Box<dyn RowWriter>, ExportError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(ExportError::Io)?;
+    }
+    let file = File::create(path).map_err(ExportError::Io)?;
+    Ok(match format {
+        ExportFormat::Csv => Box::new(CsvRowWriter(csv::Writer::from_writer(BufWriter::new(file)))),
+        ExportFormat::Jsonl => Box::new(JsonlRowWriter(BufWriter::new(file))),
+        ExportFormat::Parquet => Box::new(ParquetRowWriter::new(file)?),
+    })
+}
+
+/// 把对局逐行导出到文件
+///
+/// # 参数
+/// - `games`: 要导出的对局，按给定顺序写出
+/// - `puuid`: 只导出该玩家的数据；`all_participants` 为 true 时导出每局的全部玩家
+/// - `path`: 导出文件路径，已存在时会被覆盖
+/// - `champion_names`: 英雄 id 到展示名称的映射
+pub fn export_games(
+    games: &[Arc<Games>],
+    puuid: &str,
+    all_participants: bool,
+    format: ExportFormat,
+    path: &Path,
+    champion_names: &HashMap<i64, String>,
+) -> Result<ExportSummary, ExportError> {
+    let mut writer = open_writer(format, path)?;
+    let mut rows = 0;
+    for game in games {
+        let roles = resolve_roles(&game.json.participants);
+        for (participant, role) in game.json.participants.iter().zip(roles) {
+            if !all_participants && participant.puuid != puuid {
+                continue;
+            }
+            writer.write_row(ExportRow::new(game, participant, role, champion_names))?;
+            rows += 1;
+        }
+    }
+    writer.finish()?;
+
+    Ok(ExportSummary {
+        games: games.len(),
+        rows,
+        path: path.to_string_lossy().into_owned(),
+    })
+}
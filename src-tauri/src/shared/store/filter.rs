@@ -0,0 +1,113 @@
+use crate::shared::analysis::role::{player_role, Role};
+use crate::shared::types::sgp::history::{Games, Participant};
+use serde::{Deserialize, Serialize};
+
+/// 对局筛选条件，所有条件为空时匹配全部对局
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct MatchFilter {
+    /// 队列 ID，如 420 单双排、440 灵活排位
+    pub queue_ids: Vec<i64>,
+    pub champion_ids: Vec<i64>,
+    /// 判定后的位置：TOP / JUNGLE / MID / BOTTOM / UTILITY，也接受 `teamPosition` 的 MIDDLE
+    pub positions: Vec<String>,
+    /// 对局开始时间下限（毫秒时间戳，包含）
+    pub start_time: Option<i64>,
+    /// 对局开始时间上限（毫秒时间戳，不包含）
+    pub end_time: Option<i64>,
+    /// 胜负，为空时不限
+    pub win: Option<bool>,
+    /// 版本号，如 "15.1"
+    pub patches: Vec<String>,
+    /// 必须同队的玩家 puuid
+    pub with_puuids: Vec<String>,
+    /// 不能同队的玩家 puuid
+    pub without_puuids: Vec<String>,
+}
+
+/// 从 `gameVersion`（如 "15.1.650.1234"）中取出 "15.1"
+pub fn patch_of(game_version: &str) -> String {
+    game_version
+        .split('.')
+        .take(2)
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// 玩家在该对局中的队友（不含自己）
+pub fn teammates<'a>(
+    game: &'a Games,
+    player: &'a Participant,
+) -> impl Iterator<Item = &'a Participant> {
+    game.json
+        .participants
+        .iter()
+        .filter(move |p| p.team_id == player.team_id && p.puuid != player.puuid)
+}
+
+impl MatchFilter {
+    /// 判断玩家在该对局中的数据是否满足筛选条件，玩家不在对局中时返回 false
+    pub fn matches(&self, game: &Games, puuid: &str) -> bool {
+        let Some(participant) = game.json.participants.iter().find(|p| p.puuid == puuid) else {
+            return false;
+        };
+
+        if !self.queue_ids.is_empty() && !self.queue_ids.contains(&game.json.queue_id) {
+            return false;
+        }
+        if !self.champion_ids.is_empty() && !self.champion_ids.contains(&participant.champion_id) {
+            return false;
+        }
+        if !self.positions.is_empty() {
+            let Some(role) = player_role(&game.json.participants, puuid) else {
+                return false;
+            };
+            if !self
+                .positions
+                .iter()
+                .any(|position| Role::parse(position) == Some(role))
+            {
+                return false;
+            }
+        }
+        if self
+            .start_time
+            .is_some_and(|start| game.json.game_creation < start)
+        {
+            return false;
+        }
+        if self
+            .end_time
+            .is_some_and(|end| game.json.game_creation >= end)
+        {
+            return false;
+        }
+        if self.win.is_some_and(|win| win != participant.win) {
+            return false;
+        }
+        if !self.patches.is_empty() && !self.patches.contains(&patch_of(&game.json.game_version)) {
+            return false;
+        }
+        if !self.with_puuids.is_empty() || !self.without_puuids.is_empty() {
+            let mates: Vec<&str> = teammates(game, participant)
+                .map(|p| p.puuid.as_str())
+                .collect();
+            if !self
+                .with_puuids
+                .iter()
+                .all(|puuid| mates.contains(&puuid.as_str()))
+            {
+                return false;
+            }
+            if self
+                .without_puuids
+                .iter()
+                .any(|puuid| mates.contains(&puuid.as_str()))
+            {
+                return false;
+            }
+        }
+        true
+    }
+}
@@ -0,0 +1,190 @@
+//! 对局存储
+//!
+//! 保存 SGP 战绩接口返回的对局概要（`Games`），一局一个文件（`matches/<match_id>.json`）。
+//! 启动时全部加载到内存并建立 puuid -> 对局的索引，查询时不再访问磁盘。
+
+use crate::shared::store::{get_store_dir, match_file_name, read_json, write_json};
+use crate::shared::types::sgp::history::Games;
+use crate::utils::error::store_error::StoreError;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::{Mutex, RwLock};
+use tracing::{info, warn};
+
+#[derive(Debug, Default)]
+pub struct MatchStore {
+    dir: PathBuf,
+    /// match_id -> 对局
+    games: HashMap<String, Arc<Games>>,
+    /// puuid -> match_id 列表
+    player_index: HashMap<String, Vec<String>>,
+}
+
+impl MatchStore {
+    /// 从目录加载所有对局，无法解析的文件会被跳过
+    pub fn load(dir: PathBuf) -> Result<Self, StoreError> {
+        fs::create_dir_all(&dir).map_err(StoreError::Io)?;
+        let mut store = Self {
+            dir,
+            ..Default::default()
+        };
+
+        for entry in fs::read_dir(&store.dir).map_err(StoreError::Io)? {
+            let path = entry.map_err(StoreError::Io)?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            match read_json::<Games>(&path) {
+                Ok(Some(game)) => store.index(Arc::new(game)),
+                Ok(None) => {}
+                Err(e) => warn!("加载对局文件 {:?} 失败: {}", path, e),
+            }
+        }
+
+        Ok(store)
+    }
+
+    fn index(&mut self, game: Arc<Games>) {
+        let match_id = game.metadata.match_id.clone();
+        for participant in &game.json.participants {
+            self.player_index
+                .entry(participant.puuid.clone())
+                .or_default()
+                .push(match_id.clone());
+        }
+        self.games.insert(match_id, game);
+    }
+
+    /// 写入对局文件，不修改内存中的索引
+    pub fn write_game(dir: &Path, game: &Games) -> Result<(), StoreError> {
+        write_json(&dir.join(match_file_name(&game.metadata.match_id)?), game)
+    }
+
+    /// 是否需要保存：match_id 不为空且还没有保存过
+    pub fn is_new(&self, game: &Games) -> bool {
+        let match_id = &game.metadata.match_id;
+        !match_id.is_empty() && !self.games.contains_key(match_id)
+    }
+
+    /// 把已经写入文件的对局加入索引，已经存在的对局会被忽略
+    pub fn add(&mut self, game: Arc<Games>) {
+        if self.is_new(&game) {
+            self.index(game);
+        }
+    }
+
+    /// 保存对局，已经存在的对局不会重复写入
+    pub fn insert(&mut self, game: &Games) -> Result<(), StoreError> {
+        if !self.is_new(game) {
+            return Ok(());
+        }
+        Self::write_game(&self.dir, game)?;
+        self.index(Arc::new(game.clone()));
+        Ok(())
+    }
+
+    pub fn get(&self, match_id: &str) -> Option<Arc<Games>> {
+        self.games.get(match_id).cloned()
+    }
+
+    /// 获取玩家的所有对局（按开始时间从新到旧）
+    pub fn player_games(&self, puuid: &str) -> Vec<Arc<Games>> {
+        let mut games: Vec<Arc<Games>> = self
+            .player_index
+            .get(puuid)
+            .map(|match_ids| {
+                match_ids
+                    .iter()
+                    .filter_map(|match_id| self.games.get(match_id).cloned())
+                    .collect()
+            })
+            .unwrap_or_default();
+        games.sort_by_key(|game| std::cmp::Reverse(game.json.game_creation));
+        games
+    }
+
+    pub fn len(&self) -> usize {
+        self.games.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.games.is_empty()
+    }
+}
+
+static MATCH_STORE: RwLock<Option<MatchStore>> = RwLock::const_new(None);
+/// 串行化保存，写文件期间不持有 `MATCH_STORE` 的锁，不阻塞查询
+static SAVE_LOCK: Mutex<()> = Mutex::const_new(());
+
+pub async fn init_match_store() -> Result<(), StoreError> {
+    let dir = get_store_dir().await?.join("matches");
+    let store = MatchStore::load(dir)?;
+    info!("已加载本地对局 {} 局", store.len());
+    let mut guard = MATCH_STORE.write().await;
+    *guard = Some(store);
+    Ok(())
+}
+
+/// 保存对局，已经存在的对局不会重复写入，match_id 无效的对局会被跳过
+///
+/// 文件在阻塞线程池中写入，写入成功的对局再加入索引；中途失败时返回第一个错误，之前写入的对局仍然保留。
+pub async fn save_games(games: &[Games]) -> Result<(), StoreError> {
+    let _save = SAVE_LOCK.lock().await;
+    let (dir, new_games) = {
+        let guard = MATCH_STORE.read().await;
+        let store = guard.as_ref().ok_or(StoreError::NotInitialized)?;
+        let mut seen = HashSet::new();
+        let new_games: Vec<Games> = games
+            .iter()
+            .filter(|game| store.is_new(game) && seen.insert(game.metadata.match_id.as_str()))
+            .filter(|game| match match_file_name(&game.metadata.match_id) {
+                Ok(_) => true,
+                Err(e) => {
+                    warn!("跳过对局: {}", e);
+                    false
+                }
+            })
+            .cloned()
+            .collect();
+        (store.dir.clone(), new_games)
+    };
+    if new_games.is_empty() {
+        return Ok(());
+    }
+
+    let (written, result) = tokio::task::spawn_blocking(move || {
+        let mut written = Vec::with_capacity(new_games.len());
+        for game in new_games {
+            if let Err(e) = MatchStore::write_game(&dir, &game) {
+                return (written, Err(e));
+            }
+            written.push(Arc::new(game));
+        }
+        (written, Ok(()))
+    })
+    .await
+    .map_err(|e| StoreError::Io(std::io::Error::other(e)))?;
+
+    let mut guard = MATCH_STORE.write().await;
+    if let Some(store) = guard.as_mut() {
+        for game in written {
+            store.add(game);
+        }
+    }
+    result
+}
+
+pub async fn get_stored_game(match_id: &str) -> Result<Option<Arc<Games>>, StoreError> {
+    let guard = MATCH_STORE.read().await;
+    let store = guard.as_ref().ok_or(StoreError::NotInitialized)?;
+    Ok(store.get(match_id))
+}
+
+/// 获取本地保存的玩家对局（按开始时间从新到旧）
+pub async fn get_player_games(puuid: &str) -> Result<Vec<Arc<Games>>, StoreError> {
+    let guard = MATCH_STORE.read().await;
+    let store = guard.as_ref().ok_or(StoreError::NotInitialized)?;
+    Ok(store.player_games(puuid))
+}
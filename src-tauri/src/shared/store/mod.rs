@@ -0,0 +1,90 @@
+//! 本地存储
+//!
+//! 所有数据以 JSON 文件的形式保存在应用数据目录下，目录在应用启动时通过 `init_store_dir` 设置。
+
+#[cfg(test)]
+pub mod tests;
+
+pub mod detail_store;
+pub mod export;
+pub mod filter;
+pub mod match_store;
+pub mod note_store;
+pub mod query;
+pub mod rank_store;
+pub mod ready_check_store;
+pub mod report_store;
+
+use crate::utils::error::store_error::StoreError;
+use serde::{de::DeserializeOwned, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tokio::sync::RwLock;
+
+static STORE_DIR: RwLock<Option<PathBuf>> = RwLock::const_new(None);
+
+pub async fn init_store_dir(dir: PathBuf) -> Result<(), StoreError> {
+    fs::create_dir_all(&dir).map_err(StoreError::Io)?;
+    let mut guard = STORE_DIR.write().await;
+    *guard = Some(dir);
+    Ok(())
+}
+
+pub async fn get_store_dir() -> Result<PathBuf, StoreError> {
+    let guard = STORE_DIR.read().await;
+    guard.clone().ok_or(StoreError::NotInitialized)
+}
+
+/// 按对局保存的文件名（`<match_id>.json`）
+///
+/// match_id 可能来自前端，只接受 `HN1_1234567890` 这样的格式，避免拼出存储目录之外的路径。
+pub fn match_file_name(match_id: &str) -> Result<String, StoreError> {
+    let valid = match_id.split_once('_').is_some_and(|(platform, game_id)| {
+        !platform.is_empty()
+            && platform
+                .bytes()
+                .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+            && !game_id.is_empty()
+            && game_id.bytes().all(|b| b.is_ascii_digit())
+    });
+    if !valid {
+        return Err(StoreError::InvalidMatchId(match_id.to_string()));
+    }
+    Ok(format!("{}.json", match_id))
+}
+
+/// 按账号保存的文件名（`<puuid>.json`）
+///
+/// puuid 可能来自前端，只接受字母、数字和 `-`，避免拼出存储目录之外的路径。
+pub fn player_file_name(puuid: &str) -> Result<String, StoreError> {
+    let valid = !puuid.is_empty()
+        && puuid
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-');
+    if !valid {
+        return Err(StoreError::InvalidPuuid(puuid.to_string()));
+    }
+    Ok(format!("{}.json", puuid))
+}
+
+/// 读取 JSON 文件，文件不存在时返回 `Ok(None)`
+pub fn read_json<T: DeserializeOwned>(path: &Path) -> Result<Option<T>, StoreError> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(path).map_err(StoreError::Io)?;
+    let data = serde_json::from_str(&content).map_err(StoreError::Json)?;
+    Ok(Some(data))
+}
+
+/// 写入 JSON 文件：先写临时文件再重命名，避免写到一半时留下损坏的文件
+pub fn write_json<T: Serialize>(path: &Path, data: &T) -> Result<(), StoreError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(StoreError::Io)?;
+    }
+    let content = serde_json::to_string(data).map_err(StoreError::Json)?;
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, content).map_err(StoreError::Io)?;
+    fs::rename(&tmp_path, path).map_err(StoreError::Io)?;
+    Ok(())
+}
@@ -0,0 +1,228 @@
+//! 玩家备注存储
+//!
+//! 所有玩家的备注按 puuid 保存在 `notes.json` 中，每次读写整个文件。
+//! 备注数量通常只有几百条，不需要常驻内存。
+
+use crate::shared::store::{get_store_dir, read_json, write_json};
+use crate::utils::error::store_error::StoreError;
+use crate::utils::time::now_millis;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tokio::sync::Mutex;
+use tracing::warn;
+
+/// 评分的取值范围
+pub const MIN_RATING: u8 = 1;
+pub const MAX_RATING: u8 = 5;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerNote {
+    pub puuid: String,
+    /// 保存备注时的名称，玩家改名后不会自动更新
+    pub game_name: String,
+    pub tag_line: String,
+    pub note: String,
+    /// 去掉首尾空白并去重，保持添加顺序
+    pub tags: Vec<String>,
+    /// 1~5，未评分时为空
+    pub rating: Option<u8>,
+    /// 是否在回避名单中，出现在对局里时会发出提醒
+    #[serde(default)]
+    pub avoid: bool,
+    /// 毫秒
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+/// 新建或修改备注时前端传入的内容
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerNoteInput {
+    pub puuid: String,
+    #[serde(default)]
+    pub game_name: String,
+    #[serde(default)]
+    pub tag_line: String,
+    #[serde(default)]
+    pub note: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub rating: Option<u8>,
+    /// 为空时保持原来的状态
+    #[serde(default)]
+    pub avoid: Option<bool>,
+}
+
+fn normalize_tags(tags: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> = vec![];
+    for tag in tags.iter().map(|tag| tag.trim()) {
+        if !tag.is_empty() && !normalized.iter().any(|existing| existing == tag) {
+            normalized.push(tag.to_string());
+        }
+    }
+    normalized
+}
+
+/// 全部玩家备注，puuid -> 备注
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(transparent)]
+pub struct NoteBook {
+    notes: HashMap<String, PlayerNote>,
+}
+
+impl NoteBook {
+    pub fn get(&self, puuid: &str) -> Option<&PlayerNote> {
+        self.notes.get(puuid)
+    }
+
+    /// 新建或覆盖备注，覆盖时保留创建时间
+    ///
+    /// 名称为空时沿用之前保存的名称。
+    pub fn upsert(&mut self, input: PlayerNoteInput, now: i64) -> Result<PlayerNote, StoreError> {
+        if input.puuid.is_empty() {
+            return Err(StoreError::InvalidNote("puuid 为空".to_string()));
+        }
+        if let Some(rating) = input.rating {
+            if !(MIN_RATING..=MAX_RATING).contains(&rating) {
+                return Err(StoreError::InvalidNote(format!(
+                    "评分应为 {}~{}，实际为 {}",
+                    MIN_RATING, MAX_RATING, rating
+                )));
+            }
+        }
+
+        let previous = self.notes.get(&input.puuid);
+        let keep = |value: String, old: Option<&String>| match old {
+            Some(old) if value.is_empty() => old.clone(),
+            _ => value,
+        };
+        let note = PlayerNote {
+            game_name: keep(input.game_name, previous.map(|note| &note.game_name)),
+            tag_line: keep(input.tag_line, previous.map(|note| &note.tag_line)),
+            note: input.note.trim().to_string(),
+            tags: normalize_tags(&input.tags),
+            rating: input.rating,
+            avoid: input
+                .avoid
+                .unwrap_or_else(|| previous.is_some_and(|note| note.avoid)),
+            created_at: previous.map_or(now, |note| note.created_at),
+            updated_at: now,
+            puuid: input.puuid,
+        };
+        self.notes.insert(note.puuid.clone(), note.clone());
+        Ok(note)
+    }
+
+    /// 加入或移出回避名单，没有备注时新建一条空备注
+    pub fn set_avoid(
+        &mut self,
+        puuid: &str,
+        avoid: bool,
+        now: i64,
+    ) -> Result<PlayerNote, StoreError> {
+        let input = match self.notes.get(puuid) {
+            Some(note) => PlayerNoteInput {
+                puuid: note.puuid.clone(),
+                note: note.note.clone(),
+                tags: note.tags.clone(),
+                rating: note.rating,
+                avoid: Some(avoid),
+                ..Default::default()
+            },
+            None => PlayerNoteInput {
+                puuid: puuid.to_string(),
+                avoid: Some(avoid),
+                ..Default::default()
+            },
+        };
+        self.upsert(input, now)
+    }
+
+    /// 回避名单中的玩家的备注
+    pub fn avoided(&self, puuid: &str) -> Option<&PlayerNote> {
+        self.notes.get(puuid).filter(|note| note.avoid)
+    }
+
+    /// 删除备注，返回是否存在
+    pub fn remove(&mut self, puuid: &str) -> bool {
+        self.notes.remove(puuid).is_some()
+    }
+
+    /// 列出备注，按修改时间从新到旧
+    ///
+    /// # 参数
+    /// - `tag`: 只返回带有该标签的备注，为空时返回全部
+    /// - `avoid_only`: 只返回回避名单中的玩家
+    pub fn list(&self, tag: Option<&str>, avoid_only: bool) -> Vec<PlayerNote> {
+        let mut notes: Vec<PlayerNote> = self
+            .notes
+            .values()
+            .filter(|note| tag.is_none_or(|tag| note.tags.iter().any(|t| t == tag)))
+            .filter(|note| !avoid_only || note.avoid)
+            .cloned()
+            .collect();
+        notes.sort_by(|a, b| b.updated_at.cmp(&a.updated_at).then(a.puuid.cmp(&b.puuid)));
+        notes
+    }
+}
+
+/// 读-改-写需要串行
+static NOTE_STORE_LOCK: Mutex<()> = Mutex::const_new(());
+
+async fn notes_path() -> Result<PathBuf, StoreError> {
+    Ok(get_store_dir().await?.join("notes.json"))
+}
+
+pub async fn get_note_book() -> Result<NoteBook, StoreError> {
+    Ok(read_json(&notes_path().await?)?.unwrap_or_default())
+}
+
+pub async fn get_player_note(puuid: &str) -> Result<Option<PlayerNote>, StoreError> {
+    Ok(get_note_book().await?.get(puuid).cloned())
+}
+
+pub async fn list_player_notes(
+    tag: Option<&str>,
+    avoid_only: bool,
+) -> Result<Vec<PlayerNote>, StoreError> {
+    Ok(get_note_book().await?.list(tag, avoid_only))
+}
+
+pub async fn save_player_note(input: PlayerNoteInput) -> Result<PlayerNote, StoreError> {
+    let _guard = NOTE_STORE_LOCK.lock().await;
+    let path = notes_path().await?;
+    let mut book: NoteBook = read_json(&path)?.unwrap_or_default();
+    let note = book.upsert(input, now_millis())?;
+    write_json(&path, &book)?;
+    Ok(note)
+}
+
+pub async fn set_player_avoid(puuid: &str, avoid: bool) -> Result<PlayerNote, StoreError> {
+    let _guard = NOTE_STORE_LOCK.lock().await;
+    let path = notes_path().await?;
+    let mut book: NoteBook = read_json(&path)?.unwrap_or_default();
+    let note = book.set_avoid(puuid, avoid, now_millis())?;
+    write_json(&path, &book)?;
+    Ok(note)
+}
+
+pub async fn delete_player_note(puuid: &str) -> Result<bool, StoreError> {
+    let _guard = NOTE_STORE_LOCK.lock().await;
+    let path = notes_path().await?;
+    let mut book: NoteBook = read_json(&path)?.unwrap_or_default();
+    let removed = book.remove(puuid);
+    if removed {
+        write_json(&path, &book)?;
+    }
+    Ok(removed)
+}
+
+/// 读取备注用于附加到玩家信息上，读取失败时只记录日志并返回空的备注
+pub async fn load_notes_for_display() -> NoteBook {
+    get_note_book()
+        .await
+        .inspect_err(|e| warn!("读取玩家备注失败: {}", e))
+        .unwrap_or_default()
+}
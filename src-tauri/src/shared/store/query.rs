@@ -0,0 +1,388 @@
+//! 本地对局查询
+//!
+//! 在本地保存的对局上做筛选、排序和游标分页，同时计算筛选框需要的候选项统计（facets）。
+//! 每一类候选项的统计都会忽略该类自身的筛选条件，这样选中某个英雄后英雄下拉框仍会显示其他英雄。
+
+use crate::shared::analysis::role::{resolve_roles, Role};
+use crate::shared::analysis::score::{kda, score_sgp_game, ScoreBadge};
+use crate::shared::store::filter::{patch_of, teammates, MatchFilter};
+use crate::shared::types::sgp::history::{Games, Participant};
+use crate::utils::config::score::ScoreConfig;
+use crate::utils::error::store_error::StoreError;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Arc;
+
+/// 默认每页数量
+const DEFAULT_PAGE_SIZE: usize = 20;
+/// 队友候选项最多返回的数量
+const MAX_TEAMMATE_FACETS: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RecordSort {
+    /// 按时间从新到旧
+    #[default]
+    Newest,
+    /// 按时间从旧到新
+    Oldest,
+    /// 按 KDA 从高到低
+    Kda,
+    /// 按对局时长从长到短
+    Duration,
+}
+
+/// 最佳标记筛选
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BadgeFilter {
+    Mvp,
+    Ace,
+    /// 没有获得最佳标记
+    None,
+}
+
+impl BadgeFilter {
+    fn of(badge: Option<ScoreBadge>) -> Self {
+        match badge {
+            Some(ScoreBadge::Mvp) => BadgeFilter::Mvp,
+            Some(ScoreBadge::Ace) => BadgeFilter::Ace,
+            None => BadgeFilter::None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct RecordQuery {
+    pub puuid: String,
+    pub filter: MatchFilter,
+    pub badge: Option<BadgeFilter>,
+    pub sort: RecordSort,
+    /// 上一页返回的 `nextCursor`，为空时从第一页开始
+    pub cursor: Option<String>,
+    /// 每页数量，默认为 `DEFAULT_PAGE_SIZE`
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FacetCount<T> {
+    pub value: T,
+    /// 展示名称，英雄名或玩家名，其余为空
+    pub label: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordFacets {
+    pub queues: Vec<FacetCount<i64>>,
+    pub champions: Vec<FacetCount<i64>>,
+    pub positions: Vec<FacetCount<Role>>,
+    pub patches: Vec<FacetCount<String>>,
+    pub results: Vec<FacetCount<bool>>,
+    pub badges: Vec<FacetCount<BadgeFilter>>,
+    /// 同队次数最多的玩家
+    pub teammates: Vec<FacetCount<String>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct QueryPage {
+    pub games: Vec<Arc<Games>>,
+    /// 满足筛选条件的总局数
+    pub total: usize,
+    /// 没有下一页时为空
+    pub next_cursor: Option<String>,
+    pub facets: RecordFacets,
+}
+
+/// 游标：上一页最后一局的排序键
+#[derive(Debug, Serialize, Deserialize)]
+struct Cursor {
+    sort: RecordSort,
+    key: i64,
+    match_id: String,
+}
+
+impl Cursor {
+    fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(serde_json::to_vec(self).unwrap_or_default())
+    }
+
+    fn decode(cursor: &str, sort: RecordSort) -> Result<Self, StoreError> {
+        let bytes = URL_SAFE_NO_PAD
+            .decode(cursor)
+            .map_err(|_| StoreError::InvalidCursor)?;
+        let cursor: Cursor =
+            serde_json::from_slice(&bytes).map_err(|_| StoreError::InvalidCursor)?;
+        // 排序方式变化后旧游标没有意义
+        if cursor.sort != sort {
+            return Err(StoreError::InvalidCursor);
+        }
+        Ok(cursor)
+    }
+}
+
+struct Row<'a> {
+    game: &'a Arc<Games>,
+    participant: &'a Participant,
+    role: Option<Role>,
+    badge: BadgeFilter,
+    key: i64,
+}
+
+fn sort_key(sort: RecordSort, game: &Games, participant: &Participant) -> i64 {
+    match sort {
+        RecordSort::Newest | RecordSort::Oldest => game.json.game_creation,
+        RecordSort::Kda => (kda(participant.kills, participant.deaths, participant.assists)
+            * 1000.0)
+            .round() as i64,
+        RecordSort::Duration => game.json.game_duration,
+    }
+}
+
+/// 排序：`Less` 表示排在前面，排序键相同时按 match_id 保证顺序稳定
+fn compare(sort: RecordSort, a: (i64, &str), b: (i64, &str)) -> Ordering {
+    match sort {
+        RecordSort::Oldest => a.cmp(&b),
+        _ => b.cmp(&a),
+    }
+}
+
+fn count_by<T: Eq + Hash + Ord + Clone>(
+    values: impl Iterator<Item = T>,
+    label: impl Fn(&T) -> String,
+) -> Vec<FacetCount<T>> {
+    let mut counts: HashMap<T, usize> = HashMap::new();
+    for value in values {
+        *counts.entry(value).or_default() += 1;
+    }
+    let mut facets: Vec<FacetCount<T>> = counts
+        .into_iter()
+        .map(|(value, count)| FacetCount {
+            label: label(&value),
+            value,
+            count,
+        })
+        .collect();
+    facets.sort_by(|a, b| b.count.cmp(&a.count).then(a.value.cmp(&b.value)));
+    facets
+}
+
+fn compute_facets(rows: &[Row], query: &RecordQuery) -> RecordFacets {
+    let puuid = query.puuid.as_str();
+    let badge_ok = |row: &&Row| query.badge.is_none_or(|badge| badge == row.badge);
+    // 忽略某一类筛选条件后剩下的对局
+    let rows_without = |filter: MatchFilter| -> Vec<&Row> {
+        rows.iter()
+            .filter(|row| filter.matches(row.game, puuid))
+            .filter(badge_ok)
+            .collect()
+    };
+
+    let mut names: HashMap<String, String> = HashMap::new();
+    let teammate_rows = rows_without(MatchFilter {
+        with_puuids: vec![],
+        without_puuids: vec![],
+        ..query.filter.clone()
+    });
+    for row in &teammate_rows {
+        for mate in teammates(row.game, row.participant) {
+            names
+                .entry(mate.puuid.clone())
+                .or_insert_with(|| format!("{}#{}", mate.riot_id_game_name, mate.riot_id_tagline));
+        }
+    }
+    let mut teammate_facets = count_by(
+        teammate_rows
+            .iter()
+            .flat_map(|row| teammates(row.game, row.participant).map(|mate| mate.puuid.clone())),
+        |puuid| names.get(puuid).cloned().unwrap_or_default(),
+    );
+    // 只保留一起玩过多局的玩家，路人只会出现一次
+    teammate_facets.retain(|facet| facet.count > 1);
+    teammate_facets.truncate(MAX_TEAMMATE_FACETS);
+
+    RecordFacets {
+        queues: count_by(
+            rows_without(MatchFilter {
+                queue_ids: vec![],
+                ..query.filter.clone()
+            })
+            .iter()
+            .map(|row| row.game.json.queue_id),
+            |_| String::new(),
+        ),
+        champions: count_by(
+            rows_without(MatchFilter {
+                champion_ids: vec![],
+                ..query.filter.clone()
+            })
+            .iter()
+            .map(|row| row.participant.champion_id),
+            |_| String::new(),
+        ),
+        positions: count_by(
+            rows_without(MatchFilter {
+                positions: vec![],
+                ..query.filter.clone()
+            })
+            .iter()
+            .filter_map(|row| row.role),
+            |_| String::new(),
+        ),
+        patches: count_by(
+            rows_without(MatchFilter {
+                patches: vec![],
+                ..query.filter.clone()
+            })
+            .iter()
+            .map(|row| patch_of(&row.game.json.game_version)),
+            |_| String::new(),
+        ),
+        results: count_by(
+            rows_without(MatchFilter {
+                win: None,
+                ..query.filter.clone()
+            })
+            .iter()
+            .map(|row| row.participant.win),
+            |_| String::new(),
+        ),
+        badges: {
+            let mut facets: HashMap<BadgeFilter, usize> = HashMap::new();
+            for row in rows
+                .iter()
+                .filter(|row| query.filter.matches(row.game, puuid))
+            {
+                *facets.entry(row.badge).or_default() += 1;
+            }
+            [BadgeFilter::Mvp, BadgeFilter::Ace, BadgeFilter::None]
+                .into_iter()
+                .filter_map(|badge| {
+                    facets.get(&badge).map(|count| FacetCount {
+                        value: badge,
+                        label: String::new(),
+                        count: *count,
+                    })
+                })
+                .collect()
+        },
+        teammates: teammate_facets,
+    }
+}
+
+/// 玩家在每局中的数据和排序键
+fn build_rows<'a>(
+    games: &'a [Arc<Games>],
+    query: &RecordQuery,
+    score_config: &ScoreConfig,
+) -> Vec<Row<'a>> {
+    games
+        .iter()
+        .filter_map(|game| {
+            let index = game
+                .json
+                .participants
+                .iter()
+                .position(|p| p.puuid == query.puuid)?;
+            let participant = &game.json.participants[index];
+            let badge = score_sgp_game(game, score_config)
+                .get(index)
+                .and_then(|result| result.badge);
+            Some(Row {
+                game,
+                participant,
+                role: resolve_roles(&game.json.participants)[index],
+                badge: BadgeFilter::of(badge),
+                key: sort_key(query.sort, game, participant),
+            })
+        })
+        .collect()
+}
+
+/// 满足筛选条件的对局，按 `query.sort` 排序
+fn matched_rows<'a, 'b>(rows: &'b [Row<'a>], query: &RecordQuery) -> Vec<&'b Row<'a>> {
+    let mut matched: Vec<&Row> = rows
+        .iter()
+        .filter(|row| query.filter.matches(row.game, &query.puuid))
+        .filter(|row| query.badge.is_none_or(|badge| badge == row.badge))
+        .collect();
+    matched.sort_by(|a, b| {
+        compare(
+            query.sort,
+            (a.key, &a.game.metadata.match_id),
+            (b.key, &b.game.metadata.match_id),
+        )
+    });
+    matched
+}
+
+/// 选出满足筛选条件的全部对局（不分页，忽略游标和每页数量）
+pub fn select_games(
+    games: &[Arc<Games>],
+    query: &RecordQuery,
+    score_config: &ScoreConfig,
+) -> Vec<Arc<Games>> {
+    let rows = build_rows(games, query, score_config);
+    matched_rows(&rows, query)
+        .into_iter()
+        .map(|row| Arc::clone(row.game))
+        .collect()
+}
+
+/// 查询玩家的本地对局
+///
+/// `games` 为玩家的全部本地对局（顺序不限），返回一页对局以及候选项统计。
+/// 候选项中的英雄名称为空，由调用方按需填充。
+pub fn query_games(
+    games: &[Arc<Games>],
+    query: &RecordQuery,
+    score_config: &ScoreConfig,
+) -> Result<QueryPage, StoreError> {
+    let cursor = query
+        .cursor
+        .as_deref()
+        .map(|cursor| Cursor::decode(cursor, query.sort))
+        .transpose()?;
+
+    let rows = build_rows(games, query, score_config);
+    let matched = matched_rows(&rows, query);
+
+    let start = match &cursor {
+        Some(cursor) => matched.partition_point(|row| {
+            compare(
+                query.sort,
+                (row.key, &row.game.metadata.match_id),
+                (cursor.key, &cursor.match_id),
+            ) != Ordering::Greater
+        }),
+        None => 0,
+    };
+    let limit = query.limit.unwrap_or(DEFAULT_PAGE_SIZE).max(1);
+    let page: Vec<&Row> = matched.iter().skip(start).take(limit).copied().collect();
+    let next_cursor = match page.last() {
+        Some(last) if start + page.len() < matched.len() => Some(
+            Cursor {
+                sort: query.sort,
+                key: last.key,
+                match_id: last.game.metadata.match_id.clone(),
+            }
+            .encode(),
+        ),
+        _ => None,
+    };
+
+    Ok(QueryPage {
+        games: page.iter().map(|row| Arc::clone(row.game)).collect(),
+        total: matched.len(),
+        next_cursor,
+        facets: compute_facets(&rows, query),
+    })
+}
@@ -0,0 +1,79 @@
+//! 段位记录存储
+//!
+//! 每个账号的段位快照按时间顺序保存在 `ranks/<puuid>.json` 中，与上一条快照相同的记录不会重复保存。
+
+use crate::shared::store::{get_store_dir, player_file_name, read_json, write_json};
+use crate::utils::error::store_error::StoreError;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tokio::sync::Mutex;
+
+/// 单个排位队列的段位快照
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RankSnapshot {
+    /// 毫秒
+    pub timestamp: i64,
+    /// 快照前刚结束的对局，启动时的快照为空
+    pub game_id: Option<i64>,
+    /// 如 RANKED_SOLO_5x5
+    pub queue_type: String,
+    pub tier: String,
+    pub division: String,
+    pub league_points: i64,
+    pub wins: i64,
+    pub losses: i64,
+}
+
+impl RankSnapshot {
+    /// 段位和胜负场相同视为同一状态
+    fn same_state(&self, other: &RankSnapshot) -> bool {
+        self.queue_type == other.queue_type
+            && self.tier == other.tier
+            && self.division == other.division
+            && self.league_points == other.league_points
+            && self.wins == other.wins
+            && self.losses == other.losses
+    }
+}
+
+/// 读-改-写需要串行
+static RANK_STORE_LOCK: Mutex<()> = Mutex::const_new(());
+
+async fn rank_path(puuid: &str) -> Result<PathBuf, StoreError> {
+    Ok(get_store_dir()
+        .await?
+        .join("ranks")
+        .join(player_file_name(puuid)?))
+}
+
+/// 读取账号的全部段位快照，按时间从旧到新
+pub async fn get_rank_snapshots(puuid: &str) -> Result<Vec<RankSnapshot>, StoreError> {
+    Ok(read_json(&rank_path(puuid).await?)?.unwrap_or_default())
+}
+
+/// 追加段位快照，返回实际保存的条数（跳过与该队列上一条相同的快照）
+pub async fn save_rank_snapshots(
+    puuid: &str,
+    snapshots: &[RankSnapshot],
+) -> Result<usize, StoreError> {
+    let _guard = RANK_STORE_LOCK.lock().await;
+    let path = rank_path(puuid).await?;
+    let mut stored: Vec<RankSnapshot> = read_json(&path)?.unwrap_or_default();
+    let mut saved = 0;
+    for snapshot in snapshots {
+        let last = stored
+            .iter()
+            .rev()
+            .find(|s| s.queue_type == snapshot.queue_type);
+        if last.is_some_and(|last| last.same_state(snapshot)) {
+            continue;
+        }
+        stored.push(snapshot.clone());
+        saved += 1;
+    }
+    if saved > 0 {
+        write_json(&path, &stored)?;
+    }
+    Ok(saved)
+}
@@ -0,0 +1,75 @@
+//! 就绪检查记录存储
+//!
+//! 每次就绪检查结束后追加一条记录到 `ready_checks.json`，按时间从旧到新，只保留最近的
+//! `MAX_READY_CHECKS` 条。
+
+use crate::shared::store::{get_store_dir, read_json, write_json};
+use crate::utils::error::store_error::StoreError;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tokio::sync::Mutex;
+
+/// 最多保留的记录条数
+pub const MAX_READY_CHECKS: usize = 200;
+
+/// 自动接受做出的响应
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ReadyCheckAction {
+    /// 延迟结束后自动接受
+    Accepted,
+    /// 暂时离开时自动拒绝
+    Declined,
+    /// 在延迟期间被取消
+    Cancelled,
+    /// 自动接受失败，错误信息见 `error`
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadyCheckRecord {
+    /// 毫秒
+    pub started_at: i64,
+    pub ended_at: i64,
+    /// 没有开启自动接受时为空
+    pub action: Option<ReadyCheckAction>,
+    /// 自己最终的响应：Accepted / Declined / None
+    pub player_response: String,
+    /// 就绪检查的结果：EveryoneReady / StrangerNotReady / PartyNotReady 等，
+    /// 事件丢失时为最后一次看到的状态
+    pub state: String,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// 追加一条记录，超过上限时丢弃最旧的
+pub fn push_ready_check(records: &mut Vec<ReadyCheckRecord>, record: ReadyCheckRecord) {
+    records.push(record);
+    if records.len() > MAX_READY_CHECKS {
+        let excess = records.len() - MAX_READY_CHECKS;
+        records.drain(..excess);
+    }
+}
+
+/// 读-改-写需要串行
+static READY_CHECK_STORE_LOCK: Mutex<()> = Mutex::const_new(());
+
+async fn ready_checks_path() -> Result<PathBuf, StoreError> {
+    Ok(get_store_dir().await?.join("ready_checks.json"))
+}
+
+/// 读取最近的就绪检查记录，按时间从新到旧
+pub async fn get_ready_checks(limit: usize) -> Result<Vec<ReadyCheckRecord>, StoreError> {
+    let records: Vec<ReadyCheckRecord> =
+        read_json(&ready_checks_path().await?)?.unwrap_or_default();
+    Ok(records.into_iter().rev().take(limit).collect())
+}
+
+pub async fn save_ready_check(record: ReadyCheckRecord) -> Result<(), StoreError> {
+    let _guard = READY_CHECK_STORE_LOCK.lock().await;
+    let path = ready_checks_path().await?;
+    let mut records: Vec<ReadyCheckRecord> = read_json(&path)?.unwrap_or_default();
+    push_ready_check(&mut records, record);
+    write_json(&path, &records)
+}
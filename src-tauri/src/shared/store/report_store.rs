@@ -0,0 +1,23 @@
+//! 赛后报告存储
+//!
+//! 每局的赛后报告保存在 `reports/<match_id>.json` 中。
+
+use crate::shared::analysis::post_game::PostGameReport;
+use crate::shared::store::{get_store_dir, match_file_name, read_json, write_json};
+use crate::utils::error::store_error::StoreError;
+use std::path::PathBuf;
+
+async fn report_path(match_id: &str) -> Result<PathBuf, StoreError> {
+    Ok(get_store_dir()
+        .await?
+        .join("reports")
+        .join(match_file_name(match_id)?))
+}
+
+pub async fn get_stored_report(match_id: &str) -> Result<Option<PostGameReport>, StoreError> {
+    read_json(&report_path(match_id).await?)
+}
+
+pub async fn save_report(report: &PostGameReport) -> Result<(), StoreError> {
+    write_json(&report_path(&report.match_id).await?, report)
+}
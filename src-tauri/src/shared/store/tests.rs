@@ -0,0 +1,446 @@
+use crate::shared::analysis::role::{Role, SMITE_SPELL_ID};
+use crate::shared::fixture::GameBuilder;
+use crate::shared::store::export::{export_games, ExportFormat};
+use crate::shared::store::filter::MatchFilter;
+use crate::shared::store::match_store::MatchStore;
+use crate::shared::store::note_store::{NoteBook, PlayerNoteInput};
+use crate::shared::store::query::{query_games, select_games, RecordQuery, RecordSort};
+use crate::shared::store::ready_check_store::{
+    push_ready_check, ReadyCheckAction, ReadyCheckRecord, MAX_READY_CHECKS,
+};
+use crate::shared::store::{match_file_name, player_file_name};
+use crate::shared::types::sgp::history::Games;
+use crate::utils::config::score::ScoreConfig;
+use crate::utils::error::store_error::StoreError;
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[test]
+fn test_match_store_persists_and_indexes_games() {
+    let dir = std::env::temp_dir().join(format!("match_store_test_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let mut store = MatchStore::load(dir.clone()).unwrap();
+    let g1 = GameBuilder::new("HN1_1")
+        .created(1000)
+        .players(&[("a", 100), ("b", 100)])
+        .build();
+    store.insert(&g1).unwrap();
+    store
+        .insert(
+            &GameBuilder::new("HN1_2")
+                .created(2000)
+                .player("a", 100)
+                .build(),
+        )
+        .unwrap();
+    // 重复保存不会产生重复索引
+    store.insert(&g1).unwrap();
+    // match_id 无效的对局不会写入
+    assert!(matches!(
+        store.insert(&GameBuilder::new("../G3").player("a", 100).build()),
+        Err(StoreError::InvalidMatchId(_))
+    ));
+
+    let reloaded = MatchStore::load(dir.clone()).unwrap();
+    assert_eq!(reloaded.len(), 2);
+    let ids: Vec<String> = reloaded
+        .player_games("a")
+        .iter()
+        .map(|game| game.metadata.match_id.clone())
+        .collect();
+    assert_eq!(ids, vec!["HN1_2", "HN1_1"]);
+    assert_eq!(reloaded.player_games("b").len(), 1);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+/// 玩家 "me" 在蓝方，`mates` 为同队玩家，红方固定一名 "enemy"
+fn fixture_query_games() -> Vec<Arc<Games>> {
+    [
+        ("G1", 1000, 420, 103, true, &["friend"][..]),
+        ("G2", 2000, 420, 222, false, &[]),
+        ("G3", 3000, 440, 103, true, &["friend"]),
+        ("G4", 4000, 420, 103, false, &["friend"]),
+        ("G5", 5000, 420, 103, true, &[]),
+    ]
+    .into_iter()
+    .map(
+        |(match_id, game_creation, queue_id, champion_id, win, mates)| {
+            let builder = GameBuilder::new(match_id)
+                .created(game_creation)
+                .queue(queue_id)
+                .version("15.1.650.1234")
+                .duration(1800)
+                .player("me", 100)
+                .champion(champion_id);
+            mates
+                .iter()
+                .fold(builder, |builder, mate| builder.player(mate, 100))
+                .player("enemy", 200)
+                .winner(if win { 100 } else { 200 })
+                .arc()
+        },
+    )
+    .collect()
+}
+
+fn match_ids(games: &[Arc<Games>]) -> Vec<String> {
+    games
+        .iter()
+        .map(|game| game.metadata.match_id.clone())
+        .collect()
+}
+
+#[test]
+fn test_query_paginates_with_cursor() {
+    let games = fixture_query_games();
+    let config = ScoreConfig::default();
+    let mut query = RecordQuery {
+        puuid: "me".to_string(),
+        limit: Some(2),
+        ..Default::default()
+    };
+
+    let mut pages = vec![];
+    loop {
+        let page = query_games(&games, &query, &config).unwrap();
+        assert_eq!(page.total, 5);
+        pages.extend(match_ids(&page.games));
+        match page.next_cursor {
+            Some(cursor) => query.cursor = Some(cursor),
+            None => break,
+        }
+    }
+    assert_eq!(pages, vec!["G5", "G4", "G3", "G2", "G1"]);
+
+    // 排序方式改变后旧游标失效
+    query.sort = RecordSort::Oldest;
+    query.cursor = query_games(
+        &games,
+        &RecordQuery {
+            puuid: "me".to_string(),
+            limit: Some(1),
+            ..Default::default()
+        },
+        &config,
+    )
+    .unwrap()
+    .next_cursor;
+    assert!(matches!(
+        query_games(&games, &query, &config),
+        Err(StoreError::InvalidCursor)
+    ));
+}
+
+#[test]
+fn test_query_filters_and_facets() {
+    let games = fixture_query_games();
+    let query = RecordQuery {
+        puuid: "me".to_string(),
+        filter: MatchFilter {
+            champion_ids: vec![103],
+            win: Some(true),
+            with_puuids: vec!["friend".to_string()],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let page = query_games(&games, &query, &ScoreConfig::default()).unwrap();
+    assert_eq!(match_ids(&page.games), vec!["G3", "G1"]);
+
+    // 英雄候选项忽略英雄筛选：G1 / G3 都是 103，G2 输了所以不在其中
+    let champions: Vec<(i64, usize)> = page
+        .facets
+        .champions
+        .iter()
+        .map(|facet| (facet.value, facet.count))
+        .collect();
+    assert_eq!(champions, vec![(103, 2)]);
+    // 胜负候选项忽略胜负筛选：G1 / G3 / G4
+    let results: Vec<(bool, usize)> = page
+        .facets
+        .results
+        .iter()
+        .map(|facet| (facet.value, facet.count))
+        .collect();
+    assert_eq!(results, vec![(true, 2), (false, 1)]);
+    // 队友候选项忽略队友筛选：103 的胜局 G1 / G3 / G5 中 friend 出现两次
+    assert_eq!(page.facets.teammates[0].value, "friend");
+    assert_eq!(page.facets.teammates[0].count, 2);
+    assert_eq!(page.facets.patches[0].value, "15.1");
+
+    let query = RecordQuery {
+        puuid: "me".to_string(),
+        filter: MatchFilter {
+            without_puuids: vec!["friend".to_string()],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let page = query_games(&games, &query, &ScoreConfig::default()).unwrap();
+    assert_eq!(match_ids(&page.games), vec!["G5", "G2"]);
+}
+
+#[test]
+fn test_query_filters_and_facets_use_resolved_role() {
+    let games = vec![
+        GameBuilder::lobby("HN1_1").created(1000).arc(),
+        // 没有 teamPosition，p1 携带惩戒判定为打野
+        GameBuilder::lobby("HN1_2")
+            .created(2000)
+            .each(|p| p.team_position.clear())
+            .edit("p1", |p| p.spell1id = SMITE_SPELL_ID)
+            .arc(),
+        // 没有任何位置信息
+        GameBuilder::lobby("HN1_3")
+            .created(3000)
+            .each(|p| p.team_position.clear())
+            .arc(),
+    ];
+    let query = |positions: &[&str]| RecordQuery {
+        puuid: "p1".to_string(),
+        filter: MatchFilter {
+            positions: positions.iter().map(|p| p.to_string()).collect(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let config = ScoreConfig::default();
+
+    let page = query_games(&games, &query(&["JUNGLE"]), &config).unwrap();
+    assert_eq!(match_ids(&page.games), vec!["HN1_2"]);
+    let page = query_games(&games, &query(&["top", "MIDDLE"]), &config).unwrap();
+    assert_eq!(match_ids(&page.games), vec!["HN1_1"]);
+
+    let positions: Vec<(Role, usize)> = page
+        .facets
+        .positions
+        .iter()
+        .map(|facet| (facet.value, facet.count))
+        .collect();
+    assert_eq!(positions, vec![(Role::Top, 1), (Role::Jungle, 1)]);
+}
+
+#[test]
+fn test_export_games_writes_one_row_per_participant() {
+    let games = fixture_query_games();
+    let query = RecordQuery {
+        puuid: "me".to_string(),
+        filter: MatchFilter {
+            champion_ids: vec![103],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let selected = select_games(&games, &query, &ScoreConfig::default());
+    assert_eq!(match_ids(&selected), vec!["G5", "G4", "G3", "G1"]);
+
+    let dir = std::env::temp_dir().join(format!("export_test_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let names = HashMap::from([(103, "阿卡丽".to_string())]);
+
+    let path = dir.join("records.csv");
+    let summary = export_games(&selected, "me", false, ExportFormat::Csv, &path, &names).unwrap();
+    assert_eq!((summary.games, summary.rows), (4, 4));
+    let csv = std::fs::read_to_string(&path).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 5);
+    assert!(lines[0].starts_with("match_id,game_creation,queue_id,patch"));
+    assert!(lines[1].starts_with("G5,5000,420,15.1,1800,me,"));
+    assert!(lines[1].contains("阿卡丽"));
+
+    // 导出全部玩家：G5 两人，G4 / G3 / G1 各三人
+    let path = dir.join("records.jsonl");
+    let summary = export_games(&selected, "me", true, ExportFormat::Jsonl, &path, &names).unwrap();
+    assert_eq!(summary.rows, 11);
+    let jsonl = std::fs::read_to_string(&path).unwrap();
+    let first: serde_json::Value = serde_json::from_str(jsonl.lines().next().unwrap()).unwrap();
+    assert_eq!(first["match_id"], "G5");
+    assert_eq!(first["win"], true);
+    assert_eq!(jsonl.lines().count(), 11);
+
+    let path = dir.join("records.parquet");
+    export_games(&selected, "me", true, ExportFormat::Parquet, &path, &names).unwrap();
+    let reader = SerializedFileReader::new(std::fs::File::open(&path).unwrap()).unwrap();
+    let metadata = reader.metadata().file_metadata();
+    assert_eq!(metadata.num_rows(), 11);
+    assert_eq!(metadata.schema_descr().num_columns(), 23);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_note_book_upsert_keeps_created_at_and_normalizes_tags() {
+    let mut book = NoteBook::default();
+    let note = book
+        .upsert(
+            PlayerNoteInput {
+                puuid: "a".to_string(),
+                game_name: "Alice".to_string(),
+                tag_line: "CN1".to_string(),
+                note: " great jungler ".to_string(),
+                tags: vec![" jungle ".to_string(), "jungle".to_string(), "".to_string()],
+                rating: Some(5),
+                ..Default::default()
+            },
+            1000,
+        )
+        .unwrap();
+    assert_eq!(note.note, "great jungler");
+    assert_eq!(note.tags, vec!["jungle"]);
+    assert_eq!((note.created_at, note.updated_at), (1000, 1000));
+
+    // 覆盖时保留创建时间，名称为空时沿用之前的名称
+    let note = book
+        .upsert(
+            PlayerNoteInput {
+                puuid: "a".to_string(),
+                note: "flamer".to_string(),
+                tags: vec!["tilt".to_string()],
+                ..Default::default()
+            },
+            2000,
+        )
+        .unwrap();
+    assert_eq!(note.game_name, "Alice");
+    assert_eq!(note.rating, None);
+    assert_eq!((note.created_at, note.updated_at), (1000, 2000));
+
+    book.upsert(
+        PlayerNoteInput {
+            puuid: "b".to_string(),
+            tags: vec!["duo".to_string()],
+            ..Default::default()
+        },
+        1500,
+    )
+    .unwrap();
+    let puuids: Vec<String> = book
+        .list(None, false)
+        .into_iter()
+        .map(|note| note.puuid)
+        .collect();
+    assert_eq!(puuids, vec!["a", "b"]);
+    assert_eq!(book.list(Some("duo"), false).len(), 1);
+
+    let invalid = book.upsert(
+        PlayerNoteInput {
+            puuid: "c".to_string(),
+            rating: Some(6),
+            ..Default::default()
+        },
+        3000,
+    );
+    assert!(matches!(invalid, Err(StoreError::InvalidNote(_))));
+    assert!(book.get("c").is_none());
+
+    // 序列化为 puuid -> 备注的对象
+    let json = serde_json::to_value(&book).unwrap();
+    assert_eq!(json["b"]["tags"][0], "duo");
+    assert!(book.remove("b"));
+    assert!(!book.remove("b"));
+}
+
+#[test]
+fn test_note_book_avoid_flag_survives_note_edits() {
+    let mut book = NoteBook::default();
+    // 没有备注的玩家加入回避名单时新建备注
+    let note = book.set_avoid("a", true, 1000).unwrap();
+    assert!(note.avoid);
+    assert!(note.note.is_empty());
+
+    // 修改备注时不传回避状态则保持不变
+    book.upsert(
+        PlayerNoteInput {
+            puuid: "a".to_string(),
+            note: "flamer".to_string(),
+            ..Default::default()
+        },
+        2000,
+    )
+    .unwrap();
+    assert_eq!(book.avoided("a").unwrap().note, "flamer");
+    assert_eq!(book.list(None, true).len(), 1);
+
+    let note = book.set_avoid("a", false, 3000).unwrap();
+    assert_eq!(note.note, "flamer");
+    assert_eq!(note.created_at, 1000);
+    assert!(book.avoided("a").is_none());
+    assert!(book.list(None, true).is_empty());
+}
+
+#[test]
+fn test_ready_check_log_keeps_latest_records() {
+    let mut records = vec![];
+    for i in 0..MAX_READY_CHECKS as i64 + 3 {
+        push_ready_check(
+            &mut records,
+            ReadyCheckRecord {
+                started_at: i,
+                ended_at: i + 10,
+                action: Some(ReadyCheckAction::Accepted),
+                player_response: "Accepted".to_string(),
+                state: "EveryoneReady".to_string(),
+                error: None,
+            },
+        );
+    }
+    assert_eq!(records.len(), MAX_READY_CHECKS);
+    // 最旧的三条被丢弃
+    assert_eq!(records.first().unwrap().started_at, 3);
+    assert_eq!(
+        records.last().unwrap().started_at,
+        MAX_READY_CHECKS as i64 + 2
+    );
+
+    // 旧版本的记录没有 error 字段
+    let record: ReadyCheckRecord = serde_json::from_str(
+        r#"{"startedAt":1,"endedAt":2,"action":"cancelled","playerResponse":"None","state":"StrangerNotReady"}"#,
+    )
+    .unwrap();
+    assert_eq!(record.action, Some(ReadyCheckAction::Cancelled));
+    assert_eq!(record.error, None);
+}
+
+#[test]
+fn test_match_file_name_rejects_paths() {
+    assert_eq!(
+        match_file_name("HN1_1234567890").unwrap(),
+        "HN1_1234567890.json"
+    );
+    for match_id in [
+        "../x",
+        "HN1_12/../../x",
+        "HN1_",
+        "_123",
+        "hn1_123",
+        "HN1_12a",
+        "",
+    ] {
+        assert!(
+            matches!(
+                match_file_name(match_id),
+                Err(StoreError::InvalidMatchId(_))
+            ),
+            "{}",
+            match_id
+        );
+    }
+}
+
+#[test]
+fn test_player_file_name_rejects_paths() {
+    assert_eq!(
+        player_file_name("1a2b3c4d-5e6f-7890-abcd-ef0123456789").unwrap(),
+        "1a2b3c4d-5e6f-7890-abcd-ef0123456789.json"
+    );
+    for puuid in ["../../x", "a/b", "a\\b", "a.json", "a_b", ""] {
+        assert!(
+            matches!(player_file_name(puuid), Err(StoreError::InvalidPuuid(_))),
+            "{}",
+            puuid
+        );
+    }
+}
@@ -23,7 +23,8 @@ pub struct ReadyCheck {
     pub player_response: String,
     pub state: String,
     pub suppress_ux: bool,
-    pub timer: i32,
+    /// 已经经过的秒数，客户端返回的是小数
+    pub timer: f64,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
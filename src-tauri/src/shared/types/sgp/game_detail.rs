@@ -135,6 +135,29 @@ pub struct ParticipantFrames {
     pub n9: N1,
 }
 
+impl ParticipantFrames {
+    /// 按 participant_id（1~10）获取玩家的帧数据
+    pub fn get(&self, participant_id: i64) -> Option<&N1> {
+        match participant_id {
+            1 => Some(&self.n1),
+            2 => Some(&self.n2),
+            3 => Some(&self.n3),
+            4 => Some(&self.n4),
+            5 => Some(&self.n5),
+            6 => Some(&self.n6),
+            7 => Some(&self.n7),
+            8 => Some(&self.n8),
+            9 => Some(&self.n9),
+            10 => Some(&self.n10),
+            _ => None,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &N1> {
+        (1..=10).filter_map(|participant_id| self.get(participant_id))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct N1 {
@@ -53,11 +53,20 @@ pub struct TeamOne {
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TeamTwo {
+    /// 二队的玩家信息在部分服务器上不返回，缺省为空
+    #[serde(default)]
+    pub puuid: String,
+    #[serde(default)]
+    pub summoner_id: i64,
     pub last_selected_skin_index: i64,
     pub team_owner: bool,
     pub profile_icon_id: i64,
     pub team_participant_id: i64,
     pub champion_id: i64,
+    #[serde(default)]
+    pub selected_role: String,
+    #[serde(default)]
+    pub selected_position: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
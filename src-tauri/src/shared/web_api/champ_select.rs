@@ -0,0 +1,102 @@
+use crate::shared::analysis::premade::{PartyTag, PremadeCandidate};
+use crate::shared::init::lcu::get_lcu_client;
+use crate::shared::store::note_store::{load_notes_for_display, PlayerNote};
+use crate::shared::types::league_client::champ_select::{ChampSelectSession, ChampSelectTeam};
+use crate::shared::web_api::premade::detect_game_premades;
+use serde::{Deserialize, Serialize};
+
+/// 英雄选择阶段的玩家
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChampSelectPlayer {
+    /// 对方隐藏信息时为空
+    pub puuid: String,
+    pub cell_id: i32,
+    /// 1 或 2
+    pub team: i32,
+    pub is_ally: bool,
+    pub is_self: bool,
+    pub assigned_position: String,
+    pub champion_id: i32,
+    /// 车队信息，没有检测或不是开黑时为空
+    pub premade: Option<PartyTag>,
+    /// 自己保存的备注
+    pub note: Option<PlayerNote>,
+}
+
+impl ChampSelectPlayer {
+    fn new(member: &ChampSelectTeam, is_ally: bool, local_cell_id: i32) -> Self {
+        Self {
+            puuid: member.puuid.clone(),
+            cell_id: member.cell_id,
+            team: member.team,
+            is_ally,
+            is_self: is_ally && member.cell_id == local_cell_id,
+            assigned_position: member.assigned_position.clone(),
+            champion_id: member.champion_id,
+            premade: None,
+            note: None,
+        }
+    }
+}
+
+pub fn parse_players(session: &ChampSelectSession) -> Vec<ChampSelectPlayer> {
+    let local_cell_id = session.base.local_player_cell_id;
+    session
+        .base
+        .my_team
+        .iter()
+        .map(|member| ChampSelectPlayer::new(member, true, local_cell_id))
+        .chain(
+            session
+                .base
+                .their_team
+                .iter()
+                .map(|member| ChampSelectPlayer::new(member, false, local_cell_id)),
+        )
+        .collect()
+}
+
+/// 检测开黑并把车队信息附加到玩家上
+pub async fn attach_premades(players: &mut [ChampSelectPlayer]) {
+    let candidates: Vec<PremadeCandidate> = players
+        .iter()
+        .map(|player| PremadeCandidate {
+            puuid: player.puuid.clone(),
+            team_id: player.team as i64,
+        })
+        .collect();
+    let report = detect_game_premades(&candidates).await;
+    for player in players.iter_mut() {
+        player.premade = report.tag_of(&player.puuid);
+    }
+}
+
+/// 把保存的备注附加到玩家上
+pub async fn attach_notes(players: &mut [ChampSelectPlayer]) {
+    let notes = load_notes_for_display().await;
+    for player in players.iter_mut() {
+        player.note = notes.get(&player.puuid).cloned();
+    }
+}
+
+/// 获取英雄选择阶段的所有可见玩家
+///
+/// # 参数
+/// - `detect_premade`: 是否检测开黑，需要拉取所有玩家的战绩，比较慢
+pub async fn get_champ_select_players(
+    detect_premade: bool,
+) -> Result<Vec<ChampSelectPlayer>, String> {
+    let client = get_lcu_client().await.map_err(|e| e.to_string())?;
+    let session = client
+        .champ_select
+        .get_session()
+        .await
+        .map_err(|e| e.to_string())?;
+    let mut players = parse_players(&session);
+    attach_notes(&mut players).await;
+    if detect_premade {
+        attach_premades(&mut players).await;
+    }
+    Ok(players)
+}
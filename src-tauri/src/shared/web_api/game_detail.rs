@@ -0,0 +1,65 @@
+use crate::shared::http_api::sgp::SgpService;
+use crate::shared::init::sgp::get_sgp_client_for;
+use crate::shared::store::detail_store::{get_stored_detail, save_detail};
+use crate::shared::types::sgp::game_detail::SgpGameDetailsLol;
+use futures::stream::{self, StreamExt};
+use tracing::warn;
+
+/// 同时请求对局详情的数量
+const DETAIL_CONCURRENCY: usize = 4;
+
+/// 从 match_id（如 "HN1_1234567890"）中取出 game_id
+pub fn game_id_of(match_id: &str) -> Option<i64> {
+    match_id.rsplit('_').next()?.parse().ok()
+}
+
+/// 从 match_id 中取出平台 ID（如 "HN1"）
+pub fn platform_of(match_id: &str) -> Option<&str> {
+    match_id.rsplit_once('_').map(|(platform, _)| platform)
+}
+
+/// 获取对局详情，优先使用本地保存的数据
+///
+/// 其他服务器的对局从对局所在的服务器获取。
+pub async fn get_game_detail(match_id: &str) -> Result<SgpGameDetailsLol, String> {
+    match get_stored_detail(match_id).await {
+        Ok(Some(detail)) => return Ok(detail),
+        Ok(None) => {}
+        Err(e) => warn!("读取本地对局详情失败: {}", e),
+    }
+
+    let game_id = game_id_of(match_id).ok_or(format!("无效的对局 ID: {}", match_id))?;
+    let client = get_sgp_client_for(platform_of(match_id), SgpService::MatchHistory)
+        .await
+        .map_err(|e| e.to_string())?;
+    let detail = client
+        .get_game_detail(game_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    if let Err(e) = save_detail(&detail).await {
+        warn!("保存对局详情失败: {}", e);
+    }
+    Ok(detail)
+}
+
+/// 批量获取对局详情，失败的对局会被跳过
+///
+/// # 参数
+/// - `match_ids`: 对局 ID 列表
+/// - `fetch_missing`: 本地没有时是否从服务器拉取，为 false 时只返回本地已有的详情
+pub async fn get_game_details(match_ids: &[String], fetch_missing: bool) -> Vec<SgpGameDetailsLol> {
+    stream::iter(match_ids)
+        .map(|match_id| async move {
+            if fetch_missing {
+                return get_game_detail(match_id)
+                    .await
+                    .inspect_err(|e| warn!("获取对局详情 {} 失败: {}", match_id, e))
+                    .ok();
+            }
+            get_stored_detail(match_id).await.ok().flatten()
+        })
+        .buffered(DETAIL_CONCURRENCY)
+        .filter_map(|detail| async move { detail })
+        .collect()
+        .await
+}
@@ -0,0 +1,41 @@
+use crate::shared::analysis::head_to_head::{head_to_head, HeadToHead};
+use crate::shared::store::match_store::get_player_games;
+use crate::shared::web_api::record_sgp::sync_match_history;
+use tracing::warn;
+
+/// 每次从服务器拉取的局数
+const BACKFILL_PAGE_SIZE: usize = 50;
+
+/// 查询两名玩家的交手记录
+///
+/// 本地对局按参与的每名玩家建立了索引，因此拉取第二名玩家的战绩后，
+/// 其中包含第一名玩家的对局也能在第一名玩家的本地对局中查到。
+///
+/// # 参数
+/// - `backfill`: 先从服务器拉取第二名玩家最近的多少局，为 0 时只使用本地数据
+pub async fn get_head_to_head(
+    puuid: &str,
+    other_puuid: &str,
+    backfill: usize,
+) -> Result<HeadToHead, String> {
+    let mut synced = 0;
+    while synced < backfill {
+        let count = BACKFILL_PAGE_SIZE.min(backfill - synced);
+        match sync_match_history(other_puuid, synced as i32, count as i32).await {
+            Ok(fetched) => {
+                synced += fetched;
+                // 服务器上已经没有更早的对局
+                if fetched < count {
+                    break;
+                }
+            }
+            Err(e) => {
+                warn!("拉取 {} 的战绩失败，使用本地数据: {}", other_puuid, e);
+                break;
+            }
+        }
+    }
+
+    let games = get_player_games(puuid).await.map_err(|e| e.to_string())?;
+    Ok(head_to_head(&games, puuid, other_puuid))
+}
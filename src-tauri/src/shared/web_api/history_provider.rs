@@ -0,0 +1,450 @@
+//! 战绩来源
+//!
+//! SGP 和 LCU 都能拉取战绩，两者的结果统一转换成 SGP 的 `Games`，这样本地存储和所有分析都不用区分来源。
+//! SGP 一次就能拿到整局的数据，LCU 的战绩列表只包含玩家自己，需要再逐局获取详情。
+
+use crate::shared::http_api::sgp::SgpService;
+use crate::shared::init::lcu::get_lcu_client;
+use crate::shared::init::sgp::get_sgp_client_for;
+use crate::shared::types::league_client::match_history::{
+    Game as LcuGame, Participant as LcuParticipant, Player as LcuPlayer, Stats as LcuStats,
+    Team as LcuTeam,
+};
+use crate::shared::types::sgp::history::{
+    Atakhan, Ban, Games, Json, Metadata, Objectives, Participant, Perks, Selection, Style, Team,
+};
+use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// LCU 同时请求对局详情的数量
+const LCU_DETAIL_CONCURRENCY: usize = 4;
+
+/// 战绩的实际来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HistorySource {
+    Sgp,
+    Lcu,
+}
+
+#[async_trait]
+pub trait MatchHistoryProvider: Send + Sync {
+    fn source(&self) -> HistorySource;
+
+    /// 拉取玩家第 `beg_index` 局开始的 `count` 局，从新到旧
+    async fn fetch_games(
+        &self,
+        puuid: &str,
+        beg_index: i32,
+        count: i32,
+    ) -> Result<Vec<Games>, String>;
+}
+
+/// 通过 SGP 拉取，可以跨区查询
+pub struct SgpHistoryProvider {
+    /// 服务器 ID 或平台 ID，为空时使用当前服务器
+    pub server: Option<String>,
+}
+
+#[async_trait]
+impl MatchHistoryProvider for SgpHistoryProvider {
+    fn source(&self) -> HistorySource {
+        HistorySource::Sgp
+    }
+
+    async fn fetch_games(
+        &self,
+        puuid: &str,
+        beg_index: i32,
+        count: i32,
+    ) -> Result<Vec<Games>, String> {
+        let client = get_sgp_client_for(self.server.as_deref(), SgpService::MatchHistory)
+            .await
+            .map_err(|e| e.to_string())?;
+        let match_history = client
+            .get_match_history(puuid, beg_index, count)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(match_history.games)
+    }
+}
+
+/// 通过客户端的战绩接口拉取，只能查询当前服务器
+pub struct LcuHistoryProvider;
+
+#[async_trait]
+impl MatchHistoryProvider for LcuHistoryProvider {
+    fn source(&self) -> HistorySource {
+        HistorySource::Lcu
+    }
+
+    async fn fetch_games(
+        &self,
+        puuid: &str,
+        beg_index: i32,
+        count: i32,
+    ) -> Result<Vec<Games>, String> {
+        if count <= 0 {
+            return Ok(vec![]);
+        }
+        let client = get_lcu_client().await.map_err(|e| e.to_string())?;
+        // endIndex 包含在内
+        let match_history = client
+            .match_history
+            .get_match_history(puuid, Some(beg_index), Some(beg_index + count - 1))
+            .await
+            .map_err(|e| e.to_string())?;
+
+        // 单局详情失败时跳过，不影响同一页的其他对局
+        let game_ids: Vec<i64> = match_history
+            .games
+            .games
+            .iter()
+            .map(|game| game.game_id)
+            .collect();
+        let details: Vec<Option<LcuGame>> = stream::iter(game_ids.clone())
+            .map(|game_id| {
+                let api = client.match_history.clone();
+                async move {
+                    api.get_game(game_id)
+                        .await
+                        .inspect_err(|e| warn!("获取 LCU 对局详情 {} 失败: {}", game_id, e))
+                        .ok()
+                }
+            })
+            .buffered(LCU_DETAIL_CONCURRENCY)
+            .collect()
+            .await;
+        let games: Vec<Games> = details.iter().flatten().map(game_from_lcu).collect();
+        if games.is_empty() && !game_ids.is_empty() {
+            return Err("获取 LCU 对局详情全部失败".to_string());
+        }
+        Ok(games)
+    }
+}
+
+/// 按顺序尝试多个来源，前一个失败时使用下一个
+pub struct CompositeHistoryProvider {
+    providers: Vec<Box<dyn MatchHistoryProvider>>,
+}
+
+impl CompositeHistoryProvider {
+    pub fn new(providers: Vec<Box<dyn MatchHistoryProvider>>) -> Self {
+        Self { providers }
+    }
+
+    /// 优先使用 SGP，失败时回退到 LCU
+    ///
+    /// LCU 只能查询当前服务器，查询其他服务器时不会回退。
+    pub fn sgp_then_lcu(server: Option<String>, is_current_server: bool) -> Self {
+        let mut providers: Vec<Box<dyn MatchHistoryProvider>> =
+            vec![Box::new(SgpHistoryProvider { server })];
+        if is_current_server {
+            providers.push(Box::new(LcuHistoryProvider));
+        }
+        Self::new(providers)
+    }
+
+    /// 拉取战绩，同时返回实际使用的来源，全部失败时返回每个来源的错误
+    pub async fn fetch_games(
+        &self,
+        puuid: &str,
+        beg_index: i32,
+        count: i32,
+    ) -> Result<(Vec<Games>, HistorySource), String> {
+        let mut errors = vec![];
+        for provider in &self.providers {
+            match provider.fetch_games(puuid, beg_index, count).await {
+                Ok(games) => return Ok((games, provider.source())),
+                Err(e) => {
+                    warn!("从 {:?} 拉取战绩失败: {}", provider.source(), e);
+                    errors.push(format!("{:?}: {}", provider.source(), e));
+                }
+            }
+        }
+        Err(format!("拉取战绩失败: {}", errors.join("; ")))
+    }
+}
+
+fn perks_from_lcu(stats: &LcuStats) -> Perks {
+    let selection = |perk: i32, var1: i32, var2: i32, var3: i32| Selection {
+        perk: perk as i64,
+        var1: var1 as i64,
+        var2: var2 as i64,
+        var3: var3 as i64,
+    };
+    // 斗魂竞技场等模式没有符文
+    if stats.perk_primary_style == 0 {
+        return Perks::default();
+    }
+    Perks {
+        styles: vec![
+            Style {
+                description: "primaryStyle".to_string(),
+                style: stats.perk_primary_style as i64,
+                selections: vec![
+                    selection(
+                        stats.perk0,
+                        stats.perk0_var1,
+                        stats.perk0_var2,
+                        stats.perk0_var3,
+                    ),
+                    selection(
+                        stats.perk1,
+                        stats.perk1_var1,
+                        stats.perk1_var2,
+                        stats.perk1_var3,
+                    ),
+                    selection(
+                        stats.perk2,
+                        stats.perk2_var1,
+                        stats.perk2_var2,
+                        stats.perk2_var3,
+                    ),
+                    selection(
+                        stats.perk3,
+                        stats.perk3_var1,
+                        stats.perk3_var2,
+                        stats.perk3_var3,
+                    ),
+                ],
+            },
+            Style {
+                description: "subStyle".to_string(),
+                style: stats.perk_sub_style as i64,
+                selections: vec![
+                    selection(
+                        stats.perk4,
+                        stats.perk4_var1,
+                        stats.perk4_var2,
+                        stats.perk4_var3,
+                    ),
+                    selection(
+                        stats.perk5,
+                        stats.perk5_var1,
+                        stats.perk5_var2,
+                        stats.perk5_var3,
+                    ),
+                ],
+            },
+        ],
+        ..Default::default()
+    }
+}
+
+/// LCU 的位置记录在 timeline 中，辅助记为 BOTTOM + DUO_SUPPORT
+fn position_from_lcu(participant: &LcuParticipant) -> String {
+    if let Some(position) = &participant.stats.individual_position {
+        return position.clone();
+    }
+    let timeline = &participant.timeline;
+    match (timeline.lane.as_str(), timeline.role.as_str()) {
+        ("BOTTOM", "DUO_SUPPORT") => "UTILITY".to_string(),
+        ("NONE", _) | ("", _) => "Invalid".to_string(),
+        (lane, _) => lane.to_string(),
+    }
+}
+
+fn participant_from_lcu(participant: &LcuParticipant, player: &LcuPlayer) -> Participant {
+    let stats = &participant.stats;
+    let individual_position = position_from_lcu(participant);
+    Participant {
+        puuid: player.puuid.clone(),
+        riot_id_game_name: player.game_name.clone(),
+        riot_id_tagline: player.tag_line.clone(),
+        summoner_name: player.summoner_name.clone(),
+        summoner_id: player.summoner_id,
+        profile_icon: player.profile_icon as i64,
+        participant_id: participant.participant_id as i64,
+        team_id: participant.team_id as i64,
+        champion_id: participant.champion_id as i64,
+        spell1id: participant.spell1_id as i64,
+        spell2id: participant.spell2_id as i64,
+        champ_level: stats.champ_level as i64,
+        kills: stats.kills as i64,
+        deaths: stats.deaths as i64,
+        assists: stats.assists as i64,
+        double_kills: stats.double_kills as i64,
+        triple_kills: stats.triple_kills as i64,
+        quadra_kills: stats.quadra_kills as i64,
+        penta_kills: stats.penta_kills as i64,
+        unreal_kills: stats.unreal_kills as i64,
+        killing_sprees: stats.killing_sprees as i64,
+        largest_killing_spree: stats.largest_killing_spree as i64,
+        largest_multi_kill: stats.largest_multi_kill as i64,
+        largest_critical_strike: stats.largest_critical_strike as i64,
+        longest_time_spent_living: stats.longest_time_spent_living as i64,
+        first_blood_kill: stats.first_blood_kill,
+        first_blood_assist: stats.first_blood_assist,
+        first_tower_kill: stats.first_tower_kill,
+        first_tower_assist: stats.first_tower_assist,
+        game_ended_in_surrender: stats.game_ended_in_surrender,
+        game_ended_in_early_surrender: stats.game_ended_in_early_surrender,
+        team_early_surrendered: stats.team_early_surrendered,
+        item0: stats.item0 as i64,
+        item1: stats.item1 as i64,
+        item2: stats.item2 as i64,
+        item3: stats.item3 as i64,
+        item4: stats.item4 as i64,
+        item5: stats.item5 as i64,
+        item6: stats.item6 as i64,
+        gold_earned: stats.gold_earned as i64,
+        gold_spent: stats.gold_spent as i64,
+        total_damage_dealt: stats.total_damage_dealt as i64,
+        total_damage_dealt_to_champions: stats.total_damage_dealt_to_champions as i64,
+        physical_damage_dealt: stats.physical_damage_dealt as i64,
+        physical_damage_dealt_to_champions: stats.physical_damage_dealt_to_champions as i64,
+        magic_damage_dealt: stats.magic_damage_dealt as i64,
+        magic_damage_dealt_to_champions: stats.magic_damage_dealt_to_champions as i64,
+        true_damage_dealt: stats.true_damage_dealt as i64,
+        true_damage_dealt_to_champions: stats.true_damage_dealt_to_champions as i64,
+        total_damage_taken: stats.total_damage_taken as i64,
+        physical_damage_taken: stats.physical_damage_taken as i64,
+        magic_damage_taken: stats.magical_damage_taken as i64,
+        true_damage_taken: stats.true_damage_taken as i64,
+        damage_self_mitigated: stats.damage_self_mitigated as i64,
+        damage_dealt_to_objectives: stats.damage_dealt_to_objectives as i64,
+        damage_dealt_to_turrets: stats.damage_dealt_to_turrets as i64,
+        total_heal: stats.total_heal as i64,
+        total_units_healed: stats.total_units_healed as i64,
+        time_ccing_others: stats.time_c_cing_others as i64,
+        total_time_ccdealt: stats.total_time_crowd_control_dealt as i64,
+        vision_score: stats.vision_score as i64,
+        wards_placed: stats.wards_placed as i64,
+        wards_killed: stats.wards_killed as i64,
+        vision_wards_bought_in_game: stats.vision_wards_bought_in_game as i64,
+        sight_wards_bought_in_game: stats.sight_wards_bought_in_game as i64,
+        total_minions_killed: stats.total_minions_killed as i64,
+        neutral_minions_killed: stats.neutral_minions_killed as i64,
+        total_ally_jungle_minions_killed: stats.neutral_minions_killed_team_jungle as i64,
+        total_enemy_jungle_minions_killed: stats.neutral_minions_killed_enemy_jungle as i64,
+        turret_kills: stats.turret_kills as i64,
+        inhibitor_kills: stats.inhibitor_kills as i64,
+        perks: perks_from_lcu(stats),
+        player_augment1: stats.player_augment1 as i64,
+        player_augment2: stats.player_augment2 as i64,
+        player_augment3: stats.player_augment3 as i64,
+        player_augment4: stats.player_augment4 as i64,
+        player_augment5: stats.player_augment5 as i64,
+        player_augment6: stats.player_augment6 as i64,
+        player_subteam_id: stats.player_subteam_id as i64,
+        subteam_placement: stats.subteam_placement as i64,
+        team_position: stats
+            .team_position
+            .clone()
+            .unwrap_or_else(|| individual_position.clone()),
+        individual_position,
+        lane: participant.timeline.lane.clone(),
+        role: participant.timeline.role.clone(),
+        push_pings: stats.push_pings.unwrap_or_default() as i64,
+        vision_cleared_pings: stats.vision_cleared_pings.unwrap_or_default() as i64,
+        all_in_pings: stats.all_in_pings.unwrap_or_default() as i64,
+        assist_me_pings: stats.assist_me_pings.unwrap_or_default() as i64,
+        basic_pings: stats.basic_pings.unwrap_or_default() as i64,
+        command_pings: stats.command_pings.unwrap_or_default() as i64,
+        danger_pings: stats.danger_pings.unwrap_or_default() as i64,
+        enemy_missing_pings: stats.enemy_missing_pings.unwrap_or_default() as i64,
+        enemy_vision_pings: stats.enemy_vision_pings.unwrap_or_default() as i64,
+        get_back_pings: stats.get_back_pings.unwrap_or_default() as i64,
+        hold_pings: stats.hold_pings.unwrap_or_default() as i64,
+        need_vision_pings: stats.need_vision_pings.unwrap_or_default() as i64,
+        on_my_way_pings: stats.on_my_way_pings.unwrap_or_default() as i64,
+        win: stats.win,
+        ..Default::default()
+    }
+}
+
+fn team_from_lcu(team: &LcuTeam, champion_kills: i64) -> Team {
+    let objective = |first: bool, kills: i32| Atakhan {
+        first,
+        kills: kills as i64,
+    };
+    Team {
+        bans: team
+            .bans
+            .iter()
+            .filter_map(|ban| serde_json::from_value::<Ban>(ban.clone()).ok())
+            .collect(),
+        objectives: Objectives {
+            baron: objective(team.first_baron, team.baron_kills),
+            champion: objective(team.first_blood, champion_kills as i32),
+            dragon: objective(team.first_dargon, team.dragon_kills),
+            horde: objective(false, team.horde_kills),
+            inhibitor: objective(team.first_inhibitor, team.inhibitor_kills),
+            rift_herald: objective(false, team.rift_herald_kills),
+            tower: objective(team.first_tower, team.tower_kills),
+            ..Default::default()
+        },
+        team_id: team.team_id as i64,
+        win: team.win == "Win",
+        ..Default::default()
+    }
+}
+
+/// 把 LCU 的对局详情转换成 SGP 的对局概要
+///
+/// match_id 与 SGP 相同，为 `<平台 ID>_<game_id>`，两个来源保存的同一局不会重复。
+pub fn game_from_lcu(game: &LcuGame) -> Games {
+    let participants: Vec<Participant> = game
+        .participants
+        .iter()
+        .map(|participant| {
+            let player = game
+                .participant_identities
+                .iter()
+                .find(|identity| identity.participant_id == participant.participant_id)
+                .map(|identity| identity.player.clone())
+                .unwrap_or_default();
+            participant_from_lcu(participant, &player)
+        })
+        .collect();
+    let teams = game
+        .teams
+        .iter()
+        .map(|team| {
+            let kills = participants
+                .iter()
+                .filter(|participant| participant.team_id == team.team_id as i64)
+                .map(|participant| participant.kills)
+                .sum();
+            team_from_lcu(team, kills)
+        })
+        .collect();
+
+    Games {
+        metadata: Metadata {
+            product: "LOL".to_string(),
+            participants: participants
+                .iter()
+                .map(|participant| participant.puuid.clone())
+                .collect(),
+            match_id: format!("{}_{}", game.platform_id, game.game_id),
+            ..Default::default()
+        },
+        json: Json {
+            end_of_game_result: game.end_of_game_result.clone(),
+            game_creation: game.game_creation,
+            game_duration: game.game_duration as i64,
+            game_start_timestamp: game.game_creation,
+            game_end_timestamp: game.game_creation + game.game_duration as i64 * 1000,
+            game_id: game.game_id,
+            game_mode: game.game_mode.clone(),
+            game_type: game.game_type.clone(),
+            game_version: game.game_version.clone(),
+            map_id: game.map_id as i64,
+            platform_id: game.platform_id.clone(),
+            queue_id: game.queue_id as i64,
+            season_id: game.season_id as i64,
+            game_mode_mutators: game
+                .game_mode_mutators
+                .iter()
+                .filter_map(|mutator| mutator.as_str().map(str::to_string))
+                .collect(),
+            participants,
+            teams,
+            ..Default::default()
+        },
+    }
+}
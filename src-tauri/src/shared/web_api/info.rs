@@ -1,4 +1,5 @@
 use crate::shared::init::lcu::get_lcu_client;
+use crate::shared::types::league_client::ranked::HighestRankedEntry;
 use crate::shared::{http_api::lcu::LcuApi, init::game_data::get_champion_info_cache};
 use serde::{Deserialize, Serialize};
 
@@ -25,6 +26,18 @@ pub struct RankInfo {
     pub losses: Option<i64>,
 }
 
+impl From<&HighestRankedEntry> for RankInfo {
+    fn from(entry: &HighestRankedEntry) -> Self {
+        Self {
+            rank: entry.tier.clone(),
+            division: entry.division.clone(),
+            lp: Some(entry.league_points),
+            wins: Some(entry.wins),
+            losses: Some(entry.losses),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct FavoriteHero {
@@ -51,20 +64,8 @@ pub async fn get_rank_info(client: &LcuApi, info: &mut Info, puuid: &str) {
         wins: Some(ranked.highest_ranked_entry.wins),
         losses: Some(ranked.highest_ranked_entry.losses),
     };
-    info.solo_rank = RankInfo {
-        rank: ranked.queue_map.ranked_solo_5x5.tier,
-        division: ranked.queue_map.ranked_solo_5x5.division,
-        lp: Some(ranked.queue_map.ranked_solo_5x5.league_points),
-        wins: Some(ranked.queue_map.ranked_solo_5x5.wins),
-        losses: Some(ranked.queue_map.ranked_solo_5x5.losses),
-    };
-    info.flex_rank = RankInfo {
-        rank: ranked.queue_map.ranked_flex_sr.tier,
-        division: ranked.queue_map.ranked_flex_sr.division,
-        lp: Some(ranked.queue_map.ranked_flex_sr.league_points),
-        wins: Some(ranked.queue_map.ranked_flex_sr.wins),
-        losses: Some(ranked.queue_map.ranked_flex_sr.losses),
-    };
+    info.solo_rank = RankInfo::from(&ranked.queue_map.ranked_solo_5x5);
+    info.flex_rank = RankInfo::from(&ranked.queue_map.ranked_flex_sr);
 }
 
 pub async fn get_champion_mastery_info(client: &LcuApi, info: &mut Info, puuid: &str) {
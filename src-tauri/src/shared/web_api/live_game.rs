@@ -0,0 +1,168 @@
+use crate::shared::analysis::premade::{PartyTag, PremadeCandidate};
+use crate::shared::http_api::lcu::LcuApi;
+use crate::shared::init::game_data::{get_champion_info_cache, is_cache_initialized};
+use crate::shared::init::lcu::get_lcu_client;
+use crate::shared::init::sgp::get_sgp_client;
+use crate::shared::store::note_store::{load_notes_for_display, PlayerNote};
+use crate::shared::types::sgp::spectator_data::SgpSpectatorData;
+use crate::shared::web_api::info::FavoriteHero;
+use crate::shared::web_api::premade::detect_local_premades;
+use crate::shared::web_api::scout::{scout_player_with_masteries, PlayerScout};
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+
+/// 同时解析的玩家数量
+const LIVE_CONCURRENCY: usize = 5;
+const TEAM_ONE: i64 = 100;
+const TEAM_TWO: i64 = 200;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LivePlayer {
+    /// 隐藏信息时为空
+    pub puuid: String,
+    /// 100 或 200
+    pub team_id: i64,
+    pub is_ally: bool,
+    pub is_self: bool,
+    pub champion_id: i64,
+    pub champion_name: String,
+    pub position: String,
+    pub spell1_id: i64,
+    pub spell2_id: i64,
+    /// 当前英雄的成就，没有玩过或获取失败时为空
+    pub champion_mastery: Option<FavoriteHero>,
+    pub scout: PlayerScout,
+    /// 所在车队，不是开黑或无法判断时为空
+    pub premade: Option<PartyTag>,
+    /// 自己保存的备注
+    pub note: Option<PlayerNote>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveGame {
+    pub game_id: i64,
+    pub queue_id: i64,
+    pub game_mode: String,
+    pub map_id: i64,
+    /// 己方在前
+    pub players: Vec<LivePlayer>,
+}
+
+/// 从观战数据中取出十名玩家的座位信息，己方在前
+pub fn live_seats(data: &SgpSpectatorData, self_puuid: &str) -> Vec<LivePlayer> {
+    let game = &data.game;
+    let team_one = game.team_one.iter().map(|member| LivePlayer {
+        puuid: member.puuid.clone(),
+        team_id: TEAM_ONE,
+        champion_id: member.champion_id,
+        position: member.selected_position.clone(),
+        ..Default::default()
+    });
+    let team_two = game.team_two.iter().map(|member| LivePlayer {
+        puuid: member.puuid.clone(),
+        team_id: TEAM_TWO,
+        champion_id: member.champion_id,
+        position: member.selected_position.clone(),
+        ..Default::default()
+    });
+    let mut players: Vec<LivePlayer> = team_one.chain(team_two).collect();
+
+    let ally_team = players
+        .iter()
+        .find(|player| player.puuid == self_puuid)
+        .map(|player| player.team_id)
+        .unwrap_or(TEAM_ONE);
+    for player in &mut players {
+        player.is_ally = player.team_id == ally_team;
+        player.is_self = !player.puuid.is_empty() && player.puuid == self_puuid;
+        if let Some(selection) = game
+            .player_champion_selections
+            .iter()
+            .find(|selection| !player.puuid.is_empty() && selection.puuid == player.puuid)
+        {
+            player.spell1_id = selection.spell1id;
+            player.spell2_id = selection.spell2id;
+        }
+    }
+    players.sort_by_key(|player| !player.is_ally);
+    players
+}
+
+async fn resolve_player(client: &LcuApi, mut player: LivePlayer) -> LivePlayer {
+    if player.puuid.is_empty() {
+        return player;
+    }
+    // 侦察时已经获取了全部英雄成就，直接查找当前英雄
+    let (scout, masteries) = scout_player_with_masteries(client, &player.puuid).await;
+    player.scout = scout;
+    player.champion_mastery = masteries
+        .into_iter()
+        .find(|mastery| mastery.champion_id == player.champion_id)
+        .map(|mastery| FavoriteHero {
+            champion_id: mastery.champion_id,
+            champion_name: player.champion_name.clone(),
+            matches: mastery.champion_points,
+        });
+    player
+}
+
+/// 把保存的备注附加到玩家上
+///
+/// 备注随时可能修改，从缓存返回对局时也需要重新附加。
+pub async fn attach_live_notes(game: &mut LiveGame) {
+    let notes = load_notes_for_display().await;
+    for player in &mut game.players {
+        player.note = notes.get(&player.puuid).cloned();
+    }
+}
+
+/// 获取当前对局中所有玩家的信息
+///
+/// # 参数
+/// - `self_puuid`: 当前召唤师的 puuid，用于获取观战数据和区分敌我
+pub async fn build_live_game(self_puuid: &str) -> Result<LiveGame, String> {
+    let sgp = get_sgp_client().await.map_err(|e| e.to_string())?;
+    let lcu = get_lcu_client().await.map_err(|e| e.to_string())?;
+    let data = sgp
+        .get_spectator_gameflow_by_puuid(self_puuid)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut seats = live_seats(&data, self_puuid);
+    if is_cache_initialized().await {
+        let champion_cache = get_champion_info_cache().await;
+        for seat in &mut seats {
+            if let Some(champion) = champion_cache.get(&seat.champion_id) {
+                seat.champion_name = champion.name.clone();
+            }
+        }
+    }
+
+    let mut players: Vec<LivePlayer> = stream::iter(seats)
+        .map(|seat| resolve_player(&lcu, seat))
+        .buffered(LIVE_CONCURRENCY)
+        .collect()
+        .await;
+
+    // 侦察时已经拉取了每名玩家最近的对局，直接在本地数据上检测开黑
+    let candidates: Vec<PremadeCandidate> = players
+        .iter()
+        .map(|player| PremadeCandidate {
+            puuid: player.puuid.clone(),
+            team_id: player.team_id,
+        })
+        .collect();
+    let report = detect_local_premades(&candidates).await;
+    for player in &mut players {
+        player.premade = report.tag_of(&player.puuid);
+    }
+    Ok(LiveGame {
+        game_id: data.game.id,
+        queue_id: data.game.game_queue_config_id,
+        game_mode: data.game.game_mode.clone(),
+        map_id: data.game.map_id,
+        players,
+    })
+}
@@ -0,0 +1,42 @@
+use crate::shared::init::lcu::get_lcu_client;
+use crate::shared::store::note_store::{load_notes_for_display, PlayerNote};
+use serde::{Deserialize, Serialize};
+
+/// 大厅中的玩家
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LobbyPlayer {
+    pub puuid: String,
+    pub summoner_name: String,
+    pub summoner_level: i32,
+    pub summoner_icon_id: i32,
+    pub is_leader: bool,
+    pub is_self: bool,
+    pub first_position_preference: String,
+    pub second_position_preference: String,
+    /// 自己保存的备注
+    pub note: Option<PlayerNote>,
+}
+
+/// 获取当前大厅中的玩家，不包括机器人
+pub async fn get_lobby_players() -> Result<Vec<LobbyPlayer>, String> {
+    let client = get_lcu_client().await.map_err(|e| e.to_string())?;
+    let lobby = client.lobby.get_lobby().await.map_err(|e| e.to_string())?;
+    let notes = load_notes_for_display().await;
+    Ok(lobby
+        .members
+        .iter()
+        .filter(|member| !member.is_bot)
+        .map(|member| LobbyPlayer {
+            puuid: member.puuid.clone(),
+            summoner_name: member.summoner_name.clone(),
+            summoner_level: member.summoner_level,
+            summoner_icon_id: member.summoner_icon_id,
+            is_leader: member.is_leader,
+            is_self: member.puuid == lobby.local_member.puuid,
+            first_position_preference: member.first_position_preference.clone(),
+            second_position_preference: member.second_position_preference.clone(),
+            note: notes.get(&member.puuid).cloned(),
+        })
+        .collect())
+}
@@ -0,0 +1,149 @@
+use crate::shared::analysis::matchup::{
+    fill_matchup_names, matchup_samples, picks_into, versus_enemies, MatchupSample, MatchupStats,
+};
+use crate::shared::analysis::role::Role;
+use crate::shared::init::lcu::get_lcu_client;
+use crate::shared::store::match_store::get_player_games;
+use crate::shared::web_api::game_detail::get_game_details;
+use serde::{Deserialize, Serialize};
+
+/// 默认参与统计的最近局数
+const DEFAULT_MATCHUP_GAMES: usize = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct MatchupQuery {
+    pub puuid: String,
+    /// 使用最近多少局，默认为 `DEFAULT_MATCHUP_GAMES`
+    pub count: Option<usize>,
+    pub role: Option<Role>,
+    /// 只统计自己使用该英雄的对局
+    pub champion_id: Option<i64>,
+    /// 设置后额外统计面对该英雄时自己各个英雄的战绩
+    pub enemy_champion_id: Option<i64>,
+    /// 本地没有对局详情时是否从服务器拉取，为 false 时只用本地已有的详情计算 15 分钟差距
+    pub fetch_details: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchupReport {
+    /// 按对位英雄统计
+    pub versus: Vec<MatchupStats>,
+    /// 面对 `enemy_champion_id` 时按自己的英雄统计，没有指定时为空
+    pub picks: Vec<MatchupStats>,
+}
+
+async fn load_samples(
+    puuid: &str,
+    count: usize,
+    fetch_details: bool,
+) -> Result<Vec<MatchupSample>, String> {
+    let games: Vec<_> = get_player_games(puuid)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .take(count)
+        .collect();
+    let match_ids: Vec<String> = games
+        .iter()
+        .map(|game| game.metadata.match_id.clone())
+        .collect();
+    let details = get_game_details(&match_ids, fetch_details)
+        .await
+        .into_iter()
+        .map(|detail| (detail.metadata.match_id.clone(), detail))
+        .collect();
+    Ok(matchup_samples(&games, &details, puuid))
+}
+
+/// 统计玩家的对位战绩
+pub async fn get_matchup_report(query: &MatchupQuery) -> Result<MatchupReport, String> {
+    let samples = load_samples(
+        &query.puuid,
+        query.count.unwrap_or(DEFAULT_MATCHUP_GAMES),
+        query.fetch_details,
+    )
+    .await?;
+
+    let mut report = MatchupReport {
+        versus: versus_enemies(&samples, query.champion_id, query.role),
+        picks: query
+            .enemy_champion_id
+            .map(|enemy| picks_into(&samples, enemy, query.role))
+            .unwrap_or_default(),
+    };
+    fill_matchup_names(&mut report.versus).await;
+    fill_matchup_names(&mut report.picks).await;
+    Ok(report)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EnemyMatchup {
+    pub enemy_champion_id: i64,
+    /// 当前选择（或预选）的英雄对该英雄的战绩，没有对局时为空
+    pub current: Option<MatchupStats>,
+    /// 面对该英雄时自己各个英雄的战绩
+    pub picks: Vec<MatchupStats>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChampSelectMatchups {
+    /// 分配到的位置，匹配模式等没有分配位置时为空
+    pub role: Option<Role>,
+    /// 当前选择的英雄，没有选择时为预选的英雄，都没有时为 0
+    pub champion_id: i64,
+    /// 对方已经选出的每个英雄（对方的位置在英雄选择阶段不可见）
+    pub enemies: Vec<EnemyMatchup>,
+}
+
+/// 在英雄选择阶段查看自己与对方已选英雄的对位战绩，只使用本地数据
+pub async fn get_champ_select_matchups() -> Result<ChampSelectMatchups, String> {
+    let client = get_lcu_client().await.map_err(|e| e.to_string())?;
+    let session = client
+        .champ_select
+        .get_session()
+        .await
+        .map_err(|e| e.to_string())?;
+    let local_cell_id = session.base.local_player_cell_id;
+    let me = session
+        .base
+        .my_team
+        .iter()
+        .find(|member| member.cell_id == local_cell_id)
+        .ok_or("找不到当前玩家".to_string())?;
+    let role = Role::parse(&me.assigned_position);
+    let champion_id = if me.champion_id != 0 {
+        me.champion_id
+    } else {
+        me.champion_pick_intent
+    } as i64;
+
+    let samples = load_samples(&me.puuid, DEFAULT_MATCHUP_GAMES, false).await?;
+    let mut enemies = vec![];
+    for member in &session.base.their_team {
+        if member.champion_id == 0 {
+            continue;
+        }
+        let enemy_champion_id = member.champion_id as i64;
+        let mut picks = picks_into(&samples, enemy_champion_id, role);
+        fill_matchup_names(&mut picks).await;
+        enemies.push(EnemyMatchup {
+            enemy_champion_id,
+            current: picks
+                .iter()
+                .find(|stats| stats.champion_id == champion_id)
+                .cloned(),
+            picks,
+        });
+    }
+
+    Ok(ChampSelectMatchups {
+        role,
+        champion_id,
+        enemies,
+    })
+}
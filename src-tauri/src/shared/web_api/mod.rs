@@ -1,3 +1,18 @@
+#[cfg(test)]
+pub mod tests;
+
+pub mod champ_select;
+pub mod game_detail;
+pub mod head_to_head;
+pub mod history_provider;
 pub mod info;
+pub mod live_game;
+pub mod lobby;
+pub mod matchup;
+pub mod post_game;
+pub mod premade;
+pub mod rank_tracker;
 pub mod record;
 pub mod record_sgp;
+pub mod scout;
+pub mod search;
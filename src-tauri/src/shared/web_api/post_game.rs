@@ -0,0 +1,79 @@
+use crate::shared::analysis::lp::{lp_changes, LpChange};
+use crate::shared::analysis::post_game::{build_post_game_report, PostGameReport};
+use crate::shared::init::lcu::get_lcu_client;
+use crate::shared::store::match_store::get_player_games;
+use crate::shared::store::rank_store::get_rank_snapshots;
+use crate::shared::store::report_store::save_report;
+use crate::shared::types::sgp::history::Games;
+use crate::shared::web_api::game_detail::get_game_detail;
+use crate::shared::web_api::record_sgp::sync_match_history;
+use crate::utils::config::score::get_score_config;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::time::{sleep, Duration};
+use tracing::warn;
+
+/// 对局刚结束时服务器上可能还没有战绩，等待后重试
+const SUMMARY_RETRIES: usize = 6;
+const SUMMARY_RETRY_DELAY: Duration = Duration::from_secs(5);
+/// 每次拉取的最近局数
+const SUMMARY_SYNC_COUNT: i32 = 5;
+
+/// 拉取最近的战绩，直到找到刚结束的对局
+async fn wait_for_summary(puuid: &str, game_id: i64) -> Result<Arc<Games>, String> {
+    for attempt in 1..=SUMMARY_RETRIES {
+        if let Err(e) = sync_match_history(puuid, 0, SUMMARY_SYNC_COUNT).await {
+            warn!("拉取战绩失败（第 {} 次）: {}", attempt, e);
+        }
+        let games = get_player_games(puuid).await.map_err(|e| e.to_string())?;
+        if let Some(game) = games.into_iter().find(|game| game.json.game_id == game_id) {
+            return Ok(game);
+        }
+        sleep(SUMMARY_RETRY_DELAY).await;
+    }
+    Err(format!("没有找到对局 {} 的战绩", game_id))
+}
+
+/// 在所有排位队列的快照中找到这局的胜点变化
+async fn lp_change_of(puuid: &str, game_id: i64) -> Option<LpChange> {
+    let snapshots = get_rank_snapshots(puuid)
+        .await
+        .inspect_err(|e| warn!("读取段位快照失败: {}", e))
+        .ok()?;
+    let queue_types: HashSet<&str> = snapshots
+        .iter()
+        .map(|snapshot| snapshot.queue_type.as_str())
+        .collect();
+    queue_types.into_iter().find_map(|queue_type| {
+        lp_changes(&snapshots, queue_type)
+            .into_iter()
+            .find(|change| change.game_id == Some(game_id))
+    })
+}
+
+/// 生成并保存当前账号刚结束的对局的赛后报告
+///
+/// 应在记录段位快照之后调用，这样报告中才有胜点变化。对局详情获取失败时报告中没有时间线。
+pub async fn create_post_game_report(game_id: i64) -> Result<PostGameReport, String> {
+    let client = get_lcu_client().await.map_err(|e| e.to_string())?;
+    let puuid = client
+        .summoner
+        .get_current_summoner()
+        .await
+        .map_err(|e| e.to_string())?
+        .puuid;
+
+    let game = wait_for_summary(&puuid, game_id).await?;
+    let detail = get_game_detail(&game.metadata.match_id)
+        .await
+        .inspect_err(|e| warn!("获取对局详情失败，报告中不包含时间线: {}", e))
+        .ok();
+    let lp_change = lp_change_of(&puuid, game_id).await;
+    let score_config = get_score_config().await;
+
+    let report = build_post_game_report(&game, detail.as_ref(), &puuid, lp_change, &score_config);
+    if let Err(e) = save_report(&report).await {
+        warn!("保存赛后报告失败: {}", e);
+    }
+    Ok(report)
+}
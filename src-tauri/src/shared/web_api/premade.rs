@@ -0,0 +1,36 @@
+use crate::shared::analysis::premade::{detect_premades, PremadeCandidate, PremadeReport};
+use crate::shared::store::match_store::get_player_games;
+use crate::shared::web_api::record_sgp::sync_match_history;
+use futures::stream::{self, StreamExt};
+use tracing::warn;
+
+/// 同时拉取战绩的玩家数量
+const HISTORY_CONCURRENCY: usize = 3;
+/// 每名玩家参与检测的最近局数
+const HISTORY_COUNT: usize = 20;
+
+/// 检测当前对局中的开黑玩家
+///
+/// 先从服务器拉取每名玩家最近的对局保存到本地（失败时使用本地已有的数据），再在本地对局上检测。
+pub async fn detect_game_premades(players: &[PremadeCandidate]) -> PremadeReport {
+    stream::iter(players.iter().filter(|player| !player.puuid.is_empty()))
+        .for_each_concurrent(HISTORY_CONCURRENCY, |player| async move {
+            if let Err(e) = sync_match_history(&player.puuid, 0, HISTORY_COUNT as i32).await {
+                warn!("拉取 {} 的战绩失败，使用本地数据: {}", player.puuid, e);
+            }
+        })
+        .await;
+    detect_local_premades(players).await
+}
+
+/// 只在本地已保存的对局上检测开黑，调用方已经拉取过战绩时使用
+pub async fn detect_local_premades(players: &[PremadeCandidate]) -> PremadeReport {
+    let mut games = vec![];
+    for player in players {
+        match get_player_games(&player.puuid).await {
+            Ok(player_games) => games.extend(player_games.into_iter().take(HISTORY_COUNT)),
+            Err(e) => warn!("读取 {} 的本地对局失败: {}", player.puuid, e),
+        }
+    }
+    detect_premades(players, &games)
+}
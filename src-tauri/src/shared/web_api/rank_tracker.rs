@@ -0,0 +1,77 @@
+use crate::shared::init::lcu::get_lcu_client;
+use crate::shared::store::rank_store::{get_rank_snapshots, save_rank_snapshots, RankSnapshot};
+use crate::shared::types::league_client::ranked::RankedStats;
+use crate::utils::time::now_millis;
+use tokio::time::{sleep, Duration};
+
+/// 对局结束后段位数据可能还没有更新，等待后重试
+const RANK_REFRESH_RETRIES: usize = 5;
+const RANK_REFRESH_DELAY: Duration = Duration::from_secs(3);
+
+fn snapshots_of(ranked: &RankedStats, game_id: Option<i64>) -> Vec<RankSnapshot> {
+    let timestamp = now_millis();
+    ranked
+        .queues
+        .iter()
+        .filter(|queue| !queue.queue_type.is_empty() && queue.wins + queue.losses > 0)
+        .map(|queue| RankSnapshot {
+            timestamp,
+            game_id,
+            queue_type: queue.queue_type.clone(),
+            tier: queue.tier.clone(),
+            division: queue.division.clone(),
+            league_points: queue.league_points,
+            wins: queue.wins,
+            losses: queue.losses,
+        })
+        .collect()
+}
+
+/// 记录当前账号的段位快照，返回保存的条数
+///
+/// # 参数
+/// - `game_id`: 刚结束的对局。不为空时会等待段位更新（胜负场变化）后再记录，
+///   等不到更新（如非排位对局）时记录的快照与上一条相同，不会保存
+pub async fn record_rank_snapshot(game_id: Option<i64>) -> Result<usize, String> {
+    let client = get_lcu_client().await.map_err(|e| e.to_string())?;
+    let puuid = client
+        .summoner
+        .get_current_summoner()
+        .await
+        .map_err(|e| e.to_string())?
+        .puuid;
+    let played =
+        |snapshots: &[RankSnapshot]| -> i64 { snapshots.iter().map(|s| s.wins + s.losses).sum() };
+    let stored = get_rank_snapshots(&puuid)
+        .await
+        .map_err(|e| e.to_string())?;
+    // 每个队列最后一条快照
+    let mut last: Vec<RankSnapshot> = vec![];
+    for snapshot in stored.iter().rev() {
+        if !last.iter().any(|s| s.queue_type == snapshot.queue_type) {
+            last.push(snapshot.clone());
+        }
+    }
+
+    let mut attempts = if game_id.is_some() {
+        RANK_REFRESH_RETRIES
+    } else {
+        1
+    };
+    let snapshots = loop {
+        let ranked = client
+            .ranked
+            .get_current_ranked_stats()
+            .await
+            .map_err(|e| e.to_string())?;
+        let snapshots = snapshots_of(&ranked, game_id);
+        attempts -= 1;
+        if attempts == 0 || played(&snapshots) != played(&last) {
+            break snapshots;
+        }
+        sleep(RANK_REFRESH_DELAY).await;
+    };
+    save_rank_snapshots(&puuid, &snapshots)
+        .await
+        .map_err(|e| e.to_string())
+}
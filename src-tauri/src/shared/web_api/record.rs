@@ -1,282 +1,454 @@
-use crate::shared::init::lcu::get_lcu_client;
-use crate::shared::types::league_client::match_history::Game;
-use crate::shared::{
-    http_api::lcu::LcuApi,
-    types::league_client::match_history::{Participant, ParticipantIdentity},
+//! 战绩记录
+//!
+//! SGP 和 LCU 的战绩统一转换成 `RecordItem` 返回给前端。LCU 的对局先转换成 SGP 的 `Games`
+//! （见 `history_provider::game_from_lcu`），再和 SGP 的对局走同一套转换。
+//! 结构有不兼容的修改时增加 `RECORD_VERSION`。
+
+use crate::shared::analysis::arena::{augments_of, is_arena, placement_of};
+use crate::shared::analysis::role::{lane_opponents, resolve_roles, Role};
+use crate::shared::analysis::score::{score_sgp_game, ScoreBadge};
+use crate::shared::init::game_data::{
+    get_augment_info_cache, get_champion_info_cache, get_item_info_cache, get_perk_info_cache,
+    get_perk_style_info_cache, get_spell_info_cache, is_cache_initialized, Item as GameDataItem,
+};
+use crate::shared::store::note_store::PlayerNote;
+use crate::shared::types::league_client::match_history::Game as LcuGame;
+use crate::shared::types::sgp::history::{
+    Atakhan, Games, Participant as SgpParticipant, Team as SgpTeam,
 };
-use futures::future::join_all;
+use crate::shared::web_api::history_provider::game_from_lcu;
+use crate::utils::config::score::{get_score_config, ScoreConfig};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// 战绩结构的版本
+pub const RECORD_VERSION: u32 = 2;
 
-/// KDA 数据结构
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct Kda {
-    pub kills: i32,
-    pub deaths: i32,
-    pub assists: i32,
+pub struct RecordItem {
+    pub version: u32,
+    /// match_id，如 HN1_123456
+    pub game_id: String,
+    pub puuid: String,
+    pub game_creation: i64,
+    pub duration: i32,
+    pub queue_id: i32,
+    pub game_mode: String,
+    pub game_version: String,
+    pub platform_id: String,
+    /// 是否为斗魂竞技场，斗魂竞技场按小队名次而不是胜负结算
+    pub is_arena: bool,
+    pub teams: Vec<RecordTeam>,
+    pub participants: Vec<Participant>,
 }
 
-/// 物品数据结构（装备或眼位）
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct Item {
-    pub id: String,
-    pub name: String,
+pub struct RecordTeam {
+    pub team_id: i32,
+    pub win: bool,
+    pub bans: Vec<RecordBan>,
+    pub objectives: TeamObjectives,
 }
 
-/// 召唤师技能数据结构
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct Spell {
-    pub id: String,
-    pub name: String,
+pub struct RecordBan {
+    pub champion: Item,
+    pub pick_turn: i64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Objective {
+    /// 是否首个拿下
+    pub first: bool,
+    pub kills: i64,
 }
 
-/// 符文数据结构
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct Perk {
-    pub id: String,
-    pub name: String,
+pub struct TeamObjectives {
+    pub champion: Objective,
+    pub tower: Objective,
+    pub inhibitor: Objective,
+    pub dragon: Objective,
+    pub rift_herald: Objective,
+    pub horde: Objective,
+    pub baron: Objective,
+    pub atakhan: Objective,
 }
 
-/// 玩家统计数据
+/// 完整的符文配置，斗魂竞技场等模式为空
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct PlayerStats {
-    pub damage: i32,             // 伤害数值
-    pub damage_share: f64,       // 伤害占比
-    pub damage_taken: i32,       // 抗伤数值
-    pub damage_taken_share: f64, // 抗伤占比
-    pub healing: i32,            // 治疗数值
-    pub healing_share: f64,      // 治疗占比
+pub struct Runes {
+    pub primary_style: Item,
+    pub sub_style: Item,
+    /// 主系 4 个，副系 2 个
+    pub selections: Vec<Item>,
+    /// 属性碎片：进攻、灵活、防御
+    pub stat_perks: Vec<i64>,
 }
 
-/// 玩家信息（用于 champion、teammates、enemies）
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct PlayerInfo {
-    pub id: String,      // puuid
-    pub name: String,    // 玩家名称 + tag
-    pub hero: String,    // 英雄名称
-    pub hero_id: String, // 英雄ID
-    pub score: i32,      // 评分
-    pub kda: Kda,
+pub struct Participant {
+    pub puuid: String,
+    pub name: String,
+    pub tag: String,
+    pub team_id: i32,
+    pub win: bool,
+    /// 原始分路，经常为 NONE，位置请使用 `role`
+    pub lane: String,
+    /// 判定后的位置，没有分路的模式为空
+    pub role: Option<Role>,
+    /// 对位对手的 puuid
+    pub lane_opponent: Option<String>,
+    pub best: bool,
+    pub score: f64,
+    /// 斗魂竞技场的小队编号，其他模式为 0
+    pub subteam_id: i64,
+    /// 斗魂竞技场的名次（1~8），其他模式为 0
+    pub placement: i64,
+    /// 斗魂竞技场选择的强化符文
+    pub augments: Vec<Item>,
+    pub badge: Option<ScoreBadge>,
+    /// 自己保存的备注
+    pub note: Option<PlayerNote>,
+
+    pub champion: Item,
+    pub champion_level: i64,
+    pub spells: Vec<Item>,
+    /// 基石符文和副系第一个符文，完整配置见 `runes`
+    pub perks: Vec<Item>,
+    pub runes: Runes,
+    /// 6 件装备 + 饰品
+    pub items: Vec<Item>,
+
+    pub damage_to_turrets: i64,
+    pub damage_to_turrets_percentage: f64,
+    pub damage_to_champions: i64,
+    pub damage_to_champions_percentage: f64,
+    pub damage_taken: i64,
+    pub damage_taken_percentage: f64,
+    pub heal: i64,
+    pub heal_percentage: f64,
+    pub gold_earned: i64,
+    /// 补刀数（小兵 + 野怪）
+    pub cs: i64,
+    pub vision_score: i64,
+
+    pub kills: i64,
+    pub deaths: i64,
+    pub assists: i64,
+    pub kda: f64,
 }
 
-/// 战绩记录数据结构
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct RecordItem {
-    pub id: String,
-    pub champion: PlayerInfo,
-    pub is_win: bool,       // 是否胜利
-    pub is_best: bool,      // 是否是最佳
-    pub date: String,       // 游戏时间（毫秒时间戳字符串）
-    pub duration: i32,      // 游戏时长（秒）
-    pub queue_id: i32,      // 队列ID，表示游戏模式
-    pub items: Vec<Item>,   // 6装备 + 1个眼位
-    pub spells: Vec<Spell>, // 2个召唤师技能
-    pub perks: Vec<Perk>,   // 2个符文
-    pub stats: PlayerStats,
-    pub teammates: Vec<PlayerInfo>, // 队友列表 TODO: 换名字 蓝队
-    pub enemies: Vec<PlayerInfo>,   // 敌方列表 TODO: 换名字 红队
+pub struct Item {
+    pub id: i64,
+    pub name: String,
+}
+
+/// 转换时用于查询名称的游戏数据，未初始化时名称显示为“未知”
+#[derive(Debug, Clone, Default)]
+pub struct GameDataNames {
+    pub champions: Arc<HashMap<i64, GameDataItem>>,
+    pub items: Arc<HashMap<i64, GameDataItem>>,
+    pub spells: Arc<HashMap<i64, GameDataItem>>,
+    pub perks: Arc<HashMap<i64, GameDataItem>>,
+    pub perk_styles: Arc<HashMap<i64, GameDataItem>>,
+    pub augments: Arc<HashMap<i64, GameDataItem>>,
 }
 
-pub async fn parse_game(client: &LcuApi, game: &Game, puuid: &str) -> RecordItem {
-    fn parse_participant(
-        participant: &Participant,
-        pariticipant_identity: &ParticipantIdentity,
-    ) -> PlayerInfo {
-        PlayerInfo {
-            id: pariticipant_identity.player.puuid.clone(),
-            name: format!(
-                "{}#{}",
-                pariticipant_identity.player.game_name, pariticipant_identity.player.tag_line
-            ),
-            hero: participant.champion_id.to_string(),
-            hero_id: participant.champion_id.to_string(),
-            score: 0,
-            kda: Kda {
-                kills: participant.stats.kills,
-                deaths: participant.stats.deaths,
-                assists: participant.stats.assists,
-            },
+impl GameDataNames {
+    /// 读取游戏数据缓存，客户端未连接或已断开时使用空表，不会 panic
+    pub async fn load() -> Self {
+        if !is_cache_initialized().await {
+            return Self {
+                augments: get_augment_info_cache().await,
+                ..Default::default()
+            };
+        }
+        Self {
+            champions: get_champion_info_cache().await,
+            items: get_item_info_cache().await,
+            spells: get_spell_info_cache().await,
+            perks: get_perk_info_cache().await,
+            perk_styles: get_perk_style_info_cache().await,
+            augments: get_augment_info_cache().await,
         }
     }
 
-    let game_detail = client
-        .match_history
-        .get_game(game.game_id)
-        .await
-        .expect("获取游戏详情失败");
-
-    let mut record = RecordItem::default();
-    record.id = game.game_id.to_string();
-    record.queue_id = game.queue_id;
-    record.date = game_detail.game_creation.to_string();
-    record.duration = game_detail.game_duration;
-
-    // 先找到当前玩家的 team_id
-    let mut current_player_team_id = None;
-    for pariticipant_identity in &game_detail.participant_identities {
-        if pariticipant_identity.player.puuid == puuid {
-            let participant = game_detail
-                .participants
-                .iter()
-                .find(|p| p.participant_id == pariticipant_identity.participant_id)
-                .expect("获取玩家信息失败");
-            current_player_team_id = Some(participant.team_id);
-            break;
+    fn lookup(map: &HashMap<i64, GameDataItem>, id: i64, kind: &str) -> Item {
+        Item {
+            id,
+            name: map
+                .get(&id)
+                .map(|item| item.name.clone())
+                .unwrap_or_else(|| format!("未知{}({})", kind, id)),
         }
     }
 
-    let current_team_id = match current_player_team_id {
-        Some(id) => id,
-        None => {
-            // 如果找不到当前玩家，返回空记录
-            return record;
+    fn champion(&self, id: i64) -> Item {
+        Self::lookup(&self.champions, id, "英雄")
+    }
+
+    fn item(&self, id: i64) -> Item {
+        if id == 0 {
+            // 空物品槽位
+            return Item {
+                id,
+                name: "无装备".to_string(),
+            };
         }
-    };
+        Self::lookup(&self.items, id, "物品")
+    }
 
-    // 累加同队玩家的总数据（用于计算占比）
-    let mut team_total_damage = 0.0;
-    let mut team_total_damage_taken = 0.0;
-    let mut team_total_healing = 0.0;
+    fn spell(&self, id: i64) -> Item {
+        Self::lookup(&self.spells, id, "技能")
+    }
 
-    for pariticipant_identity in &game_detail.participant_identities {
-        let participant = game_detail
-            .participants
-            .iter()
-            .find(|p| p.participant_id == pariticipant_identity.participant_id)
-            .expect("获取玩家信息失败");
-
-        // 只累加同队玩家的数据
-        if participant.team_id == current_team_id {
-            team_total_damage += participant.stats.total_damage_dealt_to_champions as f64;
-            team_total_damage_taken += participant.stats.total_damage_taken as f64;
-            team_total_healing += participant.stats.total_heal as f64;
-        }
+    fn perk(&self, id: i64) -> Item {
+        Self::lookup(&self.perks, id, "符文")
+    }
 
-        if pariticipant_identity.player.puuid == puuid {
-            record.champion = parse_participant(participant, pariticipant_identity);
-            record.is_win = participant.stats.win;
-            record.is_best = false; // TODO: 计算是否是最佳
-            record.items = vec![
-                Item {
-                    id: participant.stats.item0.to_string(),
-                    name: participant.stats.item0.to_string(),
-                },
-                Item {
-                    id: participant.stats.item1.to_string(),
-                    name: participant.stats.item1.to_string(),
-                },
-                Item {
-                    id: participant.stats.item2.to_string(),
-                    name: participant.stats.item2.to_string(),
-                },
-                Item {
-                    id: participant.stats.item3.to_string(),
-                    name: participant.stats.item3.to_string(),
-                },
-                Item {
-                    id: participant.stats.item4.to_string(),
-                    name: participant.stats.item4.to_string(),
-                },
-                Item {
-                    id: participant.stats.item5.to_string(),
-                    name: participant.stats.item5.to_string(),
-                },
-                Item {
-                    id: participant.stats.item6.to_string(),
-                    name: participant.stats.item6.to_string(),
-                },
-            ];
-            record.spells = vec![
-                Spell {
-                    id: participant.spell1_id.to_string(),
-                    name: participant.spell1_id.to_string(),
-                },
-                Spell {
-                    id: participant.spell2_id.to_string(),
-                    name: participant.spell2_id.to_string(),
-                },
-            ];
-            record.perks = vec![
-                Perk {
-                    id: participant.stats.perk0.to_string(),
-                    name: participant.stats.perk0.to_string(),
-                },
-                Perk {
-                    id: participant.stats.perk1.to_string(),
-                    name: participant.stats.perk1.to_string(),
-                },
-            ];
-            // 当前玩家的数据
-            record.stats.damage = participant.stats.total_damage_dealt_to_champions;
-            record.stats.damage_taken = participant.stats.total_damage_taken;
-            record.stats.healing = participant.stats.total_heal;
-        }
+    fn perk_style(&self, id: i64) -> Item {
+        Self::lookup(&self.perk_styles, id, "符文风格")
+    }
 
-        // 分类队友和敌人
-        if participant.team_id == 100 {
-            record
-                .teammates
-                .push(parse_participant(participant, pariticipant_identity));
-        } else {
-            record
-                .enemies
-                .push(parse_participant(participant, pariticipant_identity));
-        }
+    fn augment(&self, id: i64) -> Item {
+        Self::lookup(&self.augments, id, "强化符文")
     }
+}
 
-    // 计算占比：当前玩家数值 / 同队总数值（0~1之间的小数）
-    if team_total_damage > 0.0 {
-        record.stats.damage_share = record.stats.damage as f64 / team_total_damage;
-    } else {
-        record.stats.damage_share = 0.0;
+fn objective(objective: &Atakhan) -> Objective {
+    Objective {
+        first: objective.first,
+        kills: objective.kills,
     }
+}
 
-    if team_total_damage_taken > 0.0 {
-        record.stats.damage_taken_share =
-            record.stats.damage_taken as f64 / team_total_damage_taken;
-    } else {
-        record.stats.damage_taken_share = 0.0;
+fn parse_team(team: &SgpTeam, names: &GameDataNames) -> RecordTeam {
+    let objectives = &team.objectives;
+    RecordTeam {
+        team_id: team.team_id as i32,
+        win: team.win,
+        bans: team
+            .bans
+            .iter()
+            // 没有禁用时为 -1
+            .filter(|ban| ban.champion_id > 0)
+            .map(|ban| RecordBan {
+                champion: names.champion(ban.champion_id),
+                pick_turn: ban.pick_turn,
+            })
+            .collect(),
+        objectives: TeamObjectives {
+            champion: objective(&objectives.champion),
+            tower: objective(&objectives.tower),
+            inhibitor: objective(&objectives.inhibitor),
+            dragon: objective(&objectives.dragon),
+            rift_herald: objective(&objectives.rift_herald),
+            horde: objective(&objectives.horde),
+            baron: objective(&objectives.baron),
+            atakhan: objectives
+                .atakhan
+                .as_ref()
+                .map(objective)
+                .unwrap_or_default(),
+        },
     }
+}
 
-    if team_total_healing > 0.0 {
-        record.stats.healing_share = record.stats.healing as f64 / team_total_healing;
-    } else {
-        record.stats.healing_share = 0.0;
+fn parse_runes(sgp_participant: &SgpParticipant, names: &GameDataNames) -> Runes {
+    let styles = &sgp_participant.perks.styles;
+    let (Some(primary), Some(sub)) = (styles.first(), styles.get(1)) else {
+        return Runes::default();
+    };
+    let stat_perks = &sgp_participant.perks.stat_perks;
+    Runes {
+        primary_style: names.perk_style(primary.style),
+        sub_style: names.perk_style(sub.style),
+        selections: primary
+            .selections
+            .iter()
+            .chain(&sub.selections)
+            .map(|selection| names.perk(selection.perk))
+            .collect(),
+        stat_perks: vec![stat_perks.offense, stat_perks.flex, stat_perks.defense],
     }
+}
 
-    record
+fn parse_participant(sgp_participant: &SgpParticipant, names: &GameDataNames) -> Participant {
+    let runes = parse_runes(sgp_participant, names);
+    // 基石符文和副系第一个符文
+    let perks = match (
+        sgp_participant.perks.styles.first(),
+        sgp_participant.perks.styles.get(1),
+    ) {
+        (Some(primary), Some(sub)) => primary
+            .selections
+            .first()
+            .into_iter()
+            .chain(sub.selections.first())
+            .map(|selection| names.perk(selection.perk))
+            .collect(),
+        _ => vec![],
+    };
+    let kills = sgp_participant.kills;
+    let deaths = sgp_participant.deaths;
+    let assists = sgp_participant.assists;
+
+    Participant {
+        puuid: sgp_participant.puuid.clone(),
+        name: sgp_participant.riot_id_game_name.clone(),
+        tag: sgp_participant.riot_id_tagline.clone(),
+        team_id: sgp_participant.team_id as i32,
+        win: sgp_participant.win,
+        lane: sgp_participant.lane.clone(),
+        subteam_id: sgp_participant.player_subteam_id,
+        placement: placement_of(sgp_participant),
+        augments: augments_of(sgp_participant)
+            .into_iter()
+            .map(|augment_id| names.augment(augment_id))
+            .collect(),
+        champion: names.champion(sgp_participant.champion_id),
+        champion_level: sgp_participant.champ_level,
+        spells: vec![
+            names.spell(sgp_participant.spell1id),
+            names.spell(sgp_participant.spell2id),
+        ],
+        perks,
+        runes,
+        items: [
+            sgp_participant.item0,
+            sgp_participant.item1,
+            sgp_participant.item2,
+            sgp_participant.item3,
+            sgp_participant.item4,
+            sgp_participant.item5,
+            sgp_participant.item6,
+        ]
+        .into_iter()
+        .map(|item_id| names.item(item_id))
+        .collect(),
+        // percentage 需要按队伍汇总后计算
+        damage_to_turrets: sgp_participant.damage_dealt_to_turrets,
+        damage_to_champions: sgp_participant.total_damage_dealt_to_champions,
+        damage_taken: sgp_participant.total_damage_taken,
+        heal: sgp_participant.total_heal,
+        gold_earned: sgp_participant.gold_earned,
+        cs: sgp_participant.total_minions_killed + sgp_participant.neutral_minions_killed,
+        vision_score: sgp_participant.vision_score,
+        kills,
+        deaths,
+        assists,
+        kda: (kills + assists) as f64 / deaths.max(1) as f64,
+        ..Default::default()
+    }
 }
 
-pub async fn get_record_list(
+/// 把对局转换成战绩记录，位置、对位、评分和占比都在这里计算
+///
+/// # 参数
+/// - `puuid`: 查看战绩的玩家
+pub fn build_record(
+    game: &Games,
     puuid: &str,
-    beg_index: Option<i32>,
-    end_index: Option<i32>,
-) -> Result<Vec<RecordItem>, String> {
-    let client = match get_lcu_client().await {
-        Ok(client) => client,
-        Err(e) => return Err(e.to_string()),
+    names: &GameDataNames,
+    score_config: &ScoreConfig,
+) -> RecordItem {
+    let json = &game.json;
+    let mut record = RecordItem {
+        version: RECORD_VERSION,
+        game_id: game.metadata.match_id.clone(),
+        puuid: puuid.to_string(),
+        game_creation: json.game_creation,
+        duration: json.game_duration as i32,
+        queue_id: json.queue_id as i32,
+        game_mode: json.game_mode.clone(),
+        game_version: json.game_version.clone(),
+        platform_id: json.platform_id.clone(),
+        is_arena: is_arena(game),
+        teams: json
+            .teams
+            .iter()
+            .map(|team| parse_team(team, names))
+            .collect(),
+        participants: json
+            .participants
+            .iter()
+            .map(|participant| parse_participant(participant, names))
+            .collect(),
     };
 
-    let match_history = client
-        .match_history
-        .get_match_history(puuid, beg_index, end_index)
-        .await
-        .expect("获取比赛历史失败");
+    // 判定位置和对位对手
+    let roles = resolve_roles(&json.participants);
+    let opponents = lane_opponents(&json.participants, &roles);
+    for ((participant, role), opponent) in record.participants.iter_mut().zip(roles).zip(opponents)
+    {
+        participant.role = role;
+        participant.lane_opponent = opponent.map(|index| json.participants[index].puuid.clone());
+    }
+
+    // 计算评分并标记 MVP / ACE
+    let scores = score_sgp_game(game, score_config);
+    for (participant, result) in record.participants.iter_mut().zip(scores) {
+        participant.score = result.score;
+        participant.best = result.badge.is_some();
+        participant.badge = result.badge;
+    }
+
+    // 按队伍（斗魂竞技场按小队）汇总 damage to turrets, damage to champions, damage taken, heal
+    let is_arena = record.is_arena;
+    let group_of = |participant: &Participant| {
+        if is_arena {
+            participant.subteam_id
+        } else {
+            participant.team_id as i64
+        }
+    };
+    let mut team_totals: HashMap<i64, [i64; 4]> = HashMap::new();
+    for participant in &record.participants {
+        let totals = team_totals.entry(group_of(participant)).or_default();
+        totals[0] += participant.damage_to_turrets;
+        totals[1] += participant.damage_to_champions;
+        totals[2] += participant.damage_taken;
+        totals[3] += participant.heal;
+    }
 
-    let futures: Vec<_> = match_history
-        .games
-        .games
-        .iter()
-        .map(|game| parse_game(&client, game, puuid))
-        .collect();
+    let share = |value: i64, total: i64| {
+        if total > 0 {
+            value as f64 / total as f64
+        } else {
+            0.0
+        }
+    };
+    for participant in &mut record.participants {
+        let totals = team_totals[&group_of(participant)];
+        participant.damage_to_turrets_percentage = share(participant.damage_to_turrets, totals[0]);
+        participant.damage_to_champions_percentage =
+            share(participant.damage_to_champions, totals[1]);
+        participant.damage_taken_percentage = share(participant.damage_taken, totals[2]);
+        participant.heal_percentage = share(participant.heal, totals[3]);
+    }
 
-    let record_list = join_all(futures).await;
+    record
+}
+
+/// 把 SGP 的对局转换成战绩记录
+pub async fn record_from_sgp(game: &Games, puuid: &str) -> RecordItem {
+    let names = GameDataNames::load().await;
+    let score_config = get_score_config().await;
+    build_record(game, puuid, &names, &score_config)
+}
 
-    Ok(record_list)
+/// 把 LCU 的对局详情转换成战绩记录
+pub async fn record_from_lcu(game: &LcuGame, puuid: &str) -> RecordItem {
+    record_from_sgp(&game_from_lcu(game), puuid).await
 }
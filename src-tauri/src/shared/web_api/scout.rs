@@ -0,0 +1,149 @@
+use crate::shared::analysis::indicator::{compute_indicators, Indicator};
+use crate::shared::analysis::performance::{
+    aggregate_performance, fill_champion_names, PerformanceReport,
+};
+use crate::shared::http_api::lcu::LcuApi;
+use crate::shared::init::game_data::{get_champion_info_cache, is_cache_initialized};
+use crate::shared::store::filter::MatchFilter;
+use crate::shared::store::match_store::get_player_games;
+use crate::shared::types::league_client::champion_mastery::Mastery;
+use crate::shared::web_api::info::{FavoriteHero, RankInfo};
+use crate::shared::web_api::record_sgp::sync_match_history;
+use crate::utils::config::indicator::get_indicator_config;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use tracing::warn;
+
+/// 拉取的最近对局数
+const RECENT_GAMES: usize = 20;
+/// 返回的英雄成就数量
+const TOP_MASTERIES: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScoutSource {
+    Summoner,
+    Ranked,
+    Mastery,
+    History,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoutError {
+    pub source: ScoutSource,
+    pub message: String,
+}
+
+/// 玩家侦察结果，某一项获取失败时对应字段为空并记录在 `errors` 中
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerScout {
+    pub puuid: String,
+    pub game_name: String,
+    pub tag_line: String,
+    pub summoner_level: i64,
+    pub profile_icon_id: i64,
+    pub solo_rank: Option<RankInfo>,
+    pub flex_rank: Option<RankInfo>,
+    pub masteries: Vec<FavoriteHero>,
+    /// 最近对局的表现
+    pub recent: Option<PerformanceReport>,
+    /// 连胜/连败、近期状态、疑似小号等提示
+    pub indicators: Vec<Indicator>,
+    pub errors: Vec<ScoutError>,
+}
+
+async fn recent_form(puuid: &str) -> Result<(PerformanceReport, Vec<Indicator>), String> {
+    // 拉取失败时仍然使用本地已有的对局
+    let synced = sync_match_history(puuid, 0, RECENT_GAMES as i32).await;
+    let games = get_player_games(puuid).await.map_err(|e| e.to_string())?;
+    if let Err(e) = synced {
+        if games.is_empty() {
+            return Err(e);
+        }
+        warn!("拉取 {} 的战绩失败，使用本地数据: {}", puuid, e);
+    }
+    let mut report =
+        aggregate_performance(&games, puuid, &MatchFilter::default(), Some(RECENT_GAMES));
+    fill_champion_names(&mut report).await;
+    let recent: Vec<_> = games.into_iter().take(RECENT_GAMES).collect();
+    let config = get_indicator_config().await;
+    let indicators = compute_indicators(&recent, puuid, &config);
+    Ok((report, indicators))
+}
+
+/// 并发获取玩家的召唤师信息、段位、英雄成就和最近对局
+///
+/// 各项之间互不影响，失败的项记录在返回值的 `errors` 中。
+pub async fn scout_player(client: &LcuApi, puuid: &str) -> PlayerScout {
+    scout_player_with_masteries(client, puuid).await.0
+}
+
+/// 同 [`scout_player`]，同时返回玩家全部的英雄成就，供需要查询指定英雄成就的调用方使用
+///
+/// 英雄成就获取失败时返回空列表。
+pub async fn scout_player_with_masteries(
+    client: &LcuApi,
+    puuid: &str,
+) -> (PlayerScout, Vec<Mastery>) {
+    let (summoner, ranked, masteries, recent) = tokio::join!(
+        client.summoner.get_summoner_by_puuid(puuid),
+        client.ranked.get_ranked_stats(puuid),
+        client.champion_mastery.get_player_champion_mastery(puuid),
+        recent_form(puuid),
+    );
+
+    let mut scout = PlayerScout {
+        puuid: puuid.to_string(),
+        ..Default::default()
+    };
+    let mut fail = |source: ScoutSource, message: String| {
+        warn!("获取 {} 的 {:?} 失败: {}", puuid, source, message);
+        scout.errors.push(ScoutError { source, message });
+    };
+
+    let summoner = summoner.map_err(|e| fail(ScoutSource::Summoner, e.to_string()));
+    let ranked = ranked.map_err(|e| fail(ScoutSource::Ranked, e.to_string()));
+    let masteries = masteries.map_err(|e| fail(ScoutSource::Mastery, e.to_string()));
+    let recent = recent.map_err(|e| fail(ScoutSource::History, e));
+
+    if let Ok(summoner) = summoner {
+        scout.game_name = summoner.game_name;
+        scout.tag_line = summoner.tag_line;
+        scout.summoner_level = summoner.summoner_level;
+        scout.profile_icon_id = summoner.profile_icon_id;
+    }
+    if let Ok(ranked) = ranked {
+        scout.solo_rank = Some(RankInfo::from(&ranked.queue_map.ranked_solo_5x5));
+        scout.flex_rank = Some(RankInfo::from(&ranked.queue_map.ranked_flex_sr));
+    }
+    let masteries = masteries.unwrap_or_default();
+    if !masteries.is_empty() {
+        let champion_cache = if is_cache_initialized().await {
+            Some(get_champion_info_cache().await)
+        } else {
+            None
+        };
+        let mut top: Vec<&Mastery> = masteries.iter().collect();
+        top.sort_by_key(|mastery| Reverse(mastery.champion_points));
+        scout.masteries = top
+            .into_iter()
+            .take(TOP_MASTERIES)
+            .map(|mastery| FavoriteHero {
+                champion_id: mastery.champion_id,
+                champion_name: champion_cache
+                    .as_ref()
+                    .and_then(|cache| cache.get(&mastery.champion_id))
+                    .map(|champion| champion.name.clone())
+                    .unwrap_or_default(),
+                matches: mastery.champion_points,
+            })
+            .collect();
+    }
+    if let Ok((report, indicators)) = recent {
+        scout.recent = Some(report);
+        scout.indicators = indicators;
+    }
+    (scout, masteries)
+}
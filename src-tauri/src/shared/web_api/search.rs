@@ -0,0 +1,183 @@
+use crate::shared::constants::sgp::{
+    SERVER_NAMES_EN, SERVER_NAMES_ZH_CN, TENCENT_SERVER_SUMMONER_INTEROPERABILITY,
+};
+use crate::shared::http_api::sgp::SgpService;
+use crate::shared::init::lcu::get_lcu_client;
+use crate::shared::init::sgp::{get_sgp_client, get_sgp_client_for};
+use futures::future::join_all;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// 玩家搜索结果，同一个账号在每个有召唤师的服务器上各有一条
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerCandidate {
+    pub puuid: String,
+    pub game_name: String,
+    pub tag_line: String,
+    /// 如 TENCENT_HN1
+    pub server_id: String,
+    pub server_name: String,
+    pub server_name_en: String,
+    pub is_current_server: bool,
+    pub summoner_level: i64,
+    pub profile_icon_id: i64,
+}
+
+/// 可以搜索的服务器
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchServer {
+    pub server_id: String,
+    pub server_name: String,
+    pub server_name_en: String,
+    pub is_current_server: bool,
+}
+
+/// 解析 `GameName#Tag`，名称中可以包含 `#`，以最后一个为准
+fn parse_riot_id(riot_id: &str) -> Result<(String, String), String> {
+    let (game_name, tag_line) = riot_id
+        .rsplit_once('#')
+        .ok_or_else(|| format!("无效的 Riot ID: {}，格式应为 名称#编号", riot_id))?;
+    let (game_name, tag_line) = (game_name.trim(), tag_line.trim());
+    if game_name.is_empty() || tag_line.is_empty() {
+        return Err(format!("无效的 Riot ID: {}，格式应为 名称#编号", riot_id));
+    }
+    Ok((game_name.to_string(), tag_line.to_string()))
+}
+
+fn server_names(server_id: &str) -> (String, String) {
+    (
+        SERVER_NAMES_ZH_CN
+            .get(server_id)
+            .map_or_else(|| server_id.to_string(), |name| name.to_string()),
+        SERVER_NAMES_EN
+            .get(server_id)
+            .map_or_else(|| server_id.to_string(), |name| name.to_string()),
+    )
+}
+
+/// 要搜索的服务器：指定了服务器时只搜索该服务器，否则搜索当前服务器和所有可以互通的服务器
+fn target_servers(current: &str, server: Option<&str>) -> Result<Vec<String>, String> {
+    let interoperable = TENCENT_SERVER_SUMMONER_INTEROPERABILITY.contains(&current);
+    match server {
+        Some(server) if server == current => Ok(vec![current.to_string()]),
+        Some(server) => {
+            if interoperable && TENCENT_SERVER_SUMMONER_INTEROPERABILITY.contains(&server) {
+                Ok(vec![server.to_string()])
+            } else {
+                Err(format!("无法从 {} 查询 {} 的玩家", current, server))
+            }
+        }
+        None if interoperable => {
+            let mut servers = vec![current.to_string()];
+            servers.extend(
+                TENCENT_SERVER_SUMMONER_INTEROPERABILITY
+                    .iter()
+                    .filter(|server| **server != current)
+                    .map(|server| server.to_string()),
+            );
+            Ok(servers)
+        }
+        None => Ok(vec![current.to_string()]),
+    }
+}
+
+/// 获取可以搜索的服务器，当前服务器在前
+pub async fn get_search_servers() -> Result<Vec<SearchServer>, String> {
+    let sgp = get_sgp_client().await.map_err(|e| e.to_string())?;
+    let current = sgp.server_id();
+    Ok(target_servers(current, None)?
+        .into_iter()
+        .map(|server_id| {
+            let (server_name, server_name_en) = server_names(&server_id);
+            SearchServer {
+                is_current_server: server_id == current,
+                server_id,
+                server_name,
+                server_name_en,
+            }
+        })
+        .collect())
+}
+
+/// 查询账号在其他服务器上的召唤师，没有召唤师或查询失败时返回 `None`
+async fn find_on_server(server_id: &str, puuid: &str) -> Option<(i64, i64)> {
+    let client = match get_sgp_client_for(Some(server_id), SgpService::Summoner).await {
+        Ok(client) => client,
+        Err(e) => {
+            warn!("{}", e);
+            return None;
+        }
+    };
+    match client.get_summoner_by_puuid(puuid).await {
+        Ok(summoners) => summoners
+            .into_iter()
+            .next()
+            .map(|summoner| (summoner.level, summoner.profile_icon_id)),
+        Err(e) => {
+            warn!("在 {} 查询召唤师失败: {}", server_id, e);
+            None
+        }
+    }
+}
+
+/// 按 Riot ID 搜索玩家
+///
+/// 先通过当前服务器的别名接口找到账号，再在可以互通的服务器上查询该账号的召唤师。
+///
+/// # 参数
+/// - `riot_id`: `GameName#Tag`
+/// - `server`: 只搜索该服务器（如 `TENCENT_HN1`），为空时搜索所有可以查询的服务器
+pub async fn search_players(
+    riot_id: &str,
+    server: Option<&str>,
+) -> Result<Vec<PlayerCandidate>, String> {
+    let (game_name, tag_line) = parse_riot_id(riot_id)?;
+    let lcu = get_lcu_client().await.map_err(|e| e.to_string())?;
+    let sgp = get_sgp_client().await.map_err(|e| e.to_string())?;
+    let current = sgp.server_id().to_string();
+    let servers = target_servers(&current, server)?;
+
+    let Some(summoner) = lcu
+        .summoner
+        .get_summoner_alias(&game_name, &tag_line)
+        .await
+        .map_err(|e| e.to_string())?
+    else {
+        return Ok(vec![]);
+    };
+
+    let found = join_all(servers.iter().map(|server_id| {
+        let summoner = &summoner;
+        let current = &current;
+        async move {
+            if server_id == current {
+                Some((summoner.summoner_level, summoner.profile_icon_id))
+            } else {
+                find_on_server(server_id, &summoner.puuid).await
+            }
+        }
+    }))
+    .await;
+
+    Ok(servers
+        .into_iter()
+        .zip(found)
+        .filter_map(|(server_id, found)| {
+            let (summoner_level, profile_icon_id) = found?;
+            let (server_name, server_name_en) = server_names(&server_id);
+            Some(PlayerCandidate {
+                puuid: summoner.puuid.clone(),
+                game_name: summoner.game_name.clone(),
+                tag_line: summoner.tag_line.clone(),
+                is_current_server: server_id == current,
+                server_id,
+                server_name,
+                server_name_en,
+                summoner_level,
+                profile_icon_id,
+            })
+        })
+        .collect())
+}
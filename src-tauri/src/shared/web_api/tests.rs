@@ -0,0 +1,154 @@
+use crate::shared::analysis::role::{resolve_roles, Role, SMITE_SPELL_ID};
+use crate::shared::types::league_client::match_history::{
+    Game as LcuGame, Participant as LcuParticipant, ParticipantIdentity, Team as LcuTeam,
+};
+use crate::shared::web_api::history_provider::game_from_lcu;
+use crate::shared::web_api::record::{build_record, GameDataNames, RECORD_VERSION};
+use crate::utils::config::score::ScoreConfig;
+
+/// 一支五人队伍的 LCU 对局详情，位置只记录在 timeline 中
+fn fixture_lcu_game() -> LcuGame {
+    // (lane, role, spell1, perk_primary_style)
+    let lines = [
+        ("TOP", "SOLO", 4, 8000),
+        ("JUNGLE", "NONE", SMITE_SPELL_ID as i32, 8100),
+        ("MIDDLE", "SOLO", 4, 8200),
+        ("BOTTOM", "DUO_CARRY", 4, 8000),
+        ("BOTTOM", "DUO_SUPPORT", 4, 8400),
+    ];
+    let mut game = LcuGame {
+        game_id: 42,
+        platform_id: "HN1".to_string(),
+        game_creation: 1000,
+        game_duration: 1800,
+        queue_id: 420,
+        game_mode: "CLASSIC".to_string(),
+        ..Default::default()
+    };
+    for (index, (lane, role, spell1, style)) in lines.iter().enumerate() {
+        let mut participant = LcuParticipant {
+            participant_id: index as i32 + 1,
+            team_id: 100,
+            champion_id: index as i32 + 1,
+            spell1_id: *spell1,
+            spell2_id: 14,
+            ..Default::default()
+        };
+        participant.timeline.lane = lane.to_string();
+        participant.timeline.role = role.to_string();
+        participant.stats.kills = 2;
+        participant.stats.perk_primary_style = *style;
+        participant.stats.perk0 = 8005;
+        participant.stats.perk4 = 8226;
+        participant.stats.win = true;
+        game.participants.push(participant);
+
+        let mut identity = ParticipantIdentity {
+            participant_id: index as i32 + 1,
+            ..Default::default()
+        };
+        identity.player.puuid = format!("p{}", index);
+        identity.player.game_name = format!("name{}", index);
+        game.participant_identities.push(identity);
+    }
+    game.teams.push(LcuTeam {
+        team_id: 100,
+        win: "Win".to_string(),
+        tower_kills: 9,
+        bans: vec![serde_json::json!({"championId": 7, "pickTurn": 1})],
+        ..Default::default()
+    });
+
+    game
+}
+
+#[test]
+fn test_game_from_lcu_maps_into_sgp_model() {
+    let game = fixture_lcu_game();
+    let games = game_from_lcu(&game);
+    assert_eq!(games.metadata.match_id, "HN1_42");
+    assert_eq!(games.metadata.participants, ["p0", "p1", "p2", "p3", "p4"]);
+    assert_eq!(games.json.game_end_timestamp, 1000 + 1800 * 1000);
+
+    let participants = &games.json.participants;
+    assert_eq!(participants[4].riot_id_game_name, "name4");
+    assert_eq!(participants[4].individual_position, "UTILITY");
+    assert_eq!(participants[0].perks.styles[0].style, 8000);
+    assert_eq!(participants[0].perks.styles[0].selections[0].perk, 8005);
+    assert_eq!(participants[0].perks.styles[1].selections[0].perk, 8226);
+    assert_eq!(
+        resolve_roles(participants),
+        vec![
+            Some(Role::Top),
+            Some(Role::Jungle),
+            Some(Role::Mid),
+            Some(Role::Bottom),
+            Some(Role::Utility),
+        ]
+    );
+
+    let team = &games.json.teams[0];
+    assert!(team.win);
+    assert_eq!(team.objectives.tower.kills, 9);
+    assert_eq!(team.objectives.champion.kills, 10);
+    assert_eq!(team.bans[0].champion_id, 7);
+}
+
+#[test]
+fn test_build_record_from_lcu_game() {
+    let game = game_from_lcu(&fixture_lcu_game());
+    let record = build_record(
+        &game,
+        "p0",
+        &GameDataNames::default(),
+        &ScoreConfig::default(),
+    );
+
+    assert_eq!(record.version, RECORD_VERSION);
+    assert_eq!(record.game_id, "HN1_42");
+    assert_eq!(record.puuid, "p0");
+    assert_eq!(record.platform_id, "HN1");
+    assert_eq!(record.participants.len(), 5);
+
+    let team = &record.teams[0];
+    assert!(team.win);
+    assert_eq!(team.objectives.tower.kills, 9);
+    assert_eq!(team.bans[0].champion.id, 7);
+    assert_eq!(team.bans[0].champion.name, "未知英雄(7)");
+
+    let me = &record.participants[0];
+    assert_eq!(me.role, Some(Role::Top));
+    assert_eq!(me.lane_opponent, None);
+    assert_eq!(me.items.len(), 7);
+    assert_eq!(me.items[0].name, "无装备");
+    assert_eq!(me.runes.primary_style.id, 8000);
+    assert_eq!(me.runes.selections.len(), 6);
+    assert_eq!(
+        me.perks.iter().map(|perk| perk.id).collect::<Vec<_>>(),
+        [8005, 8226]
+    );
+    assert_eq!(me.kda, 2.0);
+    // 没有伤害数据时占比为 0
+    assert_eq!(me.damage_to_champions_percentage, 0.0);
+    assert_eq!(
+        record
+            .participants
+            .iter()
+            .filter(|participant| participant.badge.is_some())
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_build_record_without_game_data() {
+    // 客户端未连接时游戏数据缓存未初始化，名称显示为“未知”而不是 panic
+    let names = futures::executor::block_on(GameDataNames::load());
+    let game = game_from_lcu(&fixture_lcu_game());
+    let record = build_record(&game, "p0", &names, &ScoreConfig::default());
+
+    let me = &record.participants[0];
+    assert!(me.champion.name.starts_with("未知英雄"));
+    assert!(me.spells.iter().all(|spell| spell.name.starts_with("未知")));
+    assert_eq!(record.teams[0].bans[0].champion.name, "未知英雄(7)");
+}
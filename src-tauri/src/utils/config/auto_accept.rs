@@ -0,0 +1,54 @@
+use crate::utils::config::cell::ConfigCell;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Arc;
+
+/// 配置文件名，位于应用配置目录下
+pub const AUTO_ACCEPT_CONFIG_FILE: &str = "auto_accept.json";
+/// 就绪检查大约 12 秒后超时，延迟不能超过这个值
+pub const MAX_ACCEPT_DELAY_MS: u64 = 9000;
+
+/// 自动接受对局的设置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AutoAcceptConfig {
+    /// 是否自动响应就绪检查
+    pub enabled: bool,
+    /// 就绪检查开始后等待多久再接受，期间可以取消
+    pub delay_ms: u64,
+    /// 暂时离开，开启后自动拒绝就绪检查
+    pub away: bool,
+}
+
+impl Default for AutoAcceptConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            delay_ms: 2000,
+            away: false,
+        }
+    }
+}
+
+impl AutoAcceptConfig {
+    pub fn delay_ms(&self) -> u64 {
+        self.delay_ms.min(MAX_ACCEPT_DELAY_MS)
+    }
+}
+
+static AUTO_ACCEPT_CONFIG: ConfigCell<AutoAcceptConfig> = ConfigCell::new();
+
+/// 读取自动接受配置，文件不存在时写入默认配置，解析失败时使用默认配置
+pub async fn init_auto_accept_config(path: &Path) {
+    AUTO_ACCEPT_CONFIG.init(path).await;
+}
+
+/// 写入文件并替换内存中的配置，下一次就绪检查开始生效
+pub async fn save_auto_accept_config(path: &Path, config: AutoAcceptConfig) -> std::io::Result<()> {
+    AUTO_ACCEPT_CONFIG.save(path, config).await
+}
+
+/// 获取自动接受配置，未初始化时返回默认配置
+pub async fn get_auto_accept_config() -> Arc<AutoAcceptConfig> {
+    AUTO_ACCEPT_CONFIG.get().await
+}
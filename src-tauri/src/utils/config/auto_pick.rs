@@ -0,0 +1,75 @@
+use crate::utils::config::cell::ConfigCell;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+
+/// 配置文件名，位于应用配置目录下
+pub const AUTO_PICK_CONFIG_FILE: &str = "auto_pick.json";
+/// 位置没有单独配置或没有分路的模式使用的列表
+pub const DEFAULT_POSITION: &str = "default";
+
+/// 自动选择和禁用英雄的设置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AutoPickConfig {
+    /// 轮到自己选择时自动预选并锁定
+    pub auto_pick: bool,
+    /// 轮到自己禁用时自动预选并锁定
+    pub auto_ban: bool,
+    /// 在操作倒计时结束前多少毫秒锁定，期间可以手动修改
+    pub lock_before_end_ms: u64,
+    /// 位置（top / jungle / middle / bottom / utility / default） -> 英雄 ID，优先级从高到低
+    pub picks: BTreeMap<String, Vec<i32>>,
+    /// 同上，禁用列表
+    pub bans: BTreeMap<String, Vec<i32>>,
+}
+
+impl Default for AutoPickConfig {
+    fn default() -> Self {
+        Self {
+            auto_pick: false,
+            auto_ban: false,
+            lock_before_end_ms: 3000,
+            picks: BTreeMap::new(),
+            bans: BTreeMap::new(),
+        }
+    }
+}
+
+impl AutoPickConfig {
+    fn list_for<'a>(lists: &'a BTreeMap<String, Vec<i32>>, position: &str) -> &'a [i32] {
+        lists
+            .get(&position.to_lowercase())
+            .filter(|list| !list.is_empty())
+            .or_else(|| lists.get(DEFAULT_POSITION))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// 位置的选择列表，没有配置时使用 default 列表
+    pub fn picks_for(&self, position: &str) -> &[i32] {
+        Self::list_for(&self.picks, position)
+    }
+
+    /// 位置的禁用列表，没有配置时使用 default 列表
+    pub fn bans_for(&self, position: &str) -> &[i32] {
+        Self::list_for(&self.bans, position)
+    }
+}
+
+static AUTO_PICK_CONFIG: ConfigCell<AutoPickConfig> = ConfigCell::new();
+
+/// 读取自动选择配置，文件不存在时写入默认配置，解析失败时使用默认配置
+pub async fn init_auto_pick_config(path: &Path) {
+    AUTO_PICK_CONFIG.init(path).await;
+}
+
+/// 写入文件并替换内存中的配置，下一次轮到自己操作时生效
+pub async fn save_auto_pick_config(path: &Path, config: AutoPickConfig) -> std::io::Result<()> {
+    AUTO_PICK_CONFIG.save(path, config).await
+}
+
+/// 获取自动选择配置，未初始化时返回默认配置
+pub async fn get_auto_pick_config() -> Arc<AutoPickConfig> {
+    AUTO_PICK_CONFIG.get().await
+}
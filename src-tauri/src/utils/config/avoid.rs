@@ -0,0 +1,41 @@
+use crate::utils::config::cell::ConfigCell;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Arc;
+
+/// 配置文件名，位于应用配置目录下
+pub const AVOID_CONFIG_FILE: &str = "avoid.json";
+
+/// 回避名单提醒的设置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AvoidConfig {
+    /// 是否检查英雄选择和对局中的玩家
+    pub enabled: bool,
+    /// 是否同时在客户端中弹出通知
+    pub client_notification: bool,
+    /// 是否请求任务栏闪烁提醒
+    pub request_attention: bool,
+}
+
+impl Default for AvoidConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            client_notification: false,
+            request_attention: true,
+        }
+    }
+}
+
+static AVOID_CONFIG: ConfigCell<AvoidConfig> = ConfigCell::new();
+
+/// 读取回避提醒配置，文件不存在时写入默认配置，解析失败时使用默认配置
+pub async fn init_avoid_config(path: &Path) {
+    AVOID_CONFIG.init(path).await;
+}
+
+/// 获取回避提醒配置，未初始化时返回默认配置
+pub async fn get_avoid_config() -> Arc<AvoidConfig> {
+    AVOID_CONFIG.get().await
+}
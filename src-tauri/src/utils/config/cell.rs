@@ -0,0 +1,89 @@
+//! JSON 配置文件的读写和内存中的配置
+//!
+//! 每种配置一个文件，启动时读取到 [`ConfigCell`] 中，之后通过 `get` 获取。
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::warn;
+
+/// 读取配置，文件不存在时写入默认配置，读取或解析失败时使用默认配置
+pub fn load_config<T: Serialize + DeserializeOwned + Default>(path: &Path) -> T {
+    if !path.exists() {
+        let default = T::default();
+        if let Err(e) = save_config(path, &default) {
+            warn!("创建默认配置 {} 失败: {}", path.display(), e);
+        }
+        return default;
+    }
+
+    match fs::read_to_string(path).map(|content| serde_json::from_str(&content)) {
+        Ok(Ok(config)) => config,
+        Ok(Err(e)) => {
+            warn!("解析配置 {} 失败: {}, 使用默认配置", path.display(), e);
+            T::default()
+        }
+        Err(e) => {
+            warn!("读取配置 {} 失败: {}, 使用默认配置", path.display(), e);
+            T::default()
+        }
+    }
+}
+
+pub fn save_config<T: Serialize>(path: &Path, config: &T) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let content = serde_json::to_string_pretty(config)?;
+    fs::write(path, content)?;
+    Ok(())
+}
+
+/// 内存中的一份配置，未初始化时使用默认配置
+pub struct ConfigCell<T> {
+    value: RwLock<Option<Arc<T>>>,
+}
+
+impl<T> ConfigCell<T> {
+    pub const fn new() -> Self {
+        Self {
+            value: RwLock::const_new(None),
+        }
+    }
+}
+
+impl<T> Default for ConfigCell<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Serialize + DeserializeOwned + Default> ConfigCell<T> {
+    /// 从文件读取配置
+    pub async fn init(&self, path: &Path) {
+        self.set(load_config(path)).await;
+    }
+
+    /// 写入文件并替换内存中的配置
+    pub async fn save(&self, path: &Path, config: T) -> std::io::Result<()> {
+        save_config(path, &config)?;
+        self.set(config).await;
+        Ok(())
+    }
+
+    pub async fn set(&self, config: T) {
+        *self.value.write().await = Some(Arc::new(config));
+    }
+
+    pub async fn get(&self) -> Arc<T> {
+        self.value
+            .read()
+            .await
+            .as_ref()
+            .map(Arc::clone)
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,77 @@
+use crate::utils::config::cell::ConfigCell;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Arc;
+
+/// 配置文件名，位于应用配置目录下
+pub const INDICATOR_CONFIG_FILE: &str = "indicator.json";
+
+/// 玩家状态指标的阈值
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct IndicatorConfig {
+    /// 连胜/连败达到该局数时提示
+    pub streak_length: usize,
+    /// 近期状态统计的局数，与全部对局对比
+    pub recent_games: usize,
+    /// 至少有这么多局才计算近期状态、重开和炸鱼指标
+    pub min_games: usize,
+    /// 近期 KDA 相对整体变化超过该比例时提示状态火热/低迷
+    pub form_kda_delta: f64,
+    /// 对局时长（秒）低于该值视为重开
+    pub remake_duration: i64,
+    /// 重开局占比达到该值时提示
+    pub remake_rate: f64,
+    /// 重开局至少达到该局数才提示
+    pub min_remakes: usize,
+    /// 投降结束的对局时长（秒）低于该值视为提前投降
+    pub early_surrender_duration: i64,
+    /// 提前投降输掉的对局占比达到该值时提示
+    pub early_surrender_rate: f64,
+    /// 提前投降输掉的对局至少达到该局数才提示
+    pub min_early_surrenders: usize,
+    /// 伤害占比和经济占比同时低于对应值视为消极对局
+    pub low_damage_share: f64,
+    pub low_gold_share: f64,
+    /// 消极对局达到该局数时提示
+    pub low_effort_games: usize,
+    /// 召唤师等级不高于该值且胜率、KDA 都达到阈值时提示疑似小号
+    pub smurf_max_level: i64,
+    pub smurf_win_rate: f64,
+    pub smurf_kda: f64,
+}
+
+impl Default for IndicatorConfig {
+    fn default() -> Self {
+        Self {
+            streak_length: 3,
+            recent_games: 5,
+            min_games: 8,
+            form_kda_delta: 0.35,
+            remake_duration: 300,
+            remake_rate: 0.15,
+            min_remakes: 2,
+            early_surrender_duration: 1200,
+            early_surrender_rate: 0.25,
+            min_early_surrenders: 2,
+            low_damage_share: 0.08,
+            low_gold_share: 0.12,
+            low_effort_games: 2,
+            smurf_max_level: 60,
+            smurf_win_rate: 0.7,
+            smurf_kda: 4.0,
+        }
+    }
+}
+
+static INDICATOR_CONFIG: ConfigCell<IndicatorConfig> = ConfigCell::new();
+
+/// 读取指标配置，文件不存在时写入默认配置，解析失败时使用默认配置
+pub async fn init_indicator_config(path: &Path) {
+    INDICATOR_CONFIG.init(path).await;
+}
+
+/// 获取指标配置，未初始化时返回默认配置
+pub async fn get_indicator_config() -> Arc<IndicatorConfig> {
+    INDICATOR_CONFIG.get().await
+}
@@ -1,3 +1,10 @@
+pub mod auto_accept;
+pub mod auto_pick;
+pub mod avoid;
+pub mod cell;
+pub mod indicator;
+pub mod score;
+
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
@@ -0,0 +1,87 @@
+use crate::utils::config::cell::ConfigCell;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+/// 配置文件名，位于应用配置目录下
+pub const SCORE_CONFIG_FILE: &str = "score.json";
+
+/// 评分各项指标的权重
+///
+/// 权重只表示相对大小，计算时会按总和归一化，因此不要求加起来等于 1。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ScoreWeights {
+    pub kda: f64,
+    pub kill_participation: f64,
+    pub damage_share: f64,
+    pub gold_share: f64,
+    pub vision_share: f64,
+    pub objective_damage: f64,
+    pub cs_per_minute: f64,
+}
+
+impl Default for ScoreWeights {
+    fn default() -> Self {
+        Self {
+            kda: 0.25,
+            kill_participation: 0.2,
+            damage_share: 0.2,
+            gold_share: 0.1,
+            vision_share: 0.1,
+            objective_damage: 0.1,
+            cs_per_minute: 0.05,
+        }
+    }
+}
+
+/// 评分配置：默认权重 + 按游戏模式（`game_mode`，如 `CLASSIC`、`ARAM`）覆盖的权重
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ScoreConfig {
+    pub default: ScoreWeights,
+    pub profiles: HashMap<String, ScoreWeights>,
+}
+
+impl Default for ScoreConfig {
+    fn default() -> Self {
+        let mut profiles = HashMap::new();
+        // 大乱斗没有视野和野区资源，补刀也不重要
+        profiles.insert(
+            "ARAM".to_string(),
+            ScoreWeights {
+                kda: 0.3,
+                kill_participation: 0.25,
+                damage_share: 0.3,
+                gold_share: 0.1,
+                vision_share: 0.0,
+                objective_damage: 0.05,
+                cs_per_minute: 0.0,
+            },
+        );
+        Self {
+            default: ScoreWeights::default(),
+            profiles,
+        }
+    }
+}
+
+impl ScoreConfig {
+    /// 获取指定游戏模式的权重，没有单独配置时使用默认权重
+    pub fn weights_for(&self, game_mode: &str) -> &ScoreWeights {
+        self.profiles.get(game_mode).unwrap_or(&self.default)
+    }
+}
+
+static SCORE_CONFIG: ConfigCell<ScoreConfig> = ConfigCell::new();
+
+/// 读取评分配置，文件不存在时写入默认配置，解析失败时使用默认配置
+pub async fn init_score_config(path: &Path) {
+    SCORE_CONFIG.init(path).await;
+}
+
+/// 获取评分配置，未初始化时返回默认配置
+pub async fn get_score_config() -> Arc<ScoreConfig> {
+    SCORE_CONFIG.get().await
+}
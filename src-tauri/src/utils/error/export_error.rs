@@ -0,0 +1,16 @@
+/// 战绩导出错误
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ExportError {
+    #[error("读写文件错误: {0}")]
+    Io(std::io::Error),
+    #[error("写入 CSV 错误: {0}")]
+    Csv(csv::Error),
+    #[error("序列化错误: {0}")]
+    Json(serde_json::Error),
+    #[error("构建数据列错误: {0}")]
+    Arrow(arrow_schema::ArrowError),
+    #[error("写入 Parquet 错误: {0}")]
+    Parquet(parquet::errors::ParquetError),
+}
@@ -1,5 +1,8 @@
+pub mod export_error;
 pub mod http_error;
 pub mod init_error;
 pub mod path_error;
+pub mod sgp_error;
+pub mod store_error;
 pub mod task_runner_error;
 pub mod websocket_error;
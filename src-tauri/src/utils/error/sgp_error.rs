@@ -0,0 +1,16 @@
+/// SGP 跨区查询错误
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SgpError {
+    #[error("SGP 客户端未初始化")]
+    NotInitialized,
+    #[error("未知的服务器: {0}")]
+    UnknownServer(String),
+    #[error("当前服务器 {current} 无法查询 {target} 的{service}")]
+    NotInteroperable {
+        current: String,
+        target: String,
+        service: &'static str,
+    },
+}
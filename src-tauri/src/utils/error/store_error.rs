@@ -0,0 +1,20 @@
+/// 本地存储错误
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum StoreError {
+    #[error("存储未初始化")]
+    NotInitialized,
+    #[error("读写文件错误: {0}")]
+    Io(std::io::Error),
+    #[error("解析错误: {0}")]
+    Json(serde_json::Error),
+    #[error("无效的分页游标")]
+    InvalidCursor,
+    #[error("无效的备注: {0}")]
+    InvalidNote(String),
+    #[error("无效的对局 ID: {0}")]
+    InvalidMatchId(String),
+    #[error("无效的玩家 ID: {0}")]
+    InvalidPuuid(String),
+}
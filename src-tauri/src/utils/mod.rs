@@ -1,3 +1,4 @@
 pub mod config;
 pub mod error;
 pub mod log;
+pub mod time;
@@ -4,6 +4,7 @@ import { RotateCcw } from "lucide-react";
 import { Button } from "./ui/button";
 import { SearchableSelect, SelectOption } from "./ui/searchable-select";
 import { useInitStore } from "@/lib/store/initStore";
+import type { RecordFacets } from "@/lib/api/recordList";
 
 export interface FilterOptions {
   queueId?: number;
@@ -14,8 +15,10 @@ export interface FilterOptions {
 
 export default function RecordFilter({
   onFilterChange,
+  facets,
 }: {
   onFilterChange: (filters: FilterOptions) => void;
+  facets?: RecordFacets;
 }) {
   const { t } = useTranslation();
   const [filters, setFilters] = useState<FilterOptions>({});
@@ -60,10 +63,19 @@ export default function RecordFilter({
     [t]
   );
 
-  // 英雄列表（从 records 的所有 participants 中提取唯一的英雄名称，根据 puuid 筛选）
+  // 英雄列表：优先使用后端统计的候选项，没有时使用常用英雄
   const filterHeroes = useInitStore((state) => state.filterHeroes);
   const heroOptions: SelectOption[] = useMemo(() => {
     const options: SelectOption[] = [{ value: "", label: t("filter.all") }];
+    if (facets && facets.champions.length > 0) {
+      facets.champions.forEach((champion) => {
+        options.push({
+          value: champion.value.toString(),
+          label: `${champion.label || champion.value} (${champion.count})`,
+        });
+      });
+      return options;
+    }
     filterHeroes.forEach((hero) => {
       options.push({
         value: hero.championId.toString(),
@@ -71,7 +83,7 @@ export default function RecordFilter({
       });
     });
     return options;
-  }, [filterHeroes, t]);
+  }, [facets, filterHeroes, t]);
 
   const handleGameModeChange = (value: string) => {
     const newFilters = {
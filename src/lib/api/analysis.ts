@@ -0,0 +1,410 @@
+import { invoke } from "@tauri-apps/api/core";
+import type { MatchFilter, Role } from "./recordList";
+
+export interface PerformanceStats {
+  games: number;
+  wins: number;
+  winRate: number;
+  avgKills: number;
+  avgDeaths: number;
+  avgAssists: number;
+  kda: number;
+  csPerMinute: number;
+  damageShare: number;
+  visionScore: number;
+  goldPerMinute: number;
+}
+
+export interface ChampionPerformance {
+  championId: number;
+  championName: string;
+  stats: PerformanceStats;
+}
+
+export interface RolePerformance {
+  position: string;
+  stats: PerformanceStats;
+}
+
+export interface QueuePerformance {
+  queueId: number;
+  stats: PerformanceStats;
+}
+
+export interface TrendPoint {
+  matchId: string;
+  gameCreation: number;
+  championId: number;
+  win: boolean;
+  kda: number;
+  rollingWinRate: number;
+}
+
+export interface PerformanceReport {
+  puuid: string;
+  overall: PerformanceStats;
+  champions: Array<ChampionPerformance>;
+  roles: Array<RolePerformance>;
+  queues: Array<QueuePerformance>;
+  // 按时间从旧到新
+  trend: Array<TrendPoint>;
+}
+
+export async function getPlayerPerformance(
+  puuid: string,
+  count?: number,
+  filter?: MatchFilter,
+  sync?: boolean
+): Promise<PerformanceReport> {
+  try {
+    return await invoke<PerformanceReport>("get_player_performance", {
+      puuid,
+      count,
+      filter,
+      sync,
+    });
+  } catch (error) {
+    console.error("获取玩家表现失败:", error);
+    throw error;
+  }
+}
+
+export interface DiffPoint {
+  minute: number;
+  // 蓝方减红方
+  goldDiff: number;
+  xpDiff: number;
+}
+
+export interface Snapshot {
+  minute: number;
+  gold: number;
+  cs: number;
+  xp: number;
+  level: number;
+  // 与对位的差距，没有对位时为 null
+  goldDiff: number | null;
+  csDiff: number | null;
+  xpDiff: number | null;
+}
+
+export interface PlayerTimeline {
+  participantId: number;
+  puuid: string;
+  teamId: number;
+  championId: number;
+  position: string;
+  laneOpponent: number | null;
+  snapshots: Snapshot[];
+}
+
+export interface ObjectiveTiming {
+  timestamp: number; // 毫秒
+  teamId: number;
+  participantId: number;
+}
+
+export interface TeamObjectives {
+  teamId: number;
+  kills: number;
+  towers: number;
+  turretPlates: number;
+  inhibitors: number;
+  dragons: number;
+  heralds: number;
+  voidGrubs: number;
+  barons: number;
+  atakhans: number;
+}
+
+export interface TimelineReport {
+  matchId: string;
+  curve: DiffPoint[];
+  players: PlayerTimeline[];
+  firstBlood: ObjectiveTiming | null;
+  firstTower: ObjectiveTiming | null;
+  firstDragon: ObjectiveTiming | null;
+  firstBaron: ObjectiveTiming | null;
+  objectives: TeamObjectives[];
+}
+
+export async function getMatchTimeline(
+  matchId: string
+): Promise<TimelineReport> {
+  try {
+    return await invoke<TimelineReport>("get_match_timeline", { matchId });
+  } catch (error) {
+    console.error("获取对局时间线失败:", error);
+    throw error;
+  }
+}
+
+export type TimeWindow = "early" | "mid" | "late";
+
+export interface Heatmap {
+  total: number;
+  // cells[row][col]，第 0 行为地图最上方，取值 0~1
+  cells: number[][];
+}
+
+export interface WindowHeatmap {
+  window: TimeWindow;
+  kills: Heatmap;
+  deaths: Heatmap;
+  assists: Heatmap;
+}
+
+export interface MapHeatmap {
+  mapId: number;
+  games: number;
+  windows: WindowHeatmap[];
+}
+
+export interface HeatmapReport {
+  puuid: string;
+  gridSize: number;
+  maps: MapHeatmap[];
+}
+
+export async function getPlayerHeatmap(
+  puuid: string,
+  count?: number,
+  filter?: MatchFilter,
+  gridSize?: number,
+  fetch?: boolean
+): Promise<HeatmapReport> {
+  try {
+    return await invoke<HeatmapReport>("get_player_heatmap", {
+      puuid,
+      count,
+      filter,
+      gridSize,
+      fetch,
+    });
+  } catch (error) {
+    console.error("获取热力图失败:", error);
+    throw error;
+  }
+}
+
+export interface BuildItem {
+  itemId: number;
+  name: string;
+  timestamp: number; // 毫秒
+  soldAt: number | null;
+}
+
+export interface SkillLevel {
+  slot: number; // 1~4 对应 Q / W / E / R
+  key: string;
+  timestamp: number;
+}
+
+export interface ParticipantBuild {
+  participantId: number;
+  puuid: string;
+  startingItems: BuildItem[];
+  buildOrder: BuildItem[];
+  completedItems: BuildItem[];
+  skillOrder: SkillLevel[];
+  skillMaxOrder: string[]; // 如 ["Q", "E", "W"]
+}
+
+export async function getMatchBuilds(
+  matchId: string
+): Promise<ParticipantBuild[]> {
+  try {
+    return await invoke<ParticipantBuild[]>("get_match_builds", { matchId });
+  } catch (error) {
+    console.error("获取出装顺序失败:", error);
+    throw error;
+  }
+}
+
+export interface PremadeCandidate {
+  puuid: string;
+  teamId: number;
+}
+
+export interface PartyGroup {
+  id: number; // 从 1 开始
+  teamId: number;
+  puuids: string[];
+  sharedGames: number;
+  confidence: number; // 0~1
+}
+
+export interface PartyTag {
+  groupId: number;
+  size: number;
+  confidence: number;
+}
+
+export interface PremadeReport {
+  groups: PartyGroup[];
+}
+
+export async function detectPremades(
+  players: PremadeCandidate[]
+): Promise<PremadeReport> {
+  try {
+    return await invoke<PremadeReport>("detect_premades", { players });
+  } catch (error) {
+    console.error("检测开黑失败:", error);
+    throw error;
+  }
+}
+
+export type IndicatorKind =
+  | "winStreak"
+  | "lossStreak"
+  | "hotForm"
+  | "coldForm"
+  | "frequentRemakes"
+  | "earlySurrenders"
+  | "lowEffort"
+  | "smurf";
+
+export interface Indicator {
+  kind: IndicatorKind;
+  value: number; // 连胜局数、占比或 KDA 变化比例等
+  message: string;
+  matchIds: string[]; // 作为证据的对局
+}
+
+export async function getPlayerIndicators(
+  puuid: string,
+  count?: number,
+  sync?: boolean
+): Promise<Indicator[]> {
+  try {
+    return await invoke<Indicator[]>("get_player_indicators", {
+      puuid,
+      count,
+      sync,
+    });
+  } catch (error) {
+    console.error("获取玩家状态指标失败:", error);
+    throw error;
+  }
+}
+
+export interface SharedGamePlayer {
+  championId: number;
+  role: Role | null; // 判定后的位置，没有分路的模式为 null
+  kills: number;
+  deaths: number;
+  assists: number;
+  win: boolean;
+}
+
+export interface SharedGame {
+  matchId: string;
+  gameCreation: number;
+  queueId: number;
+  gameDuration: number; // 秒
+  sameTeam: boolean;
+  player: SharedGamePlayer;
+  other: SharedGamePlayer;
+}
+
+export interface SharedRecord {
+  games: number;
+  wins: number; // 第一名玩家的胜场
+  winRate: number; // 0~1
+}
+
+export interface HeadToHead {
+  puuid: string;
+  otherPuuid: string;
+  together: SharedRecord; // 同队
+  against: SharedRecord; // 对位
+  games: SharedGame[]; // 从新到旧
+}
+
+// backfill: 先从服务器拉取另一名玩家最近的多少局，默认只使用本地数据
+export async function getHeadToHeadRecord(
+  puuid: string,
+  otherPuuid: string,
+  backfill?: number
+): Promise<HeadToHead> {
+  try {
+    return await invoke<HeadToHead>("get_head_to_head_record", {
+      puuid,
+      otherPuuid,
+      backfill,
+    });
+  } catch (error) {
+    console.error("获取交手记录失败:", error);
+    throw error;
+  }
+}
+
+export interface MatchupStats {
+  championId: number; // 按对位统计时为对方英雄，按己方英雄统计时为自己的英雄
+  championName: string;
+  games: number;
+  wins: number;
+  winRate: number; // 0~1
+  kda: number;
+  goldDiffAt15: number | null; // 只统计有时间线数据的对局
+  csDiffAt15: number | null;
+  timelineGames: number;
+}
+
+export interface MatchupQuery {
+  puuid: string;
+  count?: number; // 默认最近 100 局
+  role?: Role;
+  championId?: number; // 只统计自己使用该英雄的对局
+  enemyChampionId?: number; // 额外统计面对该英雄时自己各个英雄的战绩
+  fetchDetails?: boolean; // 本地没有对局详情时是否从服务器拉取
+}
+
+export interface MatchupReport {
+  versus: MatchupStats[]; // 按对位英雄统计
+  picks: MatchupStats[]; // 面对 enemyChampionId 时按自己的英雄统计
+}
+
+export async function getMatchupStats(
+  query: MatchupQuery
+): Promise<MatchupReport> {
+  try {
+    return await invoke<MatchupReport>("get_matchup_stats", { query });
+  } catch (error) {
+    console.error("获取对位统计失败:", error);
+    throw error;
+  }
+}
+
+export interface PlacementStats {
+  games: number;
+  averagePlacement: number;
+  top4Rate: number; // 0~1
+  firstRate: number; // 0~1
+}
+
+export interface AugmentStats {
+  augmentId: number;
+  name: string;
+  iconPath: string;
+  stats: PlacementStats;
+}
+
+export interface ArenaReport {
+  overall: PlacementStats;
+  augments: AugmentStats[]; // 按平均名次从好到差，选择局数太少的排在最后
+}
+
+// 斗魂竞技场统计，count 为空时统计本地全部对局
+export async function getArenaStats(
+  puuid: string,
+  count?: number
+): Promise<ArenaReport> {
+  try {
+    return await invoke<ArenaReport>("get_arena_stats", { puuid, count });
+  } catch (error) {
+    console.error("获取斗魂竞技场统计失败:", error);
+    throw error;
+  }
+}
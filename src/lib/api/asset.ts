@@ -123,6 +123,22 @@ export async function getPerkIcon(perkId: number): Promise<string> {
   }
 }
 
+/**
+ * 获取斗魂竞技场强化符文图标（Base64 编码）
+ *
+ * @param augmentId 强化符文 ID
+ * @returns Base64 编码的图片数据 URL
+ */
+export async function getAugmentIcon(augmentId: number): Promise<string> {
+  try {
+    const base64Url = await invoke<string>("get_augment_icon", { augmentId });
+    return base64Url;
+  } catch (error) {
+    console.error("获取强化符文图标失败:", error);
+    throw error;
+  }
+}
+
 /**
  * 批量获取图片（并发加载）
  *
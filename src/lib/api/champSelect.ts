@@ -0,0 +1,168 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
+import type {
+  Indicator,
+  MatchupStats,
+  PartyTag,
+  PerformanceReport,
+} from "./analysis";
+import type { FavoriteHero } from "./info";
+import type { PlayerNote } from "./note";
+import type { Role } from "./recordList";
+
+export interface ChampSelectPlayer {
+  puuid: string; // 对方隐藏信息时为空
+  cellId: number;
+  team: number;
+  isAlly: boolean;
+  isSelf: boolean;
+  assignedPosition: string;
+  championId: number;
+  premade: PartyTag | null;
+  note: PlayerNote | null; // 自己保存的备注
+}
+
+export async function getChampSelectPlayers(
+  detectPremade?: boolean
+): Promise<ChampSelectPlayer[]> {
+  try {
+    return await invoke<ChampSelectPlayer[]>("get_champ_select_players", {
+      detectPremade,
+    });
+  } catch (error) {
+    console.error("获取英雄选择玩家失败:", error);
+    throw error;
+  }
+}
+
+export interface RankInfo {
+  rank: string;
+  division: string;
+  lp?: number;
+  wins?: number;
+  losses?: number;
+}
+
+export type ScoutSource = "summoner" | "ranked" | "mastery" | "history";
+
+export interface ScoutError {
+  source: ScoutSource;
+  message: string;
+}
+
+export interface PlayerScout {
+  puuid: string;
+  gameName: string;
+  tagLine: string;
+  summonerLevel: number;
+  profileIconId: number;
+  soloRank: RankInfo | null;
+  flexRank: RankInfo | null;
+  masteries: FavoriteHero[];
+  recent: PerformanceReport | null;
+  indicators: Indicator[];
+  errors: ScoutError[]; // 获取失败的项，对应字段为空
+}
+
+export interface ScoutUpdate {
+  gameId: number;
+  player: ChampSelectPlayer;
+  scout: PlayerScout;
+}
+
+export async function getChampSelectScouts(): Promise<ScoutUpdate[]> {
+  try {
+    return await invoke<ScoutUpdate[]>("get_champ_select_scouts");
+  } catch (error) {
+    console.error("获取侦察结果失败:", error);
+    throw error;
+  }
+}
+
+// 每名玩家侦察完成时回调；进入或离开英雄选择时调用 onReset
+export async function listenChampSelectScouts(
+  onUpdate: (update: ScoutUpdate) => void,
+  onReset: () => void
+): Promise<UnlistenFn> {
+  const unlistenUpdate = await listen<ScoutUpdate>(
+    "champ-select-scout",
+    (event) => onUpdate(event.payload)
+  );
+  const unlistenReset = await listen("champ-select-scout-reset", () =>
+    onReset()
+  );
+  return () => {
+    unlistenUpdate();
+    unlistenReset();
+  };
+}
+
+export interface EnemyMatchup {
+  enemyChampionId: number;
+  current: MatchupStats | null; // 当前英雄对该英雄的战绩
+  picks: MatchupStats[]; // 面对该英雄时自己各个英雄的战绩
+}
+
+export interface ChampSelectMatchups {
+  role: Role | null;
+  championId: number; // 当前选择或预选的英雄，没有时为 0
+  enemies: EnemyMatchup[];
+}
+
+// 锁定前查看自己与对方已选英雄的对位战绩（只使用本地数据）
+export async function getChampSelectMatchups(): Promise<ChampSelectMatchups> {
+  try {
+    return await invoke<ChampSelectMatchups>("get_champ_select_matchups");
+  } catch (error) {
+    console.error("获取英雄选择对位战绩失败:", error);
+    throw error;
+  }
+}
+
+export interface AutoPickConfig {
+  autoPick: boolean;
+  autoBan: boolean;
+  lockBeforeEndMs: number; // 在操作倒计时结束前多少毫秒锁定
+  // 位置（top / jungle / middle / bottom / utility / default） -> 英雄 ID，优先级从高到低
+  picks: Record<string, number[]>;
+  bans: Record<string, number[]>;
+}
+
+export type ActionKind = "pick" | "ban";
+
+export interface AutoPickUpdate {
+  actionId: number;
+  kind: ActionKind;
+  championId: number;
+  lockAt: number | null; // 计划锁定的时间，已经锁定或放弃时为 null
+  locked: boolean;
+}
+
+export async function getAutoPickConfig(): Promise<AutoPickConfig> {
+  try {
+    return await invoke<AutoPickConfig>("get_auto_pick_config");
+  } catch (error) {
+    console.error("获取自动选择设置失败:", error);
+    throw error;
+  }
+}
+
+export async function setAutoPickConfig(
+  config: AutoPickConfig
+): Promise<AutoPickConfig> {
+  try {
+    return await invoke<AutoPickConfig>("set_auto_pick_config", { config });
+  } catch (error) {
+    console.error("保存自动选择设置失败:", error);
+    throw error;
+  }
+}
+
+// 自动预选、锁定或放弃锁定时回调
+export async function listenAutoPick(
+  onUpdate: (update: AutoPickUpdate) => void
+): Promise<UnlistenFn> {
+  return await listen<AutoPickUpdate>("auto-pick", (event) =>
+    onUpdate(event.payload)
+  );
+}
@@ -42,3 +42,47 @@ export async function getInfo(puuid: string): Promise<PlayerInfoData> {
     throw error;
   }
 }
+
+export interface PlayerCandidate {
+  puuid: string;
+  gameName: string;
+  tagLine: string;
+  serverId: string; // 如 TENCENT_HN1
+  serverName: string;
+  serverNameEn: string;
+  isCurrentServer: boolean;
+  summonerLevel: number;
+  profileIconId: number;
+}
+
+export interface SearchServer {
+  serverId: string;
+  serverName: string;
+  serverNameEn: string;
+  isCurrentServer: boolean;
+}
+
+// riotId 为 名称#编号；不传 server 时搜索当前服务器和所有可以互通的服务器
+export async function searchPlayers(
+  riotId: string,
+  server?: string
+): Promise<PlayerCandidate[]> {
+  try {
+    return await invoke<PlayerCandidate[]>("search_players", {
+      riotId,
+      server,
+    });
+  } catch (error) {
+    console.error("搜索玩家失败:", error);
+    throw error;
+  }
+}
+
+export async function getSearchServers(): Promise<SearchServer[]> {
+  try {
+    return await invoke<SearchServer[]>("get_search_servers");
+  } catch (error) {
+    console.error("获取可搜索的服务器失败:", error);
+    throw error;
+  }
+}
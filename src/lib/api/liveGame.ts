@@ -0,0 +1,46 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
+import type { PartyTag } from "./analysis";
+import type { PlayerScout } from "./champSelect";
+import type { FavoriteHero } from "./info";
+import type { PlayerNote } from "./note";
+
+export interface LivePlayer {
+  puuid: string; // 隐藏信息时为空
+  teamId: number; // 100 或 200
+  isAlly: boolean;
+  isSelf: boolean;
+  championId: number;
+  championName: string;
+  position: string;
+  spell1Id: number;
+  spell2Id: number;
+  championMastery: FavoriteHero | null; // 当前英雄的成就
+  scout: PlayerScout;
+  premade: PartyTag | null; // 所在车队，不是开黑时为 null
+  note: PlayerNote | null; // 自己保存的备注
+}
+
+export interface LiveGame {
+  gameId: number;
+  queueId: number;
+  gameMode: string;
+  mapId: number;
+  players: LivePlayer[]; // 己方在前
+}
+
+export async function getLiveGame(refresh?: boolean): Promise<LiveGame> {
+  try {
+    return await invoke<LiveGame>("get_live_game", { refresh });
+  } catch (error) {
+    console.error("获取对局信息失败:", error);
+    throw error;
+  }
+}
+
+// 进入游戏后对局信息解析完成时回调
+export async function listenLiveGame(
+  onGame: (game: LiveGame) => void
+): Promise<UnlistenFn> {
+  return await listen<LiveGame>("live-game", (event) => onGame(event.payload));
+}
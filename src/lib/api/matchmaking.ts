@@ -0,0 +1,89 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
+
+export interface AutoAcceptConfig {
+  enabled: boolean;
+  delayMs: number; // 就绪检查开始后等待多久再接受，最多 9000
+  away: boolean; // 暂时离开，开启后自动拒绝
+}
+
+export type ReadyCheckAction = "accepted" | "declined" | "cancelled" | "failed";
+
+export interface ReadyCheckStatus {
+  startedAt: number; // 毫秒时间戳
+  acceptAt: number | null; // 计划自动接受的时间，在这之前可以取消
+  action: ReadyCheckAction | null;
+  finished: boolean;
+}
+
+export interface ReadyCheckRecord {
+  startedAt: number;
+  endedAt: number;
+  action: ReadyCheckAction | null; // 没有开启自动接受时为 null
+  playerResponse: string; // Accepted / Declined / None
+  state: string; // EveryoneReady / StrangerNotReady / PartyNotReady 等
+  error: string | null;
+}
+
+export async function getAutoAcceptConfig(): Promise<AutoAcceptConfig> {
+  try {
+    return await invoke<AutoAcceptConfig>("get_auto_accept_config");
+  } catch (error) {
+    console.error("获取自动接受设置失败:", error);
+    throw error;
+  }
+}
+
+export async function setAutoAcceptConfig(
+  config: AutoAcceptConfig
+): Promise<AutoAcceptConfig> {
+  try {
+    return await invoke<AutoAcceptConfig>("set_auto_accept_config", {
+      config,
+    });
+  } catch (error) {
+    console.error("保存自动接受设置失败:", error);
+    throw error;
+  }
+}
+
+export async function getReadyCheckStatus(): Promise<ReadyCheckStatus | null> {
+  try {
+    return await invoke<ReadyCheckStatus | null>("get_ready_check_status");
+  } catch (error) {
+    console.error("获取就绪检查状态失败:", error);
+    throw error;
+  }
+}
+
+// 取消本次自动接受，已经接受或没有计划时返回 false
+export async function cancelAutoAccept(): Promise<boolean> {
+  try {
+    return await invoke<boolean>("cancel_auto_accept");
+  } catch (error) {
+    console.error("取消自动接受失败:", error);
+    throw error;
+  }
+}
+
+export async function getReadyCheckHistory(
+  limit?: number
+): Promise<ReadyCheckRecord[]> {
+  try {
+    return await invoke<ReadyCheckRecord[]>("get_ready_check_history", {
+      limit,
+    });
+  } catch (error) {
+    console.error("获取就绪检查记录失败:", error);
+    throw error;
+  }
+}
+
+// 就绪检查开始、自动响应、取消和结束时回调
+export async function listenReadyCheck(
+  onChange: (status: ReadyCheckStatus) => void
+): Promise<UnlistenFn> {
+  return await listen<ReadyCheckStatus>("ready-check", (event) =>
+    onChange(event.payload)
+  );
+}
@@ -0,0 +1,127 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
+
+export interface PlayerNote {
+  puuid: string;
+  gameName: string; // 保存备注时的名称
+  tagLine: string;
+  note: string;
+  tags: string[];
+  rating: number | null; // 1~5
+  avoid: boolean; // 在回避名单中，出现在对局里时会提醒
+  createdAt: number;
+  updatedAt: number;
+}
+
+export interface PlayerNoteInput {
+  puuid: string;
+  gameName?: string; // 为空时沿用之前保存的名称
+  tagLine?: string;
+  note?: string;
+  tags?: string[];
+  rating?: number | null;
+  avoid?: boolean; // 不传时保持原来的状态
+}
+
+export interface LobbyPlayer {
+  puuid: string;
+  summonerName: string;
+  summonerLevel: number;
+  summonerIconId: number;
+  isLeader: boolean;
+  isSelf: boolean;
+  firstPositionPreference: string;
+  secondPositionPreference: string;
+  note: PlayerNote | null;
+}
+
+export type AlertSource = "champSelect" | "liveGame";
+
+export interface AvoidAlert {
+  gameId: number;
+  source: AlertSource;
+  puuid: string;
+  isAlly: boolean;
+  championId: number; // 还没有选择英雄时为 0
+  note: PlayerNote;
+}
+
+export async function listPlayerNotes(
+  tag?: string,
+  avoidOnly?: boolean
+): Promise<PlayerNote[]> {
+  try {
+    return await invoke<PlayerNote[]>("list_player_notes", { tag, avoidOnly });
+  } catch (error) {
+    console.error("获取玩家备注列表失败:", error);
+    throw error;
+  }
+}
+
+export async function getPlayerNote(puuid: string): Promise<PlayerNote | null> {
+  try {
+    return await invoke<PlayerNote | null>("get_player_note", { puuid });
+  } catch (error) {
+    console.error("获取玩家备注失败:", error);
+    throw error;
+  }
+}
+
+export async function savePlayerNote(
+  input: PlayerNoteInput
+): Promise<PlayerNote> {
+  try {
+    return await invoke<PlayerNote>("save_player_note", { input });
+  } catch (error) {
+    console.error("保存玩家备注失败:", error);
+    throw error;
+  }
+}
+
+export async function deletePlayerNote(puuid: string): Promise<boolean> {
+  try {
+    return await invoke<boolean>("delete_player_note", { puuid });
+  } catch (error) {
+    console.error("删除玩家备注失败:", error);
+    throw error;
+  }
+}
+
+export async function getLobbyPlayers(): Promise<LobbyPlayer[]> {
+  try {
+    return await invoke<LobbyPlayer[]>("get_lobby_players");
+  } catch (error) {
+    console.error("获取大厅玩家失败:", error);
+    throw error;
+  }
+}
+
+export async function setPlayerAvoid(
+  puuid: string,
+  avoid: boolean
+): Promise<PlayerNote> {
+  try {
+    return await invoke<PlayerNote>("set_player_avoid", { puuid, avoid });
+  } catch (error) {
+    console.error("设置回避名单失败:", error);
+    throw error;
+  }
+}
+
+export async function getAvoidAlerts(): Promise<AvoidAlert[]> {
+  try {
+    return await invoke<AvoidAlert[]>("get_avoid_alerts");
+  } catch (error) {
+    console.error("获取回避提醒失败:", error);
+    throw error;
+  }
+}
+
+// 回避名单中的玩家出现在英雄选择或对局中时回调，同一名玩家每局只提醒一次
+export async function listenAvoidAlerts(
+  onAlert: (alert: AvoidAlert) => void
+): Promise<UnlistenFn> {
+  return await listen<AvoidAlert>("avoid-alert", (event) =>
+    onAlert(event.payload)
+  );
+}
@@ -0,0 +1,103 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
+import type { TimelineReport } from "./analysis";
+
+export interface RankPoint {
+  timestamp: number;
+  tier: string;
+  division: string;
+  leaguePoints: number;
+  absoluteLp: number; // 换算成连续的胜点，便于绘制走势
+  wins: number;
+  losses: number;
+}
+
+export interface LpChange {
+  gameId: number | null; // 启动时补记的变化为空
+  timestamp: number;
+  before: RankPoint;
+  after: RankPoint;
+  lpDelta: number;
+  games: number; // 大于 1 说明是多局的合计
+  win: boolean | null;
+}
+
+export async function getLpChanges(
+  puuid?: string,
+  queueType?: string
+): Promise<LpChange[]> {
+  try {
+    return await invoke<LpChange[]>("get_lp_changes", { puuid, queueType });
+  } catch (error) {
+    console.error("获取胜点变化失败:", error);
+    throw error;
+  }
+}
+
+export async function getRankHistory(
+  puuid?: string,
+  queueType?: string
+): Promise<RankPoint[]> {
+  try {
+    return await invoke<RankPoint[]>("get_rank_history", { puuid, queueType });
+  } catch (error) {
+    console.error("获取段位走势失败:", error);
+    throw error;
+  }
+}
+
+export interface PostGamePlayer {
+  puuid: string;
+  gameName: string;
+  tagLine: string;
+  teamId: number;
+  subteamId: number; // 斗魂竞技场的小队编号，其他模式为 0
+  championId: number;
+  win: boolean;
+  placement: number; // 斗魂竞技场的名次，其他模式为 0
+  score: number;
+  badge: "MVP" | "ACE" | null;
+  kills: number;
+  deaths: number;
+  assists: number;
+  kda: number;
+  damageShare: number; // 0~1，斗魂竞技场按小队计算
+  goldShare: number;
+}
+
+export interface PostGameReport {
+  matchId: string;
+  gameId: number;
+  puuid: string; // 报告所属的玩家
+  queueId: number;
+  gameMode: string;
+  gameCreation: number;
+  gameDuration: number; // 秒
+  win: boolean;
+  players: PostGamePlayer[];
+  timeline: TimelineReport | null; // 没有对局详情时为空
+  lpChange: LpChange | null;
+}
+
+// 不传 matchId 时返回本次启动后最近一局的报告
+export async function getPostGameReport(
+  matchId?: string
+): Promise<PostGameReport | null> {
+  try {
+    return await invoke<PostGameReport | null>("get_post_game_report", {
+      matchId,
+    });
+  } catch (error) {
+    console.error("获取赛后报告失败:", error);
+    throw error;
+  }
+}
+
+// 每局结束后报告生成完成时回调
+export async function listenPostGameReport(
+  onReport: (report: PostGameReport) => void
+): Promise<UnlistenFn> {
+  return await listen<PostGameReport>("post-game-report", (event) =>
+    onReport(event.payload)
+  );
+}
@@ -1,30 +1,82 @@
 import { invoke } from "@tauri-apps/api/core";
+import type { PlayerNote } from "./note";
 
-// Rust 返回的原始数据结构（date 是时间戳字符串）
+// 战绩结构的版本，与后端的 RECORD_VERSION 一致
+export const RECORD_VERSION = 2;
+
+// SGP 和 LCU 的战绩统一转换成这个结构
 export interface RecordItem {
-  gameId: string; // 游戏 ID
+  version: number;
+  gameId: string; // 游戏 ID（match_id，如 HN1_123456）
   puuid: string; // 玩家 ID
   gameCreation: number; // 游戏创建时间（毫秒时间戳）
   duration: number; // 游戏时长（秒）
   queueId: number; // 队列ID(游戏模式)
+  gameMode: string;
+  gameVersion: string;
+  platformId: string;
+  isArena: boolean; // 斗魂竞技场按小队名次结算
+  teams: RecordTeam[];
   participants: Participant[];
 }
 
+export interface RecordTeam {
+  teamId: number;
+  win: boolean;
+  bans: { champion: Item; pickTurn: number }[];
+  objectives: TeamObjectives;
+}
+
+export interface Objective {
+  first: boolean; // 是否首个拿下
+  kills: number;
+}
+
+export interface TeamObjectives {
+  champion: Objective;
+  tower: Objective;
+  inhibitor: Objective;
+  dragon: Objective;
+  riftHerald: Objective;
+  horde: Objective;
+  baron: Objective;
+  atakhan: Objective;
+}
+
+// 完整的符文配置，斗魂竞技场等模式为空
+export interface Runes {
+  primaryStyle: Item;
+  subStyle: Item;
+  selections: Item[]; // 主系 4 个，副系 2 个
+  statPerks: number[]; // 属性碎片：进攻、灵活、防御
+}
+
+export type Role = "TOP" | "JUNGLE" | "MID" | "BOTTOM" | "UTILITY";
+
 export interface Participant {
   puuid: string;
   name: string;
   tag: string;
   teamId: number;
   win: boolean;
-  lane: string;
-  // 这个暂时无效
-  best: boolean;
+  lane: string; // 原始分路，经常为 NONE
+  role: Role | null; // 判定后的位置，没有分路的模式为 null
+  laneOpponent: string | null; // 对位对手的 puuid
+  best: boolean; // 是否为队内最佳（MVP 或 ACE）
+  score: number; // 评分 0~10
+  badge: "MVP" | "ACE" | null;
+  note: PlayerNote | null; // 自己保存的备注
+  subteamId: number; // 斗魂竞技场小队编号，其他模式为 0
+  placement: number; // 斗魂竞技场名次 1~8，其他模式为 0
 
   // Infomation
   champion: Item;
+  championLevel: number;
   spells: Item[];
-  perks: Item[];
-  items: Item[];
+  perks: Item[]; // 基石符文和副系第一个符文，完整配置见 runes
+  runes: Runes;
+  items: Item[]; // 6 件装备 + 饰品
+  augments: Item[]; // 斗魂竞技场强化符文
 
   // Stats
   damageToTurrets: number;
@@ -35,6 +87,9 @@ export interface Participant {
   damageTakenPercentage: number;
   heal: number;
   healPercentage: number;
+  goldEarned: number;
+  cs: number; // 补刀数（小兵 + 野怪）
+  visionScore: number;
 
   // KDA
   kills: number;
@@ -48,20 +103,119 @@ interface Item {
   name: string;
 }
 
-export async function getRecordList(
+export interface MatchFilter {
+  queueIds?: number[];
+  championIds?: number[];
+  // 判定后的位置：TOP / JUNGLE / MID / BOTTOM / UTILITY，也接受 MIDDLE
+  positions?: string[];
+  // 毫秒时间戳
+  startTime?: number | null;
+  endTime?: number | null;
+  win?: boolean | null;
+  // 版本号，如 "15.1"
+  patches?: string[];
+  // 必须同队 / 不能同队的玩家 puuid
+  withPuuids?: string[];
+  withoutPuuids?: string[];
+}
+
+export type BadgeFilter = "mvp" | "ace" | "none";
+export type RecordSort = "newest" | "oldest" | "kda" | "duration";
+
+export interface RecordQuery {
+  puuid: string;
+  filter?: MatchFilter;
+  badge?: BadgeFilter | null;
+  sort?: RecordSort;
+  cursor?: string | null; // 上一页返回的 nextCursor
+  limit?: number;
+}
+
+export interface FacetCount<T> {
+  value: T;
+  label: string; // 英雄名或玩家名，其余为空
+  count: number;
+}
+
+export interface RecordFacets {
+  queues: FacetCount<number>[];
+  champions: FacetCount<number>[];
+  positions: FacetCount<Role>[];
+  patches: FacetCount<string>[];
+  results: FacetCount<boolean>[];
+  badges: FacetCount<BadgeFilter>[];
+  teammates: FacetCount<string>[];
+}
+
+export interface RecordPage {
+  records: RecordItem[];
+  total: number;
+  nextCursor: string | null; // 没有下一页时为 null
+  facets: RecordFacets;
+}
+
+export type HistorySource = "sgp" | "lcu";
+
+export interface SyncResult {
+  count: number; // 拉取到的局数
+  source: HistorySource; // SGP 失败时回退到 LCU
+}
+
+// 从服务器拉取战绩保存到本地，返回拉取到的局数和实际使用的来源
+// server 为玩家所在的服务器（如 TENCENT_HN10），不传时使用当前服务器，只能查询互通的服务器
+export async function syncRecords(
   puuid: string,
   begIndex: number,
-  endIndex: number
-): Promise<RecordItem[]> {
+  count: number,
+  server?: string
+): Promise<SyncResult> {
   try {
-    const recordListRaw = await invoke<RecordItem[]>("get_rank_list", {
+    return await invoke<SyncResult>("sync_records", {
       puuid,
       begIndex,
-      endIndex,
+      count,
+      server,
     });
-    return recordListRaw;
+  } catch (error) {
+    console.error("同步战绩失败:", error);
+    throw error;
+  }
+}
+
+// 查询本地战绩
+export async function queryRecords(query: RecordQuery): Promise<RecordPage> {
+  try {
+    return await invoke<RecordPage>("query_records", { query });
   } catch (error) {
     console.error("获取战绩列表失败:", error);
     throw error;
   }
 }
+
+export type ExportFormat = "csv" | "jsonl" | "parquet";
+
+export interface ExportSummary {
+  games: number;
+  rows: number;
+  path: string;
+}
+
+// 按查询条件导出本地战绩（忽略游标和每页数量），每局每名玩家一行
+export async function exportRecords(
+  query: RecordQuery,
+  format: ExportFormat,
+  path: string,
+  allParticipants = false
+): Promise<ExportSummary> {
+  try {
+    return await invoke<ExportSummary>("export_records", {
+      query,
+      format,
+      path,
+      allParticipants,
+    });
+  } catch (error) {
+    console.error("导出战绩失败:", error);
+    throw error;
+  }
+}
@@ -1,20 +1,33 @@
-import { useState, useEffect, useCallback, useRef, useMemo } from "react";
+import { useState, useEffect, useCallback, useRef } from "react";
 import PlayerInfo from "@/components/PlayerInfo";
 import type { PlayerInfoData } from "@/lib/api/info";
 import RecordFilter, { FilterOptions } from "@/components/RecordFilter";
 import RecordList from "@/components/RecordList";
-import type { RecordItem } from "@/lib/api/recordList";
-import { getRecordList } from "@/lib/api/recordList";
+import type {
+  BadgeFilter,
+  RecordFacets,
+  RecordItem,
+  RecordQuery as Query,
+} from "@/lib/api/recordList";
+import { queryRecords, syncRecords } from "@/lib/api/recordList";
 import { getInfo } from "@/lib/api/info";
 import { useInitStore } from "@/lib/store/initStore";
 
+const PAGE_SIZE = 20;
+// 每次从服务器拉取的局数
+const SYNC_SIZE = 20;
+
 export default function RecordQuery() {
   const [playerInfo, setPlayerInfo] = useState<PlayerInfoData>();
   const [records, setRecords] = useState<RecordItem[]>([]);
   const [filters, setFilters] = useState<FilterOptions>({});
   const [isLoading, setIsLoading] = useState(false);
   const [hasMore, setHasMore] = useState(true);
-  const currentIndexRef = useRef(0);
+  const [facets, setFacets] = useState<RecordFacets>();
+  const cursorRef = useRef<string | null>(null);
+  // 已经从服务器拉取的局数，以及服务器上是否还有更早的对局
+  const syncedRef = useRef(0);
+  const remoteHasMoreRef = useRef(true);
   const puuid = useInitStore((state) => state.summoner?.puuid);
 
   if (!puuid) {
@@ -28,128 +41,78 @@ export default function RecordQuery() {
     });
   }, [puuid]);
 
-  // 加载更多战绩
+  // 根据筛选框的选项构造查询条件
+  const buildQuery = (cursor: string | null): Query => {
+    const badgeMap: Record<string, BadgeFilter> = {
+      mvp: "mvp",
+      svp: "ace",
+      other: "none",
+    };
+    return {
+      puuid,
+      filter: {
+        queueIds: filters.queueId !== undefined ? [filters.queueId] : [],
+        championIds: filters.hero ? [Number(filters.hero)] : [],
+        win: filters.win ? filters.win === "true" : null,
+      },
+      badge: filters.mvp ? badgeMap[filters.mvp] : null,
+      cursor,
+      limit: PAGE_SIZE,
+    };
+  };
+
+  // 加载更多战绩：先查本地，本地没有更多时再从服务器拉取一页
   const loadMoreRecords = useCallback(async () => {
     if (isLoading || !hasMore) return;
 
     setIsLoading(true);
     try {
-      const begIndex = currentIndexRef.current;
-      const endIndex = begIndex + 19;
-
-      const newRecords = await getRecordList(
-        puuid,
-        begIndex,
-        endIndex
-      );
+      let page = await queryRecords(buildQuery(cursorRef.current));
+      if (page.nextCursor === null && remoteHasMoreRef.current) {
+        const { count: fetched } = await syncRecords(
+          puuid,
+          syncedRef.current,
+          SYNC_SIZE
+        );
+        syncedRef.current += fetched;
+        if (fetched < SYNC_SIZE) {
+          remoteHasMoreRef.current = false;
+        }
+        page = await queryRecords(buildQuery(cursorRef.current));
+      }
 
+      cursorRef.current = page.nextCursor;
+      setFacets(page.facets);
       setRecords((prev) => {
         // 合并新数据，去重
-        let records = [...prev, ...newRecords];
-        records = records.filter(
+        const records = [...prev, ...page.records];
+        return records.filter(
           (record, index, self) =>
             index === self.findIndex((t) => t.gameId === record.gameId)
         );
-        const totalLength = records.length;
-
-        // 更新索引：使用去重后的实际长度
-        currentIndexRef.current = totalLength;
-
-        // 如果返回的数据少于请求的数量，或者达到上限，标记为没有更多数据
-        if (
-          newRecords.length === 0 ||
-          newRecords.length < endIndex - begIndex + 1
-        ) {
-          setHasMore(false);
-        }
-
-        return records;
       });
+      setHasMore(page.nextCursor !== null || remoteHasMoreRef.current);
     } catch (error) {
       console.error("获取战绩列表失败:", error);
       setHasMore(false);
     } finally {
       setIsLoading(false);
     }
-  }, [isLoading, hasMore]);
-
-  // 筛选逻辑（使用 useMemo 优化性能）
-  const recordsFilter = useMemo(() => {
-    // 如果没有筛选条件，直接返回所有记录
-    const hasFilters =
-      filters.queueId !== undefined ||
-      filters.win ||
-      filters.mvp ||
-      filters.hero;
-
-    if (!hasFilters) {
-      return records;
-    }
-
-    // 预处理：为每个 record 创建 participant 映射，避免重复查找
-    const recordsWithParticipant = records
-      .map((record) => {
-        const participant = record.participants.find(
-          (p) => p.puuid === record.puuid
-        );
-        return { record, participant };
-      })
-      .filter(
-        (
-          item
-        ): item is {
-          record: RecordItem;
-          participant: NonNullable<typeof item.participant>;
-        } => item.participant !== undefined
-      );
+  }, [isLoading, hasMore, filters]);
 
-    let filtered = recordsWithParticipant;
-
-    // 根据 queueId 筛选
-    if (filters.queueId !== undefined) {
-      filtered = filtered.filter(
-        (item) => item.record.queueId === filters.queueId
-      );
-    }
-
-    // 根据 win 筛选
-    if (filters.win) {
-      filtered = filtered.filter((item) => {
-        return filters.win === "true"
-          ? item.participant.win
-          : !item.participant.win;
-      });
-    }
-
-    // 根据 mvp 筛选
-    if (filters.mvp) {
-      filtered = filtered.filter((item) => {
-        if (filters.mvp === "mvp") {
-          return item.participant.best && item.participant.win;
-        } else if (filters.mvp === "svp") {
-          return item.participant.best && !item.participant.win;
-        } else if (filters.mvp === "other") {
-          return !item.participant.best;
-        }
-        return true;
-      });
-    }
-
-    // 根据 hero 筛选
-    if (filters.hero) {
-      filtered = filtered.filter(
-        (item) => item.participant.champion.id === Number(filters.hero)
-      );
-    }
-
-    // 只返回 record 数组
-    return filtered.map((item) => item.record);
-  }, [records, filters]);
+  // 筛选条件变化时从第一页重新查询
+  useEffect(() => {
+    cursorRef.current = null;
+    setRecords([]);
+    setHasMore(true);
+  }, [filters]);
 
-  // 初始加载
+  // 初始加载，以及当前条件下还没有任何数据时继续加载
   useEffect(() => {
-    loadMoreRecords();
-  }, []);
+    if (records.length === 0 && hasMore && !isLoading) {
+      loadMoreRecords();
+    }
+  }, [records, hasMore, isLoading]);
 
   const handleFilterChange = (newFilters: FilterOptions) => {
     setFilters(newFilters);
@@ -173,6 +136,7 @@ export default function RecordQuery() {
           <div className="mb-5 shrink-0">
             <RecordFilter
               onFilterChange={handleFilterChange}
+              facets={facets}
             />
           </div>
 
@@ -180,7 +144,7 @@ export default function RecordQuery() {
           <div className="flex-1 overflow-hidden flex flex-col">
             <div className="flex-1 overflow-y-auto pr-2">
               <RecordList
-                records={recordsFilter}
+                records={records}
                 onLoadMore={loadMoreRecords}
                 hasMore={hasMore}
                 isLoading={isLoading}